@@ -49,8 +49,20 @@ async fn do_run() -> Result<(), QvOpenApiError> {
         .await?;
     info!("connect response: {}", connect_response);
 
+    // QV_ACCOUNT_NO가 없으면 첫 번째 계좌를 사용
+    let account_index = match std::env::var("QV_ACCOUNT_NO") {
+        Ok(account_no) => future_client.get_account_index(&account_no)?,
+        Err(_) => future_client
+            .get_accounts()
+            .first()
+            .map(|account| account.account_index)
+            .ok_or(QvOpenApiError::BadRequestError {
+                message: "No account found".into(),
+            })?,
+    };
+
     let query_response = future_client
-        .query(C8201Request::new(1, '1').into_raw())
+        .query(C8201Request::new(account_index, '1').into_raw())
         .await?;
     info!("query response: {}", query_response);
 
@@ -1,29 +1,31 @@
 use std::{
     collections::HashMap,
     sync::{Arc, Mutex, RwLock},
-    thread::sleep,
-    time::{Duration, Instant},
 };
 
-use crate::context::*;
+use crate::{context::*, rate_limiter::*, reconnect::*, stream::*, tr_index_pool::*};
 use log::*;
 use qvopenapi::{
-    error::*, models::*, AbstractQvOpenApiClient, QvOpenApiClient, QvOpenApiRequest, WindowHelper,
+    error::*, models::*, AbstractQvOpenApiClient, AccountEntry, AccountRegistry, Instrument,
+    InstrumentCatalog, InstrumentSelector, QvOpenApiClient, QvOpenApiRequest, WindowHelper,
 };
 use serde_json::{json, Value};
+use tokio::sync::{broadcast, Notify};
 
-type TrContextMap = HashMap<i32, Arc<TrContext>>;
 const INITIAL_TR_INDEX: i32 = 3;
 const MAX_TR_INDEX: i32 = 255;
-const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
 
 pub struct QvOpenApiAsyncClient {
     delegate: Arc<dyn AbstractQvOpenApiClient + Send + Sync>,
     tr_context_map: Arc<RwLock<TrContextMap>>,
-    next_tr_index: Mutex<i32>,
+    tr_index_pool: Arc<TrIndexPool>,
+    rate_limiter: Arc<RateLimiter>,
     connected_info: Arc<RwLock<Option<ConnectResponse>>>,
+    account_registry: Arc<AccountRegistry>,
+    instrument_catalog: Arc<InstrumentCatalog>,
     is_connecting: Arc<RwLock<bool>>,
-    is_dropping: Arc<Mutex<bool>>,
+    connection_state: Arc<ConnectionStateNotifier>,
+    reconnect: Arc<Mutex<Option<ReconnectHandle>>>,
     hwnd: isize,
 }
 
@@ -45,28 +47,19 @@ impl QvOpenApiAsyncClient {
         let client = QvOpenApiAsyncClient {
             delegate: delgate.clone(),
             tr_context_map: Arc::new(RwLock::new(HashMap::new())),
-            next_tr_index: Mutex::new(INITIAL_TR_INDEX),
+            tr_index_pool: Arc::new(TrIndexPool::new(INITIAL_TR_INDEX, MAX_TR_INDEX)),
+            rate_limiter: Arc::new(RateLimiter::new(RateLimitConfig::default()).unwrap()),
             connected_info: Arc::new(RwLock::new(None)),
+            account_registry: Arc::new(AccountRegistry::new()),
+            instrument_catalog: Arc::new(InstrumentCatalog::new()),
             is_connecting: Arc::new(RwLock::new(false)),
-            is_dropping: Arc::new(Mutex::new(false)),
+            connection_state: Arc::new(ConnectionStateNotifier::new()),
+            reconnect: Arc::new(Mutex::new(None)),
             hwnd,
         };
 
         client.setup_callbacks(delgate);
 
-        let cloned_context_map = client.tr_context_map.clone();
-        let cloned_is_dropping = client.is_dropping.clone();
-        {
-            std::thread::spawn(move || loop {
-                let is_dropping = *cloned_is_dropping.clone().lock().unwrap();
-                if is_dropping {
-                    break;
-                }
-                Self::check_expired(cloned_context_map.clone());
-                sleep(Duration::from_millis(100));
-            });
-        }
-
         client
     }
 
@@ -81,7 +74,7 @@ impl QvOpenApiAsyncClient {
                 message: format!("Already using tr_index {}", tr_index),
             })
         } else {
-            let context = Arc::new(TrContext::new(tr_index, tr_type.clone()));
+            let context = Arc::new(TrContext::new(tr_index, tr_type.clone(), None));
             map.insert(tr_index, context.clone());
 
             if matches!(tr_type, TrType::CONNECT) {
@@ -100,7 +93,11 @@ impl QvOpenApiAsyncClient {
         password: &str,
         cert_password: &str,
     ) -> TrFuture {
-        TrFuture::new(self.do_connect(self.hwnd, account_type, id, password, cert_password))
+        TrFuture::new(
+            self.do_connect(self.hwnd, account_type, id, password, cert_password),
+            &self.tr_context_map,
+            &RequestOptions::default(),
+        )
     }
 
     fn do_connect(
@@ -133,32 +130,408 @@ impl QvOpenApiAsyncClient {
         }
     }
 
+    pub fn get_accounts(&self) -> Vec<AccountEntry> {
+        self.account_registry.get_accounts()
+    }
+
+    /**
+     * 계좌번호로 RawQueryRequest에 넣을 account_index를 찾음
+     */
+    pub fn get_account_index(&self, account_no: &str) -> Result<i32, QvOpenApiError> {
+        self.account_registry.get_account_index(account_no)
+    }
+
+    /**
+     * 계좌마다 p8101(주식), p8302(선물옵션)를 조회해서 계좌명을 채움
+     * 해당 상품이 아닌 계좌는 조회가 거부되므로 계좌명이 비어 있는 채로 남음
+     * 접속이 끊긴 경우에만 Err
+     */
+    pub async fn refresh_account_names(&self) -> Result<Vec<AccountEntry>, QvOpenApiError> {
+        for account in self.account_registry.get_accounts() {
+            let index = account.account_index;
+
+            match self.query_typed(&P8101Request::new(index, 'A')).await {
+                // 신 OBM에서는 p8101 계좌명이 비어서 올 수 있음
+                Ok(stock) if !stock.account.accnt_namez30.is_empty() => {
+                    self.account_registry
+                        .set_stock_account_name(index, &stock.account.accnt_namez30);
+                }
+                Ok(_) => {}
+                Err(QvOpenApiError::NotConnectedError) => {
+                    return Err(QvOpenApiError::NotConnectedError)
+                }
+                Err(err) => debug!("No stock account name for index {}: {}", index, err),
+            }
+
+            match self.query_typed(&P8302Request::new(index, '9')).await {
+                Ok(derivatives) if !derivatives.account.o_accnt_namez40.is_empty() => {
+                    self.account_registry
+                        .set_derivatives_account_name(index, &derivatives.account.o_accnt_namez40);
+                }
+                Ok(_) => {}
+                Err(QvOpenApiError::NotConnectedError) => {
+                    return Err(QvOpenApiError::NotConnectedError)
+                }
+                Err(err) => debug!("No derivatives account name for index {}: {}", index, err),
+            }
+        }
+        Ok(self.account_registry.get_accounts())
+    }
+
+    pub fn get_instruments(&self) -> Vec<Instrument> {
+        self.instrument_catalog.get_instruments()
+    }
+
+    /**
+     * 전략 설정의 종목 지정 ("FrontMonth": "Kospi200" 등)을 실제 종목으로 변환
+     * refresh_instruments()로 목록을 먼저 채워야 함
+     */
+    pub fn resolve_instrument(
+        &self,
+        selector: &InstrumentSelector,
+    ) -> Result<Instrument, QvOpenApiError> {
+        self.instrument_catalog.resolve(selector)
+    }
+
+    /**
+     * p1003(선물옵션 코드 조회)으로 해당 선옵구분의 종목 목록을 다시 채움
+     */
+    pub async fn refresh_instruments(
+        &self,
+        gubun: char,
+    ) -> Result<Vec<Instrument>, QvOpenApiError> {
+        let output = self
+            .query(P1003Request::new('k', gubun).into_raw()?)
+            .await?;
+        // 조회가 거부되면 기존 목록을 그대로 둠
+        let block = take_out_block(output, BLOCK_NAME_P1003_OUT_ARRAY)?;
+        let instruments = block
+            .as_array()
+            .ok_or(QvOpenApiError::UnknownError)?
+            .iter()
+            .map(|row| {
+                Instrument::new(
+                    row["codez8"].as_str().unwrap_or_default(),
+                    row["namez30"].as_str().unwrap_or_default(),
+                    gubun,
+                )
+            })
+            .collect();
+        self.instrument_catalog.set_instruments(gubun, instruments);
+        Ok(self.instrument_catalog.get_instruments())
+    }
+
     pub fn query(&self, req: Arc<dyn QvOpenApiRequest>) -> TrFuture {
-        TrFuture::new(self.do_query(req))
+        self.query_with_options(req, &RequestOptions::default())
     }
 
-    fn do_query(&self, req: Arc<dyn QvOpenApiRequest>) -> Result<Arc<TrContext>, QvOpenApiError> {
-        let tr_index = self.get_next_tr_index();
-        let context = self.set_context(tr_index, TrType::QUERY)?;
-        match self.delegate.query(tr_index, req) {
+    pub fn query_with_options(
+        &self,
+        req: Arc<dyn QvOpenApiRequest>,
+        options: &RequestOptions,
+    ) -> TrFuture {
+        let priority = options
+            .priority
+            .unwrap_or_else(|| RequestPriority::for_tr_code(req.get_tr_code()));
+        let permit = self.rate_limiter.enqueue(req.get_tr_code(), priority);
+
+        // 바로 보낼 수 있으면 지금 보냄
+        if permit.is_granted() {
+            match self.tr_index_pool.try_acquire() {
+                Ok(tr_index) => {
+                    let context = Self::do_query(
+                        &self.delegate,
+                        &self.tr_context_map,
+                        &self.tr_index_pool,
+                        tr_index,
+                        req,
+                    );
+                    return TrFuture::new(context, &self.tr_context_map, options);
+                }
+                Err(err) if !options.wait_for_index => {
+                    return TrFuture::new(Err(err), &self.tr_context_map, options);
+                }
+                Err(_) => {}
+            }
+        }
+
+        let delegate = self.delegate.clone();
+        let context_map = self.tr_context_map.clone();
+        let tr_index_pool = self.tr_index_pool.clone();
+        let wait_for_index = options.wait_for_index;
+        let pending = async move {
+            permit.await;
+            let tr_index = if wait_for_index {
+                tr_index_pool.acquire().await
+            } else {
+                tr_index_pool.try_acquire()?
+            };
+            Self::do_query(&delegate, &context_map, &tr_index_pool, tr_index, req)
+        };
+        TrFuture::pending(Box::pin(pending), &self.tr_context_map, options)
+    }
+
+    /**
+     * TR 전송 예산 변경. 기본값은 제한 없음
+     */
+    pub fn set_rate_limit(&self, config: RateLimitConfig) -> Result<(), QvOpenApiError> {
+        self.rate_limiter.set_config(config)
+    }
+
+    /**
+     * 전송 예산 때문에 대기 중인 요청 수
+     */
+    pub fn get_queue_depth(&self) -> QueueDepth {
+        self.rate_limiter.get_queue_depth()
+    }
+
+    /**
+     * 사용 중이지 않은 TR index 수
+     */
+    pub fn get_free_tr_index_count(&self) -> usize {
+        self.tr_index_pool.get_free_count()
+    }
+
+    /**
+     * 요청 타입에 정해진 Output으로 결과를 받음
+     * HTTP 등 JSON이 필요한 곳은 query()를 그대로 사용
+     */
+    pub fn query_typed<R: TypedQueryRequest>(&self, req: &R) -> TypedTrFuture<R::Output> {
+        self.query_typed_with_options(req, &RequestOptions::default())
+    }
+
+    pub fn query_typed_with_options<R: TypedQueryRequest>(
+        &self,
+        req: &R,
+        options: &RequestOptions,
+    ) -> TypedTrFuture<R::Output> {
+        match req.to_query() {
+            Ok(raw) => TypedTrFuture::new(self.query_with_options(raw, options)),
+            Err(err) => TypedTrFuture::new(TrFuture::new(Err(err), &self.tr_context_map, options)),
+        }
+    }
+
+    // tr_index는 풀에서 받은 것이므로 다른 context가 쓰고 있지 않음
+    // 실패하면 보내지 않은 요청이므로 context가 drop되면서 index도 바로 반환됨
+    fn do_query(
+        delegate: &Arc<dyn AbstractQvOpenApiClient + Send + Sync>,
+        context_map: &RwLock<TrContextMap>,
+        tr_index_pool: &Arc<TrIndexPool>,
+        tr_index: i32,
+        req: Arc<dyn QvOpenApiRequest>,
+    ) -> Result<Arc<TrContext>, QvOpenApiError> {
+        let context = Arc::new(TrContext::new(
+            tr_index,
+            TrType::QUERY,
+            Some(tr_index_pool.clone()),
+        ));
+        context_map
+            .write()
+            .unwrap()
+            .insert(tr_index, context.clone());
+        match delegate.query(tr_index, req) {
             Ok(_) => Ok(context),
             Err(err) => {
-                let mut context_map = self.tr_context_map.write().unwrap();
-                context_map.remove(&tr_index);
+                context_map.write().unwrap().remove(&tr_index);
+                context.settle();
                 Err(err)
             }
         }
     }
 
+    /**
+     * s8120(주문/체결 내역)을 다음 페이지가 없을 때까지 조회하고, 반복 블록을 합쳐서 반환
+     */
+    pub async fn query_s8120_all(
+        &self,
+        req: &S8120Request,
+        options: &RequestOptions,
+    ) -> Result<Value, QvOpenApiError> {
+        self.query_all_pages(
+            |cts| match cts {
+                None => Ok(req.into_raw()?),
+                Some(cts) => Ok(req.with_next(cts).into_raw()?),
+            },
+            BLOCK_NAME_S8120_OUT1_ARRAY,
+            BLOCK_NAME_S8120_OUT_IN,
+            "ctsz56",
+            options,
+        )
+        .await
+    }
+
+    pub async fn query_c8322_all(
+        &self,
+        req: &C8322Request,
+        options: &RequestOptions,
+    ) -> Result<Value, QvOpenApiError> {
+        self.query_all_pages(
+            |cts| match cts {
+                None => Ok(req.into_raw()?),
+                Some(cts) => Ok(req.with_next(cts).into_raw()?),
+            },
+            BLOCK_NAME_C8322_OUT1_ARRAY,
+            BLOCK_NAME_C8322_OUT_IN,
+            "cts_areaz67",
+            options,
+        )
+        .await
+    }
+
+    /**
+     * 가능수량을 조회해서 TR별 출력 타입으로 반환
+     */
+    pub async fn max_orderable(
+        &self,
+        req: &MaxOrderableRequest,
+    ) -> Result<MaxOrderableOutput, QvOpenApiError> {
+        Ok(match req {
+            MaxOrderableRequest::StockBuy(req) => {
+                MaxOrderableOutput::StockBuy(self.query_typed(req).await?)
+            }
+            MaxOrderableRequest::StockSell(req) => {
+                MaxOrderableOutput::StockSell(self.query_typed(req).await?)
+            }
+            MaxOrderableRequest::FuturesOptions(req) => {
+                MaxOrderableOutput::FuturesOptions(self.query_typed(req).await?)
+            }
+        })
+    }
+
+    async fn query_all_pages<F>(
+        &self,
+        make_request: F,
+        array_block_name: &str,
+        button_block_name: &str,
+        cts_field_name: &str,
+        options: &RequestOptions,
+    ) -> Result<Value, QvOpenApiError>
+    where
+        F: Fn(Option<&str>) -> Result<Arc<dyn QvOpenApiRequest>, QvOpenApiError>,
+    {
+        let mut rows: Vec<Value> = Vec::new();
+        let mut messages: Vec<Value> = Vec::new();
+        let mut cts: Option<String> = None;
+        loop {
+            let mut output = self
+                .query_with_options(make_request(cts.as_deref())?, options)
+                .await?;
+            if !output["error_type"].is_null() {
+                return Ok(output);
+            }
+
+            if let Some(page_rows) = output["result"][array_block_name].as_array() {
+                rows.extend(page_rows.iter().cloned());
+            }
+            if let Some(page_messages) = output["messages"].as_array() {
+                messages.extend(page_messages.iter().cloned());
+            }
+
+            let button = &output["result"][button_block_name];
+            // ISPAGEUP과 같은 값('N')일 때만 다음 페이지가 있음
+            let has_next = button["nextbutton"]
+                .as_str()
+                .is_some_and(|flag| flag.starts_with(NEXT_PAGE_FLAG));
+            let next_cts = button[cts_field_name]
+                .as_str()
+                .filter(|next_cts| !next_cts.trim().is_empty())
+                .map(String::from);
+
+            match next_cts {
+                // 같은 CTS가 반복되면 무한 조회가 되므로 중단
+                Some(next_cts) if has_next && cts.as_ref() != Some(&next_cts) => {
+                    debug!("Querying next page (cts: {})", next_cts.trim());
+                    cts = Some(next_cts);
+                }
+                _ => {
+                    output["result"][array_block_name] = json!(rows);
+                    output["messages"] = json!(messages);
+                    return Ok(output);
+                }
+            }
+        }
+    }
+
+    /**
+     * 실시간 시세를 Stream으로 구독
+     * 반환된 Stream을 drop하면 구독이 취소되고, 같은 종목을 구독하는 Stream이 더 없으면 wmcaDetach 호출
+     */
+    pub fn subscribe(
+        &self,
+        kind: SiseKind,
+        codes: &[&str],
+        config: SiseStreamConfig,
+    ) -> Result<SiseStream, QvOpenApiError> {
+        SiseStream::new(self.delegate.clone(), kind, codes, config)
+    }
+
+    /**
+     * 직접 끊는 경우이므로 재접속도 중단
+     */
     pub fn disconnect(&self) -> Result<(), QvOpenApiError> {
+        self.disable_reconnect();
         self.delegate.disconnect()
     }
 
+    /**
+     * CA_SOCKETERROR로 끊기면 credentials로 다시 로그인 (기본값은 꺼져 있음)
+     * 로그인에 한 번 성공한 정보를 넘겨야 하며, tokio runtime 안에서 호출해야 함
+     * 다시 호출하면 이전 설정을 대체
+     */
+    pub fn enable_reconnect(
+        self: &Arc<Self>,
+        credentials: Credentials,
+        config: ReconnectConfig,
+    ) -> Result<(), QvOpenApiError> {
+        let runtime = tokio::runtime::Handle::try_current().map_err(|err| {
+            QvOpenApiError::BadRequestError {
+                message: format!("enable_reconnect needs a tokio runtime: {}", err),
+            }
+        })?;
+
+        let connection_lost = Arc::new(Notify::new());
+        let task = runtime.spawn(supervise(
+            Arc::downgrade(self),
+            credentials,
+            config,
+            connection_lost.clone(),
+            self.connection_state.clone(),
+        ));
+        *self.reconnect.lock().unwrap() = Some(ReconnectHandle {
+            connection_lost,
+            task,
+        });
+        Ok(())
+    }
+
+    pub fn disable_reconnect(&self) {
+        self.reconnect.lock().unwrap().take();
+    }
+
+    /**
+     * 접속, 끊김, 재접속 시도 등 상태 변경 이벤트
+     * 너무 늦게 읽으면 오래된 이벤트부터 빠짐 (RecvError::Lagged)
+     */
+    pub fn subscribe_connection_state(&self) -> broadcast::Receiver<ConnectionState> {
+        self.connection_state.subscribe()
+    }
+
+    pub fn get_connection_state(&self) -> ConnectionState {
+        self.connection_state.get_last()
+    }
+
+    // 마지막 접속 때 다시 등록된 (BC, 종목코드) 수
+    pub(crate) fn get_reattached_subscription_count(&self) -> usize {
+        self.delegate.get_handler().get_reattached_count()
+    }
+
     fn setup_callbacks(&self, delagate: Arc<dyn AbstractQvOpenApiClient + Send + Sync>) {
         {
             let context_map_lock = self.tr_context_map.clone();
             let is_connecting_lock = self.is_connecting.clone();
             let connected_info_lock = self.connected_info.clone();
+            let account_registry = self.account_registry.clone();
+            let connection_state = self.connection_state.clone();
             delagate.on_connect(Box::new(move |res| {
                 let mut connected_info = connected_info_lock.write().unwrap();
                 let mut is_connecting_locked = is_connecting_lock.write().unwrap();
@@ -168,10 +541,12 @@ impl QvOpenApiAsyncClient {
                     res,
                     |context, res| {
                         *connected_info = Some(res.clone());
+                        account_registry.set_connect_info(res);
                         context.on_connect(res)
                     },
                 );
                 *is_connecting_locked = false;
+                connection_state.emit(ConnectionState::Connected);
             }));
         }
         {
@@ -187,16 +562,24 @@ impl QvOpenApiAsyncClient {
         }
         {
             let context_map_lock = self.tr_context_map.clone();
+            let tr_index_pool = self.tr_index_pool.clone();
             delagate.on_complete(Box::new(move |tr_index| {
                 Self::handle_callback(context_map_lock.clone(), tr_index, (), |context, _res| {
                     context.on_complete()
                 });
+                // 취소되거나 timeout된 요청의 늦은 응답이 끝남
+                tr_index_pool.settle(tr_index);
             }));
         }
         {
             let context_map_lock = self.tr_context_map.clone();
             let is_connecting_lock = self.is_connecting.clone();
             let connected_info_lock = self.connected_info.clone();
+            let account_registry = self.account_registry.clone();
+            let connection_state = self.connection_state.clone();
+            let delagate_clone = self.delegate.clone();
+            let reconnect = self.reconnect.clone();
+            let tr_index_pool = self.tr_index_pool.clone();
             delagate.on_disconnect(Box::new(move || {
                 let mut connected_info = connected_info_lock.write().unwrap();
                 let mut context_map = context_map_lock.write().unwrap();
@@ -209,13 +592,27 @@ impl QvOpenApiAsyncClient {
                 context_map.clear();
                 *is_connecting_locked = false;
                 *connected_info = None;
+                account_registry.clear();
+                tr_index_pool.settle_all();
+                let is_reconnect_enabled = reconnect.lock().unwrap().is_some();
+                if !is_reconnect_enabled {
+                    Self::end_streams(&delagate_clone);
+                }
+                // CA_SOCKETERROR 뒤에 오는 CA_DISCONNECTED는 ConnectionLost로 이미 알렸고 재접속 중이므로 알리지 않음
+                if !(is_reconnect_enabled && connection_state.is_reconnecting()) {
+                    connection_state.emit(ConnectionState::Disconnected);
+                }
             }));
         }
         {
             let context_map_lock = self.tr_context_map.clone();
             let is_connecting_lock = self.is_connecting.clone();
             let connected_info_lock = self.connected_info.clone();
+            let account_registry = self.account_registry.clone();
             let delagate_clone = self.delegate.clone();
+            let connection_state = self.connection_state.clone();
+            let reconnect = self.reconnect.clone();
+            let tr_index_pool = self.tr_index_pool.clone();
             delagate.on_socket_error(Box::new(move || {
                 let mut connected_info = connected_info_lock.write().unwrap();
                 let mut context_map = context_map_lock.write().unwrap();
@@ -228,7 +625,16 @@ impl QvOpenApiAsyncClient {
                 context_map.clear();
                 *is_connecting_locked = false;
                 *connected_info = None;
-                delagate_clone.disconnect().unwrap();
+                account_registry.clear();
+                tr_index_pool.settle_all();
+                if let Err(err) = delagate_clone.disconnect() {
+                    error!("Failed to disconnect after socket error: {}", err);
+                }
+                connection_state.emit(ConnectionState::ConnectionLost);
+                match &*reconnect.lock().unwrap() {
+                    Some(reconnect) => reconnect.connection_lost.notify_one(),
+                    None => Self::end_streams(&delagate_clone),
+                }
             }));
         }
         {
@@ -250,6 +656,7 @@ impl QvOpenApiAsyncClient {
         {
             let context_map_lock = self.tr_context_map.clone();
             let is_connecting_lock = self.is_connecting.clone();
+            let tr_index_pool = self.tr_index_pool.clone();
             delagate.on_error(Box::new(move |res| {
                 let is_connecting_locked = is_connecting_lock.read().unwrap();
 
@@ -260,11 +667,20 @@ impl QvOpenApiAsyncClient {
                 };
                 Self::handle_callback(context_map_lock.clone(), tr_index, (), |context, _res| {
                     context.on_error_response(res.clone())
-                })
+                });
+                tr_index_pool.settle(res.tr_index);
             }));
         }
     }
 
+    // 재접속하지 않으면 구독이 다시 등록될 일이 없으므로 SiseStream을 NotConnectedError로 끝냄
+    fn end_streams(delegate: &Arc<dyn AbstractQvOpenApiClient + Send + Sync>) {
+        delegate
+            .get_handler()
+            .subscriptions
+            .fail_all(&QvOpenApiError::NotConnectedError);
+    }
+
     fn handle_callback<F, R>(
         context_map_lock: Arc<RwLock<TrContextMap>>,
         tr_index: i32,
@@ -282,7 +698,8 @@ impl QvOpenApiAsyncClient {
                 }
                 None => {
                     if tr_index != TR_INDEX_CONNECT {
-                        error!("Context for tr_index {} is not found", tr_index);
+                        // 취소되었거나 timeout된 요청의 늦은 응답
+                        debug!("Discarding response for tr_index {}", tr_index);
                     }
                 }
             }
@@ -293,46 +710,10 @@ impl QvOpenApiAsyncClient {
             context_map.remove(&tr_index);
         }
     }
-
-    fn get_next_tr_index(&self) -> i32 {
-        let mut locked = self.next_tr_index.lock().unwrap();
-        let ret: i32 = *locked;
-        *locked += 1;
-        if *locked > MAX_TR_INDEX {
-            *locked = INITIAL_TR_INDEX;
-        }
-        ret
-    }
-
-    fn check_expired(context_map_lock: Arc<RwLock<TrContextMap>>) {
-        let mut expired_vec: Vec<Arc<TrContext>> = Vec::new();
-        {
-            let now = Instant::now();
-            let context_map = context_map_lock.read().unwrap();
-            for context in context_map.values() {
-                let elapsed = now.duration_since(context.request_timestamp);
-                if elapsed > DEFAULT_TIMEOUT {
-                    expired_vec.push(context.clone());
-                }
-            }
-        }
-
-        if !expired_vec.is_empty() {
-            let mut context_map = context_map_lock.write().unwrap();
-            for expired in expired_vec {
-                expired.on_timeout();
-                context_map.remove(&expired.tr_index);
-            }
-        }
-    }
 }
 
 impl Drop for QvOpenApiAsyncClient {
     fn drop(&mut self) {
-        {
-            let mut is_dropping = self.is_dropping.lock().unwrap();
-            *is_dropping = true;
-        }
         {
             let mut context_map = self.tr_context_map.write().unwrap();
             for context in context_map.values() {
@@ -342,3 +723,29 @@ impl Drop for QvOpenApiAsyncClient {
         }
     }
 }
+
+/**
+ * 조회 결과에서 block_name 블록만 꺼냄
+ * 오류가 있거나, 조회가 거부되어 블록 없이 메시지만 온 경우는 Err
+ */
+fn take_out_block(mut output: Value, block_name: &str) -> Result<Value, QvOpenApiError> {
+    if !output["error_type"].is_null() {
+        return Err(serde_json::from_value(output["error_type"].take())?);
+    }
+
+    let block = output["result"][block_name].take();
+    if !block.is_null() {
+        return Ok(block);
+    }
+
+    match output["messages"]
+        .as_array()
+        .and_then(|messages| messages.last())
+    {
+        Some(message) => Err(QvOpenApiError::QvApiMessageError {
+            message_code: message["msg_code"].as_str().unwrap_or("").into(),
+            message: message["msg"].as_str().unwrap_or("").into(),
+        }),
+        None => Err(QvOpenApiError::UnknownError),
+    }
+}
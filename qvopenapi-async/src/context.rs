@@ -1,33 +1,101 @@
 use std::{
     collections::HashMap,
     future::Future,
+    marker::PhantomData,
     pin::Pin,
-    sync::{Arc, Mutex},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, RwLock, Weak,
+    },
     task::{Context, Poll, Waker},
-    time::Instant,
+    time::{Duration, Instant},
 };
 
-use log::error;
+use log::{debug, error};
 use qvopenapi::{error::*, models::*};
+use serde::de::DeserializeOwned;
 use serde_json::{json, Value};
+use tokio::time::Sleep;
+
+use crate::{rate_limiter::*, tr_index_pool::*};
+
+pub type TrContextMap = HashMap<i32, Arc<TrContext>>;
+
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/**
+ * 요청별 옵션
+ */
+#[derive(Debug, Clone)]
+pub struct RequestOptions {
+    // 응답(CA_RECEIVECOMPLETE)까지 기다리는 시간. 지나면 RequestTimeoutError
+    pub timeout: Duration,
+    // TR index가 모두 사용 중일 때 true면 빌 때까지 기다리고 (timeout에 포함), false면 TransactionPoolFullError
+    pub wait_for_index: bool,
+    // 지정하지 않으면 주문 TR은 High, 나머지는 Normal
+    pub priority: Option<RequestPriority>,
+}
+
+impl RequestOptions {
+    pub fn with_timeout(timeout: Duration) -> RequestOptions {
+        RequestOptions {
+            timeout,
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for RequestOptions {
+    fn default() -> Self {
+        RequestOptions {
+            timeout: DEFAULT_TIMEOUT,
+            wait_for_index: false,
+            priority: None,
+        }
+    }
+}
+
+/**
+ * 전송 허가와 TR index를 기다렸다가 요청을 보내는 Future
+ */
+pub type PendingRequest =
+    Pin<Box<dyn Future<Output = Result<Arc<TrContext>, QvOpenApiError>> + Send>>;
 
 pub struct TrContext {
     pub tr_index: i32,
     pub tr_type: TrType,
     pub request_timestamp: Instant,
     pub status: Mutex<TrContextStatus>,
+    // 풀에서 받은 index는 context가 완전히 사라질 때 반환
+    index_pool: Option<Arc<TrIndexPool>>,
+    // TrIndexPool::begin()에서 받은 이 요청의 세대. 반환/격리할 때 같은 index를 나중에 받은 요청과 구분
+    generation: u64,
+    // 서버가 이 index로 더 보낼 것이 없음 (CA_RECEIVECOMPLETE, CA_RECEIVEERROR, 접속 끊김, 전송 실패)
+    // 아니면 늦은 응답이 올 수 있으므로 index를 반환하지 않고 격리
+    is_settled: AtomicBool,
 }
 
 impl TrContext {
-    pub fn new(tr_index: i32, tr_type: TrType) -> TrContext {
+    pub fn new(tr_index: i32, tr_type: TrType, index_pool: Option<Arc<TrIndexPool>>) -> TrContext {
+        let generation = match &index_pool {
+            Some(index_pool) => index_pool.begin(tr_index),
+            None => 0,
+        };
         TrContext {
             tr_index,
             tr_type,
             request_timestamp: Instant::now(),
             status: Mutex::new(TrContextStatus::new()),
+            index_pool,
+            generation,
+            is_settled: AtomicBool::new(false),
         }
     }
 
+    pub fn settle(&self) {
+        self.is_settled.store(true, Ordering::Release);
+    }
+
     pub fn on_connect(&self, res: &ConnectResponse) -> bool {
         if !matches!(self.tr_type, TrType::CONNECT) {
             error!("Expected tr type CONNECT, but {:?} found", self.tr_type);
@@ -63,12 +131,14 @@ impl TrContext {
             return false;
         }
 
+        self.settle();
         let mut status = self.status.lock().unwrap();
         status.set_done();
         return true;
     }
 
     pub fn on_disconnect(&self) -> bool {
+        self.settle();
         self.on_custom_error(QvOpenApiError::NotConnectedError)
     }
 
@@ -87,6 +157,7 @@ impl TrContext {
     }
 
     pub fn on_error_response(&self, err: ErrorResponse) -> bool {
+        self.settle();
         let mut status = self.status.lock().unwrap();
         status.errors.push(err);
         return false;
@@ -108,6 +179,18 @@ impl TrContext {
     }
 }
 
+impl Drop for TrContext {
+    fn drop(&mut self) {
+        if let Some(index_pool) = &self.index_pool {
+            if self.is_settled.load(Ordering::Acquire) {
+                index_pool.release(self.tr_index, self.generation);
+            } else {
+                index_pool.quarantine(self.tr_index, self.generation);
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum TrType {
     CONNECT,
@@ -155,27 +238,101 @@ impl TrContextStatus {
     }
 }
 
+/**
+ * 응답을 기다리는 Future
+ * 처음 poll될 때 tokio 타이머를 걸고, timeout이 지나면 RequestTimeoutError로 끝남
+ * 끝나기 전에 drop되면 tr_context_map에서 바로 빠지므로 늦게 온 응답은 버려짐
+ * 이때 TR index는 늦은 응답이 끝날 때까지 TrIndexPool에 격리되어 새 요청에 쓰이지 않음
+ */
 pub struct TrFuture {
-    context: Result<Arc<TrContext>, QvOpenApiError>,
+    state: TrFutureState,
+    context_map: Weak<RwLock<TrContextMap>>,
+    deadline: Instant,
+    sleep: Option<Pin<Box<Sleep>>>,
+}
+
+enum TrFutureState {
+    // 전송 허가(RateLimiter) 또는 빈 TR index(RequestOptions.wait_for_index)를 기다리는 중
+    Pending(PendingRequest),
+    Started(Result<Arc<TrContext>, QvOpenApiError>),
 }
 
 impl Future for TrFuture {
     type Output = Result<Value, QvOpenApiError>;
 
-    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        match &self.context {
-            Ok(context) => {
-                let mut status = context.status.lock().unwrap();
-                Self::poll_for_status(&mut status, cx)
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if let TrFutureState::Pending(pending) = &mut self.state {
+            match pending.as_mut().poll(cx) {
+                Poll::Ready(context) => {
+                    self.state = TrFutureState::Started(context);
+                }
+                Poll::Pending => {
+                    if self.poll_timer(cx).is_ready() {
+                        self.state =
+                            TrFutureState::Started(Err(QvOpenApiError::RequestTimeoutError));
+                    } else {
+                        return Poll::Pending;
+                    }
+                }
             }
-            Err(e) => Poll::Ready(Err(e.clone())),
         }
+
+        let context = match &self.state {
+            TrFutureState::Started(Ok(context)) => context.clone(),
+            TrFutureState::Started(Err(e)) => return Poll::Ready(Err(e.clone())),
+            TrFutureState::Pending(_) => return Poll::Pending,
+        };
+
+        if let Poll::Ready(output) = Self::poll_for_status(&mut context.status.lock().unwrap(), cx)
+        {
+            return Poll::Ready(output);
+        }
+
+        if self.poll_timer(cx).is_ready() {
+            context.on_timeout();
+            self.remove_context(&context);
+            return Self::poll_for_status(&mut context.status.lock().unwrap(), cx);
+        }
+        Poll::Pending
     }
 }
 
 impl TrFuture {
-    pub fn new(context: Result<Arc<TrContext>, QvOpenApiError>) -> TrFuture {
-        TrFuture { context: context }
+    pub fn new(
+        context: Result<Arc<TrContext>, QvOpenApiError>,
+        context_map: &Arc<RwLock<TrContextMap>>,
+        options: &RequestOptions,
+    ) -> TrFuture {
+        TrFuture {
+            state: TrFutureState::Started(context),
+            context_map: Arc::downgrade(context_map),
+            deadline: Instant::now() + options.timeout,
+            sleep: None,
+        }
+    }
+
+    /**
+     * pending이 끝나야 요청이 나감. 기다리는 시간도 timeout에 포함
+     */
+    pub fn pending(
+        pending: PendingRequest,
+        context_map: &Arc<RwLock<TrContextMap>>,
+        options: &RequestOptions,
+    ) -> TrFuture {
+        TrFuture {
+            state: TrFutureState::Pending(pending),
+            context_map: Arc::downgrade(context_map),
+            deadline: Instant::now() + options.timeout,
+            sleep: None,
+        }
+    }
+
+    fn poll_timer(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        let deadline = self.deadline;
+        self.sleep
+            .get_or_insert_with(|| Box::pin(tokio::time::sleep_until(deadline.into())))
+            .as_mut()
+            .poll(cx)
     }
 
     fn poll_for_status(
@@ -195,4 +352,63 @@ impl TrFuture {
             Poll::Pending
         }
     }
+
+    // 같은 tr_index가 다른 요청에 재사용되었을 수 있으므로 같은 context일 때만 제거
+    fn remove_context(&self, context: &Arc<TrContext>) {
+        if let Some(context_map) = self.context_map.upgrade() {
+            let mut context_map = context_map.write().unwrap();
+            if context_map
+                .get(&context.tr_index)
+                .is_some_and(|found| Arc::ptr_eq(found, context))
+            {
+                context_map.remove(&context.tr_index);
+            }
+        }
+    }
+}
+
+impl Drop for TrFuture {
+    fn drop(&mut self) {
+        let context = match &self.state {
+            TrFutureState::Started(Ok(context)) => context.clone(),
+            _ => return,
+        };
+        // 접속은 취소하지 않음 (CA_CONNECTED가 오면 접속 정보를 저장해야 함)
+        if !matches!(context.tr_type, TrType::QUERY) || context.status.lock().unwrap().is_done {
+            return;
+        }
+        debug!("Request cancelled (tr_index: {})", context.tr_index);
+        // 이 Future가 마지막 참조이므로 context가 drop되면서 index가 격리됨
+        // 그 사이에 늦은 응답이 끝났으면 TrIndexPool::settle()이 기록해 두어 바로 반환됨
+        self.remove_context(&context);
+    }
+}
+
+/**
+ * TrFuture의 JSON 출력을 요청별 Output 타입으로 변환
+ */
+pub struct TypedTrFuture<R> {
+    inner: TrFuture,
+    _output: PhantomData<fn() -> R>,
+}
+
+impl<R> TypedTrFuture<R> {
+    pub fn new(inner: TrFuture) -> TypedTrFuture<R> {
+        TypedTrFuture {
+            inner,
+            _output: PhantomData,
+        }
+    }
+}
+
+impl<R: DeserializeOwned> Future for TypedTrFuture<R> {
+    type Output = Result<R, QvOpenApiError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match Pin::new(&mut self.inner).poll(cx) {
+            Poll::Ready(Ok(output)) => Poll::Ready(parse_typed_output(output)),
+            Poll::Ready(Err(e)) => Poll::Ready(Err(e)),
+            Poll::Pending => Poll::Pending,
+        }
+    }
 }
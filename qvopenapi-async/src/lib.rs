@@ -1,6 +1,14 @@
 mod client;
 mod context;
+mod rate_limiter;
+mod reconnect;
+mod stream;
+mod tr_index_pool;
 
 pub use client::QvOpenApiAsyncClient;
+pub use context::{RequestOptions, TrFuture, TypedTrFuture};
 pub use qvopenapi::error;
 pub use qvopenapi::models;
+pub use rate_limiter::{QueueDepth, RateBudget, RateLimitConfig, RequestPriority};
+pub use reconnect::{ConnectionState, Credentials, ReconnectConfig};
+pub use stream::{OverflowPolicy, RealtimeEvent, SiseStream, SiseStreamConfig};
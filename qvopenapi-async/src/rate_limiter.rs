@@ -0,0 +1,372 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+    time::{Duration, Instant},
+};
+
+use qvopenapi::{error::*, models::*};
+use serde::Serialize;
+use tokio::time::Sleep;
+
+// 주문 TR. RequestOptions.priority를 지정하지 않으면 High로 보냄
+const ORDER_TR_CODES: [&str; 9] = [
+    TR_CODE_C8101,
+    TR_CODE_C8102,
+    TR_CODE_C8103,
+    TR_CODE_C8104,
+    TR_CODE_C8141,
+    TR_CODE_C8142,
+    TR_CODE_C8143,
+    TR_CODE_S8301,
+    TR_CODE_S8302,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RequestPriority {
+    // 주문. 대기 중인 Normal 요청보다 먼저 보냄
+    High,
+    // 조회
+    Normal,
+}
+
+impl RequestPriority {
+    pub fn for_tr_code(tr_code: &str) -> RequestPriority {
+        if ORDER_TR_CODES.contains(&tr_code) {
+            RequestPriority::High
+        } else {
+            RequestPriority::Normal
+        }
+    }
+}
+
+/**
+ * period 동안 capacity건 (token bucket)
+ * 한 번에 capacity건까지 몰아서 보낼 수 있고, 이후에는 period / capacity마다 1건씩 채워짐
+ */
+#[derive(Debug, Clone, Copy)]
+pub struct RateBudget {
+    pub capacity: u32,
+    pub period: Duration,
+}
+
+impl RateBudget {
+    pub fn per_second(capacity: u32) -> RateBudget {
+        RateBudget {
+            capacity,
+            period: Duration::from_secs(1),
+        }
+    }
+}
+
+/**
+ * 기본값은 제한 없음
+ */
+#[derive(Debug, Clone, Default)]
+pub struct RateLimitConfig {
+    // 모든 TR 합계
+    pub global: Option<RateBudget>,
+    // TR 코드별 (소문자, e.g. "c8201")
+    pub per_tr_code: HashMap<String, RateBudget>,
+}
+
+/**
+ * 전송을 기다리는 요청 수
+ */
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct QueueDepth {
+    pub high: usize,
+    pub normal: usize,
+}
+
+struct TokenBucket {
+    budget: RateBudget,
+    tokens: f64,
+    updated: Instant,
+}
+
+impl TokenBucket {
+    fn new(budget: RateBudget, now: Instant) -> TokenBucket {
+        TokenBucket {
+            budget,
+            tokens: budget.capacity as f64,
+            updated: now,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        let capacity = self.budget.capacity as f64;
+        self.tokens =
+            (self.tokens + elapsed * capacity / self.budget.period.as_secs_f64()).min(capacity);
+        self.updated = now;
+    }
+
+    fn has_token(&self) -> bool {
+        self.tokens >= 1.0
+    }
+
+    fn next_token_at(&self) -> Instant {
+        if self.has_token() {
+            return self.updated;
+        }
+        let per_token = self.budget.period.as_secs_f64() / self.budget.capacity as f64;
+        self.updated + Duration::from_secs_f64((1.0 - self.tokens) * per_token)
+    }
+}
+
+struct Ticket {
+    id: u64,
+    tr_code: String,
+    waker: Option<Waker>,
+}
+
+struct RateLimiterState {
+    global: Option<TokenBucket>,
+    per_tr_code: HashMap<String, TokenBucket>,
+    high: VecDeque<Ticket>,
+    normal: VecDeque<Ticket>,
+    granted: HashSet<u64>,
+    next_ticket_id: u64,
+}
+
+/**
+ * QvOpenApiAsyncClient::query와 delegate 사이의 전송 순서 관리
+ * 서버의 초당 TR 제한을 넘기면 데이터 대신 메시지만 오므로, 예산이 찰 때까지 요청을 큐에 잡아둠
+ * High 큐를 먼저 보고, 같은 큐 안에서는 TR 코드별로 들어온 순서를 지킴
+ */
+pub struct RateLimiter {
+    state: Mutex<RateLimiterState>,
+}
+
+impl RateLimiter {
+    pub fn new(config: RateLimitConfig) -> Result<RateLimiter, QvOpenApiError> {
+        let now = Instant::now();
+        let (global, per_tr_code) = Self::build_buckets(&config, now)?;
+        Ok(RateLimiter {
+            state: Mutex::new(RateLimiterState {
+                global,
+                per_tr_code,
+                high: VecDeque::new(),
+                normal: VecDeque::new(),
+                granted: HashSet::new(),
+                next_ticket_id: 0,
+            }),
+        })
+    }
+
+    /**
+     * 예산을 바꾸면 버킷은 가득 찬 상태로 다시 시작
+     */
+    pub fn set_config(&self, config: RateLimitConfig) -> Result<(), QvOpenApiError> {
+        let now = Instant::now();
+        let (global, per_tr_code) = Self::build_buckets(&config, now)?;
+        let mut state = self.state.lock().unwrap();
+        state.global = global;
+        state.per_tr_code = per_tr_code;
+        state.schedule(now);
+        Ok(())
+    }
+
+    /**
+     * 큐에 넣고 바로 보낼 수 있으면 그 자리에서 허가
+     */
+    pub fn enqueue(self: &Arc<Self>, tr_code: &str, priority: RequestPriority) -> RatePermit {
+        let mut state = self.state.lock().unwrap();
+        state.next_ticket_id += 1;
+        let ticket = Ticket {
+            id: state.next_ticket_id,
+            tr_code: tr_code.to_lowercase(),
+            waker: None,
+        };
+        let id = ticket.id;
+        match priority {
+            RequestPriority::High => state.high.push_back(ticket),
+            RequestPriority::Normal => state.normal.push_back(ticket),
+        }
+        state.schedule(Instant::now());
+        RatePermit {
+            limiter: self.clone(),
+            id,
+            is_granted: state.granted.remove(&id),
+            sleep: None,
+        }
+    }
+
+    pub fn get_queue_depth(&self) -> QueueDepth {
+        let state = self.state.lock().unwrap();
+        QueueDepth {
+            high: state.high.len(),
+            normal: state.normal.len(),
+        }
+    }
+
+    fn build_buckets(
+        config: &RateLimitConfig,
+        now: Instant,
+    ) -> Result<(Option<TokenBucket>, HashMap<String, TokenBucket>), QvOpenApiError> {
+        let budgets = config.global.iter().chain(config.per_tr_code.values());
+        for budget in budgets {
+            if budget.capacity == 0 || budget.period.is_zero() {
+                return Err(QvOpenApiError::BadRequestError {
+                    message: "capacity and period of a rate budget must be greater than 0".into(),
+                });
+            }
+        }
+
+        let global = config.global.map(|budget| TokenBucket::new(budget, now));
+        let per_tr_code = config
+            .per_tr_code
+            .iter()
+            .map(|(tr_code, budget)| (tr_code.to_lowercase(), TokenBucket::new(*budget, now)))
+            .collect();
+        Ok((global, per_tr_code))
+    }
+}
+
+impl RateLimiterState {
+    /**
+     * 지금 보낼 수 있는 요청을 모두 허가하고 깨움
+     */
+    fn schedule(&mut self, now: Instant) {
+        if let Some(global) = &mut self.global {
+            global.refill(now);
+        }
+        for bucket in self.per_tr_code.values_mut() {
+            bucket.refill(now);
+        }
+
+        for is_high in [true, false] {
+            let mut i = 0;
+            loop {
+                if matches!(&self.global, Some(global) if !global.has_token()) {
+                    return;
+                }
+                let lane = if is_high { &self.high } else { &self.normal };
+                let tr_code = match lane.get(i) {
+                    Some(ticket) => &ticket.tr_code,
+                    None => break,
+                };
+                // 다른 TR 코드는 앞 요청이 TR 코드 예산에 막혀 있어도 먼저 나갈 수 있음
+                let bucket = self.per_tr_code.get_mut(tr_code);
+                if matches!(&bucket, Some(bucket) if !bucket.has_token()) {
+                    i += 1;
+                    continue;
+                }
+                if let Some(bucket) = bucket {
+                    bucket.tokens -= 1.0;
+                }
+                if let Some(global) = &mut self.global {
+                    global.tokens -= 1.0;
+                }
+
+                let lane = if is_high {
+                    &mut self.high
+                } else {
+                    &mut self.normal
+                };
+                let ticket = lane.remove(i).unwrap();
+                self.granted.insert(ticket.id);
+                if let Some(waker) = ticket.waker {
+                    waker.wake();
+                }
+            }
+        }
+    }
+
+    fn find_ticket(&mut self, id: u64) -> Option<&mut Ticket> {
+        self.high
+            .iter_mut()
+            .chain(self.normal.iter_mut())
+            .find(|ticket| ticket.id == id)
+    }
+
+    // 이 요청이 보낼 수 있게 되는 가장 이른 시각. 그때도 다른 요청에 밀렸으면 다시 계산
+    fn next_ready_at(&self, tr_code: &str) -> Instant {
+        let global = self.global.as_ref().map(|global| global.next_token_at());
+        let bucket = self
+            .per_tr_code
+            .get(tr_code)
+            .map(|bucket| bucket.next_token_at());
+        global
+            .into_iter()
+            .chain(bucket)
+            .max()
+            .unwrap_or_else(Instant::now)
+    }
+}
+
+/**
+ * 전송 허가를 기다리는 Future
+ * drop되면 큐에서 빠짐
+ */
+pub struct RatePermit {
+    limiter: Arc<RateLimiter>,
+    id: u64,
+    is_granted: bool,
+    sleep: Option<Pin<Box<Sleep>>>,
+}
+
+impl RatePermit {
+    pub fn is_granted(&self) -> bool {
+        self.is_granted
+    }
+}
+
+impl Future for RatePermit {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.is_granted {
+            return Poll::Ready(());
+        }
+
+        let ready_at = {
+            let mut state = self.limiter.state.lock().unwrap();
+            state.schedule(Instant::now());
+            if state.granted.remove(&self.id) {
+                drop(state);
+                self.is_granted = true;
+                return Poll::Ready(());
+            }
+            let ticket = match state.find_ticket(self.id) {
+                Some(ticket) => ticket,
+                // 큐에도 없고 허가도 안 된 경우는 없음
+                None => return Poll::Ready(()),
+            };
+            if !matches!(&ticket.waker, Some(w) if w.will_wake(cx.waker())) {
+                ticket.waker = Some(cx.waker().clone());
+            }
+            let tr_code = ticket.tr_code.clone();
+            state.next_ready_at(&tr_code)
+        };
+
+        let deadline = tokio::time::Instant::from_std(ready_at);
+        let sleep = self
+            .sleep
+            .get_or_insert_with(|| Box::pin(tokio::time::sleep_until(deadline)));
+        if sleep.deadline() != deadline {
+            sleep.as_mut().reset(deadline);
+        }
+        if sleep.as_mut().poll(cx).is_ready() {
+            // 다른 요청이 먼저 가져가지 않았으면 다음 poll에서 허가됨
+            cx.waker().wake_by_ref();
+        }
+        Poll::Pending
+    }
+}
+
+impl Drop for RatePermit {
+    fn drop(&mut self) {
+        if self.is_granted {
+            return;
+        }
+        let mut state = self.limiter.state.lock().unwrap();
+        state.high.retain(|ticket| ticket.id != self.id);
+        state.normal.retain(|ticket| ticket.id != self.id);
+        state.granted.remove(&self.id);
+    }
+}
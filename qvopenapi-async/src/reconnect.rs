@@ -0,0 +1,246 @@
+use std::{
+    fmt,
+    sync::{Arc, RwLock, Weak},
+    time::Duration,
+};
+
+use log::*;
+use qvopenapi::{error::*, models::*};
+use serde::Serialize;
+use tokio::{
+    sync::{broadcast, Notify},
+    task::JoinHandle,
+};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::client::QvOpenApiAsyncClient;
+
+const STATE_CHANNEL_SIZE: usize = 16;
+
+/**
+ * 재접속에 쓸 로그인 정보
+ * drop되면 비밀번호를 메모리에서 지움
+ */
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct Credentials {
+    #[zeroize(skip)]
+    account_type: AccountType,
+    id: String,
+    password: String,
+    cert_password: String,
+}
+
+impl Credentials {
+    pub fn new(
+        account_type: AccountType,
+        id: &str,
+        password: &str,
+        cert_password: &str,
+    ) -> Credentials {
+        Credentials {
+            account_type,
+            id: id.into(),
+            password: password.into(),
+            cert_password: cert_password.into(),
+        }
+    }
+}
+
+impl From<&ConnectRequest> for Credentials {
+    fn from(req: &ConnectRequest) -> Self {
+        Credentials::new(req.account_type, &req.id, &req.password, &req.cert_password)
+    }
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Credentials")
+            .field("account_type", &self.account_type)
+            .field("id", &self.id)
+            .finish_non_exhaustive()
+    }
+}
+
+/**
+ * 재시도 간격은 initial_backoff부터 두 배씩 늘어나고 max_backoff에서 멈춤
+ */
+#[derive(Debug, Clone)]
+pub struct ReconnectConfig {
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    // None이면 접속될 때까지 계속 시도
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for ReconnectConfig {
+    fn default() -> Self {
+        ReconnectConfig {
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+            max_attempts: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum ConnectionState {
+    Connected,
+    Disconnected,
+    // CA_SOCKETERROR
+    ConnectionLost,
+    // delay_ms 뒤에 attempt번째 접속 시도
+    Reconnecting { attempt: u32, delay_ms: u64 },
+    ReconnectFailed { attempt: u32, message: String },
+    // subscriptions: 다시 등록한 실시간 구독 수
+    Reconnected { attempt: u32, subscriptions: usize },
+    // max_attempts를 다 쓰고 포기
+    GaveUp { attempts: u32 },
+}
+
+/**
+ * 접속 상태 변경을 구독자에게 전달하고 마지막 상태를 기억
+ */
+pub(crate) struct ConnectionStateNotifier {
+    sender: broadcast::Sender<ConnectionState>,
+    last: RwLock<ConnectionState>,
+}
+
+impl ConnectionStateNotifier {
+    pub(crate) fn new() -> ConnectionStateNotifier {
+        let (sender, _) = broadcast::channel(STATE_CHANNEL_SIZE);
+        ConnectionStateNotifier {
+            sender,
+            last: RwLock::new(ConnectionState::Disconnected),
+        }
+    }
+
+    pub(crate) fn emit(&self, state: ConnectionState) {
+        debug!("Connection state: {:?}", state);
+        *self.last.write().unwrap() = state.clone();
+        // 구독자가 없으면 버려짐
+        let _ = self.sender.send(state);
+    }
+
+    pub(crate) fn subscribe(&self) -> broadcast::Receiver<ConnectionState> {
+        self.sender.subscribe()
+    }
+
+    pub(crate) fn get_last(&self) -> ConnectionState {
+        self.last.read().unwrap().clone()
+    }
+
+    // 접속이 끊긴 뒤 재접속에 성공하거나 포기하기 전까지
+    pub(crate) fn is_reconnecting(&self) -> bool {
+        matches!(
+            *self.last.read().unwrap(),
+            ConnectionState::ConnectionLost
+                | ConnectionState::Reconnecting { .. }
+                | ConnectionState::ReconnectFailed { .. }
+        )
+    }
+}
+
+/**
+ * 실행 중인 재접속 task
+ * drop되면 task를 멈추고, task가 들고 있던 Credentials도 함께 지워짐
+ */
+pub(crate) struct ReconnectHandle {
+    pub(crate) connection_lost: Arc<Notify>,
+    pub(crate) task: JoinHandle<()>,
+}
+
+impl Drop for ReconnectHandle {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/**
+ * CA_SOCKETERROR가 올 때마다 backoff를 두고 다시 로그인
+ * 이미 한 번 로그인에 성공한 정보로만 시작하므로 비밀번호 오류로 계정이 잠길 일은 없다고 봄
+ */
+pub(crate) async fn supervise(
+    client: Weak<QvOpenApiAsyncClient>,
+    credentials: Credentials,
+    config: ReconnectConfig,
+    connection_lost: Arc<Notify>,
+    notifier: Arc<ConnectionStateNotifier>,
+) {
+    loop {
+        connection_lost.notified().await;
+        // 재접속 중에 온 CA_SOCKETERROR로 남은 알림이면 이미 접속되어 있음
+        if client
+            .upgrade()
+            .is_some_and(|client| client.get_connect_info().is_ok())
+        {
+            continue;
+        }
+
+        let mut delay = config.initial_backoff;
+        let mut attempt: u32 = 0;
+        loop {
+            if config.max_attempts.is_some_and(|max| attempt >= max) {
+                notifier.emit(ConnectionState::GaveUp { attempts: attempt });
+                break;
+            }
+            attempt += 1;
+
+            notifier.emit(ConnectionState::Reconnecting {
+                attempt,
+                delay_ms: delay.as_millis() as u64,
+            });
+            tokio::time::sleep(delay).await;
+
+            let client = match client.upgrade() {
+                Some(client) => client,
+                None => return,
+            };
+            match try_connect(&client, &credentials).await {
+                Ok(_) => {
+                    notifier.emit(ConnectionState::Reconnected {
+                        attempt,
+                        subscriptions: client.get_reattached_subscription_count(),
+                    });
+                    break;
+                }
+                Err(err) => {
+                    warn!("Reconnect attempt {} failed: {}", attempt, err);
+                    notifier.emit(ConnectionState::ReconnectFailed {
+                        attempt,
+                        message: err.to_string(),
+                    });
+                }
+            }
+            delay = (delay * 2).min(config.max_backoff);
+        }
+    }
+}
+
+async fn try_connect(
+    client: &QvOpenApiAsyncClient,
+    credentials: &Credentials,
+) -> Result<(), QvOpenApiError> {
+    // 사용자가 그 사이에 직접 접속한 경우
+    if client.get_connect_info().is_ok() {
+        return Ok(());
+    }
+
+    let output = client
+        .connect(
+            credentials.account_type,
+            &credentials.id,
+            &credentials.password,
+            &credentials.cert_password,
+        )
+        .await;
+    match output {
+        Ok(output) if !output["result"]["connect_info"].is_null() => Ok(()),
+        Ok(output) => match serde_json::from_value(output["error_type"].clone()) {
+            Ok(Some(err)) => Err(err),
+            _ => Err(QvOpenApiError::NotConnectedError),
+        },
+        Err(QvOpenApiError::AlreadyConnectedError) => Ok(()),
+        Err(err) => Err(err),
+    }
+}
@@ -0,0 +1,195 @@
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+use futures_core::Stream;
+use log::*;
+use qvopenapi::{error::*, models::*, AbstractQvOpenApiClient, SubscriptionId};
+
+pub type RealtimeEvent = Result<SiseResponse, QvOpenApiError>;
+
+const DEFAULT_BUFFER_SIZE: usize = 1024;
+
+/**
+ * 버퍼가 가득 찼을 때의 처리
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    // 가장 오래된 이벤트를 버림
+    DropOldest,
+    // SiseBufferOverflowError를 반환하고 스트림을 종료
+    Error,
+}
+
+#[derive(Debug, Clone)]
+pub struct SiseStreamConfig {
+    pub buffer_size: usize,
+    pub overflow_policy: OverflowPolicy,
+}
+
+impl Default for SiseStreamConfig {
+    fn default() -> Self {
+        SiseStreamConfig {
+            buffer_size: DEFAULT_BUFFER_SIZE,
+            overflow_policy: OverflowPolicy::DropOldest,
+        }
+    }
+}
+
+struct SiseStreamStatus {
+    config: SiseStreamConfig,
+    buffer: VecDeque<SiseResponse>,
+    waker: Option<Waker>,
+    // 버퍼를 다 비운 뒤에 반환하고 스트림을 종료
+    error: Option<QvOpenApiError>,
+    is_done: bool,
+    dropped: u64,
+}
+
+/**
+ * 실시간 구독 하나에 대응하는 Stream
+ * drop되면 구독을 취소하고, 해당 종목의 마지막 구독이었으면 wmcaDetach까지 호출됨
+ */
+pub struct SiseStream {
+    delegate: Arc<dyn AbstractQvOpenApiClient + Send + Sync>,
+    subscription_id: SubscriptionId,
+    status: Arc<Mutex<SiseStreamStatus>>,
+}
+
+impl SiseStream {
+    pub fn new(
+        delegate: Arc<dyn AbstractQvOpenApiClient + Send + Sync>,
+        kind: SiseKind,
+        codes: &[&str],
+        config: SiseStreamConfig,
+    ) -> Result<SiseStream, QvOpenApiError> {
+        if config.buffer_size == 0 {
+            return Err(QvOpenApiError::BadRequestError {
+                message: "buffer_size must be greater than 0".into(),
+            });
+        }
+
+        let status = Arc::new(Mutex::new(SiseStreamStatus {
+            buffer: VecDeque::with_capacity(config.buffer_size),
+            config,
+            waker: None,
+            error: None,
+            is_done: false,
+            dropped: 0,
+        }));
+
+        let cloned_status = status.clone();
+        let subscription_id = delegate.subscribe(
+            kind,
+            codes,
+            Box::new(move |res| {
+                let mut status = cloned_status.lock().unwrap();
+                match res {
+                    Ok(res) => status.push(res),
+                    Err(err) => status.fail(err.clone()),
+                }
+            }),
+        )?;
+
+        Ok(SiseStream {
+            delegate,
+            subscription_id,
+            status,
+        })
+    }
+
+    pub fn get_subscription_id(&self) -> SubscriptionId {
+        self.subscription_id
+    }
+
+    /**
+     * DropOldest 정책으로 지금까지 버려진 이벤트 수
+     */
+    pub fn get_dropped_count(&self) -> u64 {
+        self.status.lock().unwrap().dropped
+    }
+}
+
+impl SiseStreamStatus {
+    fn push(&mut self, res: &SiseResponse) {
+        if self.is_done || self.error.is_some() {
+            return;
+        }
+
+        if self.buffer.len() >= self.config.buffer_size {
+            match self.config.overflow_policy {
+                OverflowPolicy::DropOldest => {
+                    self.buffer.pop_front();
+                    self.dropped += 1;
+                }
+                OverflowPolicy::Error => {
+                    warn!(
+                        "Real-time buffer overflowed (bc_code: {}, code: {})",
+                        res.bc_code, res.code
+                    );
+                    self.fail(QvOpenApiError::SiseBufferOverflowError {
+                        capacity: self.config.buffer_size,
+                    });
+                    return;
+                }
+            }
+        }
+
+        self.buffer.push_back(res.clone());
+        self.wake();
+    }
+
+    fn fail(&mut self, err: QvOpenApiError) {
+        if self.is_done || self.error.is_some() {
+            return;
+        }
+        self.error = Some(err);
+        self.wake();
+    }
+
+    fn wake(&mut self) {
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl Stream for SiseStream {
+    type Item = RealtimeEvent;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut status = self.status.lock().unwrap();
+        if status.is_done {
+            return Poll::Ready(None);
+        }
+
+        // 버퍼를 다 비운 뒤에 오류를 알림
+        match status.buffer.pop_front() {
+            Some(res) => Poll::Ready(Some(Ok(res))),
+            None if status.error.is_some() => {
+                status.is_done = true;
+                Poll::Ready(status.error.take().map(Err))
+            }
+            None => {
+                if !matches!(&status.waker, Some(w) if w.will_wake(cx.waker())) {
+                    status.waker = Some(cx.waker().clone());
+                }
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl Drop for SiseStream {
+    fn drop(&mut self) {
+        if let Err(err) = self.delegate.unsubscribe(self.subscription_id) {
+            error!(
+                "Failed to unsubscribe {} on stream drop: {}",
+                self.subscription_id, err
+            );
+        }
+    }
+}
@@ -0,0 +1,365 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+    time::{Duration, Instant},
+};
+
+use log::*;
+use qvopenapi::error::*;
+use tokio::time::Sleep;
+
+// 격리한 index에 CA_RECEIVECOMPLETE/CA_RECEIVEERROR가 끝내 오지 않을 때 다시 쓰기까지 기다리는 시간
+const QUARANTINE_GRACE: Duration = Duration::from_secs(30);
+
+/**
+ * TR index 할당기
+ * 취소되거나 timeout된 요청의 index는 늦은 응답이 새 요청에 섞이지 않도록,
+ * 그 index의 CA_RECEIVECOMPLETE/CA_RECEIVEERROR가 오거나 QUARANTINE_GRACE가 지날 때까지 격리
+ * 빈 index는 기다리는 순서대로 넘겨줌
+ */
+pub struct TrIndexPool {
+    first: i32,
+    last: i32,
+    state: Mutex<TrIndexPoolState>,
+}
+
+struct TrIndexPoolState {
+    free: VecDeque<i32>,
+    // index -> 격리가 풀리는 시각
+    quarantined: HashMap<i32, Instant>,
+    // index -> 그 index로 보낸 요청의 세대. begin()에서 정해지고 반환/격리할 때 지움
+    generations: HashMap<i32, u64>,
+    // 사용 중에 응답이 끝난 (index, 세대). 취소된 요청이 map에서 빠진 직후 응답이 끝났으면 격리하지 않음
+    settled: HashSet<(i32, u64)>,
+    next_generation: u64,
+    // 먼저 기다린 쪽부터 index를 받음. 기다리는 쪽이 있으면 free는 비어 있음
+    waiters: VecDeque<Waiter>,
+    // 넘겨받았지만 아직 poll되지 않은 index
+    handed_off: HashMap<u64, i32>,
+    next_waiter_id: u64,
+}
+
+struct Waiter {
+    id: u64,
+    waker: Option<Waker>,
+}
+
+impl TrIndexPool {
+    pub fn new(first: i32, last: i32) -> TrIndexPool {
+        TrIndexPool {
+            first,
+            last,
+            state: Mutex::new(TrIndexPoolState {
+                free: (first..=last).collect(),
+                quarantined: HashMap::new(),
+                generations: HashMap::new(),
+                settled: HashSet::new(),
+                next_generation: 0,
+                waiters: VecDeque::new(),
+                handed_off: HashMap::new(),
+                next_waiter_id: 0,
+            }),
+        }
+    }
+
+    /**
+     * 빈 index가 없으면 바로 TransactionPoolFullError
+     * 기다리는 쪽이 있으면 빈 index도 없으므로 앞지르지 않음
+     */
+    pub fn try_acquire(&self) -> Result<i32, QvOpenApiError> {
+        let (tr_index, wakers) = {
+            let mut state = self.state.lock().unwrap();
+            let wakers = state.reclaim_expired(Instant::now());
+            (state.free.pop_front(), wakers)
+        };
+        wake_all(wakers);
+        tr_index.ok_or(QvOpenApiError::TransactionPoolFullError)
+    }
+
+    /**
+     * 빈 index가 생길 때까지 기다림
+     * 호출한 순서대로 대기열에 들어감
+     */
+    pub fn acquire(self: &Arc<Self>) -> TrIndexAcquire {
+        let (waiter_id, wakers) = {
+            let mut state = self.state.lock().unwrap();
+            let wakers = state.reclaim_expired(Instant::now());
+            state.next_waiter_id += 1;
+            let id = state.next_waiter_id;
+            match state.free.pop_front() {
+                Some(tr_index) => {
+                    state.handed_off.insert(id, tr_index);
+                }
+                None => state.waiters.push_back(Waiter { id, waker: None }),
+            }
+            (id, wakers)
+        };
+        wake_all(wakers);
+        TrIndexAcquire {
+            pool: self.clone(),
+            waiter_id,
+            is_done: false,
+            sleep: None,
+        }
+    }
+
+    /**
+     * 받은 index로 요청을 보내기 직전에 호출해서 이 요청의 세대를 정함
+     * 그 전에 온 늦은 응답은 이전 요청의 것이므로 이 요청의 settle로 치지 않음
+     */
+    pub fn begin(&self, tr_index: i32) -> u64 {
+        let mut state = self.state.lock().unwrap();
+        state.next_generation += 1;
+        let generation = state.next_generation;
+        state.generations.insert(tr_index, generation);
+        state.settled.retain(|(i, _)| *i != tr_index);
+        generation
+    }
+
+    /**
+     * 응답이 끝난 요청의 index 반환
+     * generation은 begin()에서 받은 값. 이미 다른 요청이 쓰고 있는 index는 반환하지 않음
+     */
+    pub fn release(&self, tr_index: i32, generation: u64) {
+        if !self.contains(tr_index) {
+            return;
+        }
+
+        let waker = {
+            let mut state = self.state.lock().unwrap();
+            if !state.is_current(tr_index, generation) {
+                error!("tr_index {} released twice", tr_index);
+                return;
+            }
+            state.forget(tr_index);
+            state.hand_over(tr_index)
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+
+    /**
+     * 응답을 다 받지 못한 채 끝난 요청(취소, timeout)의 index
+     * settle()이 불리거나 QUARANTINE_GRACE가 지나야 다시 씀
+     */
+    pub fn quarantine(&self, tr_index: i32, generation: u64) {
+        if !self.contains(tr_index) {
+            return;
+        }
+
+        let waker = {
+            let mut state = self.state.lock().unwrap();
+            if !state.is_current(tr_index, generation) {
+                error!("tr_index {} released twice", tr_index);
+                return;
+            }
+            // 이 요청의 세대로 기록된 settle만 인정
+            let settled = state.settled.contains(&(tr_index, generation));
+            state.forget(tr_index);
+            if settled {
+                state.hand_over(tr_index)
+            } else {
+                debug!("Quarantining tr_index {}", tr_index);
+                state
+                    .quarantined
+                    .insert(tr_index, Instant::now() + QUARANTINE_GRACE);
+                // 맨 앞에서 기다리는 쪽이 격리가 풀리는 시각에 맞춰 타이머를 다시 걸도록 깨움
+                state
+                    .waiters
+                    .front()
+                    .and_then(|waiter| waiter.waker.clone())
+            }
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+
+    /**
+     * index의 CA_RECEIVECOMPLETE/CA_RECEIVEERROR가 오면 호출
+     * 격리 중이면 격리를 풀고, 아직 사용 중이면 나중에 quarantine()이 와도 바로 반환되도록 지금 세대로 기록
+     * 받기만 하고 begin() 전인 index는 이전 요청의 응답이므로 무시
+     */
+    pub fn settle(&self, tr_index: i32) {
+        if !self.contains(tr_index) {
+            return;
+        }
+
+        let waker = {
+            let mut state = self.state.lock().unwrap();
+            if state.quarantined.remove(&tr_index).is_none() {
+                if let Some(generation) = state.generations.get(&tr_index).copied() {
+                    state.settled.insert((tr_index, generation));
+                }
+                return;
+            }
+            debug!("tr_index {} settled", tr_index);
+            state.hand_over(tr_index)
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+
+    /**
+     * 접속이 끊기면 늦은 응답도 오지 않으므로 격리를 모두 풂
+     */
+    pub fn settle_all(&self) {
+        let wakers: Vec<Waker> = {
+            let mut state = self.state.lock().unwrap();
+            state.settled.clear();
+            let mut tr_indexes: Vec<i32> = state.quarantined.drain().map(|(i, _)| i).collect();
+            tr_indexes.sort();
+            tr_indexes
+                .into_iter()
+                .filter_map(|tr_index| state.hand_over(tr_index))
+                .collect()
+        };
+        wake_all(wakers);
+    }
+
+    pub fn get_free_count(&self) -> usize {
+        self.state.lock().unwrap().free.len()
+    }
+
+    fn contains(&self, tr_index: i32) -> bool {
+        tr_index >= self.first && tr_index <= self.last
+    }
+}
+
+impl TrIndexPoolState {
+    // 반환했거나 다음 요청이 begin()한 index면 false
+    fn is_current(&self, tr_index: i32, generation: u64) -> bool {
+        self.generations.get(&tr_index) == Some(&generation)
+    }
+
+    fn forget(&mut self, tr_index: i32) {
+        self.generations.remove(&tr_index);
+        self.settled.retain(|(i, _)| *i != tr_index);
+    }
+
+    // 맨 앞에서 기다리는 쪽에 넘기고, 없으면 free로
+    fn hand_over(&mut self, tr_index: i32) -> Option<Waker> {
+        match self.waiters.pop_front() {
+            Some(waiter) => {
+                self.handed_off.insert(waiter.id, tr_index);
+                waiter.waker
+            }
+            None => {
+                self.free.push_back(tr_index);
+                None
+            }
+        }
+    }
+
+    fn reclaim_expired(&mut self, now: Instant) -> Vec<Waker> {
+        let mut expired: Vec<i32> = self
+            .quarantined
+            .iter()
+            .filter(|(_, until)| **until <= now)
+            .map(|(tr_index, _)| *tr_index)
+            .collect();
+        if expired.is_empty() {
+            return Vec::new();
+        }
+        expired.sort();
+
+        let mut wakers = Vec::new();
+        for tr_index in expired {
+            warn!(
+                "No response for quarantined tr_index {}, reusing it",
+                tr_index
+            );
+            self.quarantined.remove(&tr_index);
+            wakers.extend(self.hand_over(tr_index));
+        }
+        wakers
+    }
+
+    fn next_reclaim_at(&self) -> Option<Instant> {
+        self.quarantined.values().min().copied()
+    }
+}
+
+fn wake_all(wakers: Vec<Waker>) {
+    for waker in wakers {
+        waker.wake();
+    }
+}
+
+/**
+ * 빈 index를 기다리는 Future
+ * drop되면 대기열에서 빠지고, 이미 넘겨받은 index는 다음 순서로 넘어감
+ */
+pub struct TrIndexAcquire {
+    pool: Arc<TrIndexPool>,
+    waiter_id: u64,
+    is_done: bool,
+    sleep: Option<Pin<Box<Sleep>>>,
+}
+
+impl Future for TrIndexAcquire {
+    type Output = i32;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let (wakers, reclaim_at) = {
+            let mut state = self.pool.state.lock().unwrap();
+            let wakers = state.reclaim_expired(Instant::now());
+            if let Some(tr_index) = state.handed_off.remove(&self.waiter_id) {
+                drop(state);
+                wake_all(wakers);
+                self.is_done = true;
+                return Poll::Ready(tr_index);
+            }
+            if let Some(waiter) = state
+                .waiters
+                .iter_mut()
+                .find(|waiter| waiter.id == self.waiter_id)
+            {
+                if !matches!(&waiter.waker, Some(w) if w.will_wake(cx.waker())) {
+                    waiter.waker = Some(cx.waker().clone());
+                }
+            }
+            (wakers, state.next_reclaim_at())
+        };
+        wake_all(wakers);
+
+        // 격리된 index만 남은 경우 격리가 풀리는 시각에 다시 확인
+        if let Some(reclaim_at) = reclaim_at {
+            let deadline = tokio::time::Instant::from_std(reclaim_at);
+            let sleep = self
+                .sleep
+                .get_or_insert_with(|| Box::pin(tokio::time::sleep_until(deadline)));
+            if sleep.deadline() != deadline {
+                sleep.as_mut().reset(deadline);
+            }
+            if sleep.as_mut().poll(cx).is_ready() {
+                cx.waker().wake_by_ref();
+            }
+        }
+        Poll::Pending
+    }
+}
+
+impl Drop for TrIndexAcquire {
+    fn drop(&mut self) {
+        if self.is_done {
+            return;
+        }
+        let waker = {
+            let mut state = self.pool.state.lock().unwrap();
+            state.waiters.retain(|waiter| waiter.id != self.waiter_id);
+            match state.handed_off.remove(&self.waiter_id) {
+                Some(tr_index) => state.hand_over(tr_index),
+                None => None,
+            }
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
@@ -13,6 +13,12 @@ pub fn convert_error(err: QvOpenApiError) -> Result<reply::WithStatus<reply::Jso
             }),
             StatusCode::BAD_REQUEST,
         )),
+        QvOpenApiError::BadRequestError { .. } => Ok(reply::with_status(
+            reply::json(&HttpMessageResponse {
+                message: err.to_string(),
+            }),
+            StatusCode::BAD_REQUEST,
+        )),
         err => Ok(reply::with_status(
             reply::json(&HttpMessageResponse {
                 message: err.to_string(),
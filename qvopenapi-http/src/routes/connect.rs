@@ -1,6 +1,8 @@
 use std::{convert::Infallible, sync::Arc};
 
-use qvopenapi_async::{models::ConnectRequest, QvOpenApiAsyncClient};
+use log::warn;
+use qvopenapi_async::{models::ConnectRequest, Credentials, QvOpenApiAsyncClient, ReconnectConfig};
+use serde::Deserialize;
 use warp::{
     filters::{body, method::post, BoxedFilter},
     http::StatusCode,
@@ -10,9 +12,18 @@ use warp::{
 
 use crate::error;
 
+#[derive(Deserialize)]
+struct HttpConnectRequest {
+    #[serde(flatten)]
+    request: ConnectRequest,
+    // true면 네트워크 오류로 끊겼을 때 같은 정보로 다시 로그인
+    #[serde(default)]
+    reconnect: bool,
+}
+
 pub fn filter_connect(client: Arc<QvOpenApiAsyncClient>) -> BoxedFilter<(impl Reply,)> {
     let cloned = client.clone();
-    let handler = move |req: ConnectRequest| connect(cloned.clone(), req);
+    let handler = move |req: HttpConnectRequest| connect(cloned.clone(), req);
     post()
         .and(warp::path!("connect"))
         .and(body::json())
@@ -22,8 +33,9 @@ pub fn filter_connect(client: Arc<QvOpenApiAsyncClient>) -> BoxedFilter<(impl Re
 
 async fn connect(
     client: Arc<QvOpenApiAsyncClient>,
-    request: ConnectRequest,
+    http_request: HttpConnectRequest,
 ) -> Result<impl Reply, Infallible> {
+    let request = &http_request.request;
     let ret = client
         .connect(
             request.account_type,
@@ -46,5 +58,13 @@ async fn connect(
         ));
     }
 
+    if http_request.reconnect && !result["result"]["connect_info"].is_null() {
+        if let Err(err) =
+            client.enable_reconnect(Credentials::from(request), ReconnectConfig::default())
+        {
+            warn!("Failed to enable reconnect: {}", err);
+        }
+    }
+
     Ok(reply::with_status(reply::json(&result), StatusCode::OK))
 }
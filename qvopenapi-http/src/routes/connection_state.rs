@@ -0,0 +1,24 @@
+use std::{convert::Infallible, sync::Arc};
+
+use qvopenapi_async::QvOpenApiAsyncClient;
+use warp::{
+    filters::{method::get, BoxedFilter},
+    http::StatusCode,
+    reply::{self, Reply},
+    Filter,
+};
+
+pub fn filter_connection_state(client: Arc<QvOpenApiAsyncClient>) -> BoxedFilter<(impl Reply,)> {
+    let cloned = client.clone();
+    let handler = move || connection_state(cloned.clone());
+    get()
+        .and(warp::path!("connection-state"))
+        .and_then(handler)
+        .boxed()
+}
+
+async fn connection_state(client: Arc<QvOpenApiAsyncClient>) -> Result<impl Reply, Infallible> {
+    let result = client.get_connection_state();
+
+    Ok(reply::with_status(reply::json(&result), StatusCode::OK))
+}
@@ -5,13 +5,22 @@ use warp::{filters::BoxedFilter, reply::Reply, Filter};
 
 pub mod connect;
 pub mod connect_info;
+pub mod connection_state;
 pub mod disconnect;
 pub mod query;
+pub mod queue_depth;
+pub mod stream;
 
 pub fn filter(client: Arc<QvOpenApiAsyncClient>) -> BoxedFilter<(impl Reply,)> {
     connect::filter_connect(client.clone())
         .or(query::filter_c8201(client.clone()))
+        .or(query::filter_c8311(client.clone()))
+        .or(query::filter_c8322(client.clone()))
         .or(connect_info::filter_connect_info(client.clone()))
+        .or(connection_state::filter_connection_state(client.clone()))
         .or(disconnect::filter_disconnect(client.clone()))
+        .or(queue_depth::filter_queue_depth(client.clone()))
+        .or(stream::filter_stream(client.clone()))
+        .or(stream::filter_stream_sse(client.clone()))
         .boxed()
 }
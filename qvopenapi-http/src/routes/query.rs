@@ -1,6 +1,7 @@
-use std::{convert::Infallible, sync::Arc};
+use std::{convert::Infallible, sync::Arc, time::Duration};
 
-use qvopenapi_async::{models::*, QvOpenApiAsyncClient};
+use qvopenapi_async::{error::*, models::*, QvOpenApiAsyncClient, RequestOptions};
+use serde_json::Value;
 use warp::{
     filters::{body, method::post, BoxedFilter},
     http::StatusCode,
@@ -10,6 +11,9 @@ use warp::{
 
 use crate::error;
 
+// 연속조회는 페이지마다 따로 timeout이 적용됨
+const HISTORY_TIMEOUT: Duration = Duration::from_secs(60);
+
 pub fn filter_c8201(client: Arc<QvOpenApiAsyncClient>) -> BoxedFilter<(impl Reply,)> {
     let cloned = client.clone();
     let handler = move |req: C8201Request| query_c8201(cloned.clone(), req);
@@ -24,8 +28,57 @@ async fn query_c8201(
     client: Arc<QvOpenApiAsyncClient>,
     request: C8201Request,
 ) -> Result<impl Reply, Infallible> {
-    let ret = client.query(request.into_raw()).await;
+    reply_query_result(client.query(request.into_raw()).await)
+}
+
+pub fn filter_c8311(client: Arc<QvOpenApiAsyncClient>) -> BoxedFilter<(impl Reply,)> {
+    let cloned = client.clone();
+    let handler = move |req: C8311Request| query_c8311(cloned.clone(), req);
+    post()
+        .and(warp::path!("query" / "c8311"))
+        .and(body::json())
+        .and_then(handler)
+        .boxed()
+}
+
+async fn query_c8311(
+    client: Arc<QvOpenApiAsyncClient>,
+    request: C8311Request,
+) -> Result<impl Reply, Infallible> {
+    let raw = match request.into_raw() {
+        Ok(raw) => raw,
+        Err(err) => return error::convert_error(err),
+    };
+    reply_query_result(client.query(raw).await)
+}
+
+pub fn filter_c8322(client: Arc<QvOpenApiAsyncClient>) -> BoxedFilter<(impl Reply,)> {
+    let cloned = client.clone();
+    let handler = move |req: C8322Request| query_c8322(cloned.clone(), req);
+    post()
+        .and(warp::path!("query" / "c8322"))
+        .and(body::json())
+        .and_then(handler)
+        .boxed()
+}
+
+/**
+ * 모든 페이지를 연속조회해서 한 번에 반환
+ */
+async fn query_c8322(
+    client: Arc<QvOpenApiAsyncClient>,
+    request: C8322Request,
+) -> Result<impl Reply, Infallible> {
+    reply_query_result(
+        client
+            .query_c8322_all(&request, &RequestOptions::with_timeout(HISTORY_TIMEOUT))
+            .await,
+    )
+}
 
+fn reply_query_result(
+    ret: Result<Value, QvOpenApiError>,
+) -> Result<reply::WithStatus<reply::Json>, Infallible> {
     if ret.is_err() {
         return error::convert_error(ret.err().unwrap());
     }
@@ -0,0 +1,24 @@
+use std::{convert::Infallible, sync::Arc};
+
+use qvopenapi_async::QvOpenApiAsyncClient;
+use warp::{
+    filters::{method::get, BoxedFilter},
+    http::StatusCode,
+    reply::{self, Reply},
+    Filter,
+};
+
+pub fn filter_queue_depth(client: Arc<QvOpenApiAsyncClient>) -> BoxedFilter<(impl Reply,)> {
+    let cloned = client.clone();
+    let handler = move || queue_depth(cloned.clone());
+    get()
+        .and(warp::path!("queue-depth"))
+        .and_then(handler)
+        .boxed()
+}
+
+async fn queue_depth(client: Arc<QvOpenApiAsyncClient>) -> Result<impl Reply, Infallible> {
+    let result = client.get_queue_depth();
+
+    Ok(reply::with_status(reply::json(&result), StatusCode::OK))
+}
@@ -0,0 +1,290 @@
+use std::{collections::HashMap, convert::Infallible, sync::Arc};
+
+use futures_util::{SinkExt, StreamExt};
+use log::*;
+use qvopenapi_async::{
+    models::*, ConnectionState, QvOpenApiAsyncClient, RealtimeEvent, SiseStream, SiseStreamConfig,
+};
+use serde::{Deserialize, Serialize};
+use tokio::{
+    sync::{broadcast, mpsc},
+    task::JoinHandle,
+};
+use warp::{
+    filters::{
+        method::get,
+        query,
+        sse::{self, Event},
+        ws::{Message, WebSocket, Ws},
+        BoxedFilter,
+    },
+    reply::Reply,
+    Filter,
+};
+
+// 소켓 송신 대기열. 가득 차면 각 SiseStream 버퍼에 쌓이고, 그것도 차면 오래된 시세부터 버려짐
+const SEND_BUFFER_SIZE: usize = 256;
+
+/**
+ * 클라이언트 -> 서버
+ * {"type": "subscribe", "kind": "j8", "codes": ["005930"]}
+ */
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum StreamRequest {
+    Subscribe { kind: SiseKind, codes: Vec<String> },
+    Unsubscribe { kind: SiseKind, codes: Vec<String> },
+}
+
+/**
+ * 서버 -> 클라이언트
+ */
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum StreamMessage {
+    Sise(Box<SiseResponse>),
+    // failed: 등록하지 못한 종목. codes에 있는 종목은 그대로 유지됨
+    Subscribed {
+        kind: SiseKind,
+        codes: Vec<String>,
+        failed: Vec<FailedCode>,
+    },
+    Unsubscribed {
+        kind: SiseKind,
+        codes: Vec<String>,
+    },
+    Error {
+        message: String,
+    },
+    // 접속 상태 변경 (재접속 시도 등)
+    Connection(ConnectionState),
+}
+
+#[derive(Debug, Serialize)]
+struct FailedCode {
+    code: String,
+    message: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct SseQuery {
+    kind: SiseKind,
+    // 쉼표로 구분한 종목코드
+    codes: String,
+}
+
+// 연결마다 (BC, 종목코드)별 SiseStream을 돌리는 task
+// task를 abort하면 SiseStream이 drop되어 구독이 취소되고, 마지막 구독자였으면 wmcaDetach 호출
+type ConnectionSubscriptions = HashMap<(SiseKind, String), JoinHandle<()>>;
+
+pub fn filter_stream(client: Arc<QvOpenApiAsyncClient>) -> BoxedFilter<(impl Reply,)> {
+    let cloned = client.clone();
+    let handler = move |ws: Ws| {
+        let client = cloned.clone();
+        ws.on_upgrade(move |socket| handle_socket(client, socket))
+    };
+    get()
+        .and(warp::path!("stream"))
+        .and(warp::ws())
+        .map(handler)
+        .boxed()
+}
+
+/**
+ * WebSocket을 쓸 수 없는 클라이언트용
+ * GET /stream/sse?kind=j8&codes=005930,000660
+ */
+pub fn filter_stream_sse(client: Arc<QvOpenApiAsyncClient>) -> BoxedFilter<(impl Reply,)> {
+    let cloned = client.clone();
+    let handler = move |req: SseQuery| stream_sse(cloned.clone(), req);
+    get()
+        .and(warp::path!("stream" / "sse"))
+        .and(query::query())
+        .and_then(handler)
+        .boxed()
+}
+
+async fn stream_sse(
+    client: Arc<QvOpenApiAsyncClient>,
+    request: SseQuery,
+) -> Result<Box<dyn Reply>, Infallible> {
+    let codes: Vec<&str> = request
+        .codes
+        .split(',')
+        .map(|code| code.trim())
+        .filter(|code| !code.is_empty())
+        .collect();
+    let stream = match client.subscribe(request.kind, &codes, SiseStreamConfig::default()) {
+        Ok(stream) => stream,
+        Err(err) => return Ok(Box::new(crate::error::convert_error(err)?)),
+    };
+
+    let events = stream.map(|event| {
+        let message = to_stream_message(event);
+        let event_name = match &message {
+            StreamMessage::Sise(res) => res.bc_code.as_str(),
+            _ => "error",
+        };
+        let event = Event::default().event(event_name);
+        Ok::<Event, Infallible>(match event.json_data(&message) {
+            Ok(event) => event,
+            Err(err) => Event::default().event("error").data(err.to_string()),
+        })
+    });
+    Ok(Box::new(sse::reply(sse::keep_alive().stream(events))))
+}
+
+async fn handle_socket(client: Arc<QvOpenApiAsyncClient>, socket: WebSocket) {
+    let (mut ws_tx, mut ws_rx) = socket.split();
+    let (tx, mut rx) = mpsc::channel::<StreamMessage>(SEND_BUFFER_SIZE);
+
+    let sender = tokio::spawn(async move {
+        while let Some(message) = rx.recv().await {
+            let text = match serde_json::to_string(&message) {
+                Ok(text) => text,
+                Err(err) => {
+                    error!("Failed to serialize stream message: {}", err);
+                    continue;
+                }
+            };
+            if ws_tx.send(Message::text(text)).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    let connection_forwarder = spawn_connection_forwarder(&client, tx.clone());
+    let mut subscriptions: ConnectionSubscriptions = HashMap::new();
+    while let Some(received) = ws_rx.next().await {
+        let message = match received {
+            Ok(message) => message,
+            Err(err) => {
+                debug!("WebSocket closed with error: {}", err);
+                break;
+            }
+        };
+        if message.is_close() {
+            break;
+        }
+        let text = match message.to_str() {
+            Ok(text) => text,
+            // ping/pong, binary
+            Err(_) => continue,
+        };
+
+        let reply = match serde_json::from_str::<StreamRequest>(text) {
+            Ok(StreamRequest::Subscribe { kind, codes }) => {
+                subscribe(&client, &tx, &mut subscriptions, kind, &codes)
+            }
+            Ok(StreamRequest::Unsubscribe { kind, codes }) => {
+                unsubscribe(&mut subscriptions, kind, &codes)
+            }
+            Err(err) => StreamMessage::Error {
+                message: format!("Invalid request: {}", err),
+            },
+        };
+        if tx.send(reply).await.is_err() {
+            break;
+        }
+    }
+
+    for (_, task) in subscriptions.drain() {
+        task.abort();
+    }
+    connection_forwarder.abort();
+    sender.abort();
+}
+
+fn subscribe(
+    client: &QvOpenApiAsyncClient,
+    tx: &mpsc::Sender<StreamMessage>,
+    subscriptions: &mut ConnectionSubscriptions,
+    kind: SiseKind,
+    codes: &[String],
+) -> StreamMessage {
+    // overflow, 등록 실패, 세션 종료로 끝난 stream은 다시 구독할 수 있도록 정리
+    subscriptions.retain(|_, task| !task.is_finished());
+
+    let mut subscribed = Vec::new();
+    let mut failed = Vec::new();
+    for code in codes {
+        let key = (kind, code.trim().to_string());
+        if subscriptions.contains_key(&key) {
+            subscribed.push(key.1);
+            continue;
+        }
+
+        match client.subscribe(kind, &[key.1.as_str()], SiseStreamConfig::default()) {
+            Ok(stream) => {
+                subscribed.push(key.1.clone());
+                subscriptions.insert(key, spawn_forwarder(stream, tx.clone()));
+            }
+            // 나머지 종목은 계속 등록하고, 성공/실패 종목을 함께 알림
+            Err(err) => failed.push(FailedCode {
+                code: key.1,
+                message: err.to_string(),
+            }),
+        }
+    }
+    StreamMessage::Subscribed {
+        kind,
+        codes: subscribed,
+        failed,
+    }
+}
+
+fn unsubscribe(
+    subscriptions: &mut ConnectionSubscriptions,
+    kind: SiseKind,
+    codes: &[String],
+) -> StreamMessage {
+    let mut unsubscribed = Vec::new();
+    for code in codes {
+        if let Some(task) = subscriptions.remove(&(kind, code.trim().to_string())) {
+            task.abort();
+            unsubscribed.push(code.trim().to_string());
+        }
+    }
+    StreamMessage::Unsubscribed {
+        kind,
+        codes: unsubscribed,
+    }
+}
+
+fn spawn_forwarder(mut stream: SiseStream, tx: mpsc::Sender<StreamMessage>) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        while let Some(event) = stream.next().await {
+            if tx.send(to_stream_message(event)).await.is_err() {
+                break;
+            }
+        }
+    })
+}
+
+fn spawn_connection_forwarder(
+    client: &QvOpenApiAsyncClient,
+    tx: mpsc::Sender<StreamMessage>,
+) -> JoinHandle<()> {
+    let mut receiver = client.subscribe_connection_state();
+    tokio::spawn(async move {
+        loop {
+            let state = match receiver.recv().await {
+                Ok(state) => state,
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => break,
+            };
+            if tx.send(StreamMessage::Connection(state)).await.is_err() {
+                break;
+            }
+        }
+    })
+}
+
+fn to_stream_message(event: RealtimeEvent) -> StreamMessage {
+    match event {
+        Ok(res) => StreamMessage::Sise(Box::new(res)),
+        Err(err) => StreamMessage::Error {
+            message: err.to_string(),
+        },
+    }
+}
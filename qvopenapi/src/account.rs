@@ -0,0 +1,103 @@
+use std::sync::RwLock;
+
+use serde::Serialize;
+
+use crate::{error::*, models::ConnectResponse};
+
+/**
+ * 로그인 시 받은 계좌 목록과 p8101/p8302로 조회한 계좌명을 합쳐서 보관
+ * account_index는 로그인 응답의 계좌 순서 (1부터 시작)
+ */
+#[derive(Default)]
+pub struct AccountRegistry {
+    accounts: RwLock<Vec<AccountEntry>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AccountEntry {
+    pub account_index: i32,
+    pub account_no: String,
+    pub account_name: String,
+    // 상품 코드
+    pub act_pdt_cdz3: String,
+    // 주식 계좌명 (p8101)
+    pub stock_account_name: Option<String>,
+    // 선물옵션 계좌명 (p8302)
+    pub derivatives_account_name: Option<String>,
+}
+
+impl AccountRegistry {
+    pub fn new() -> AccountRegistry {
+        Self::default()
+    }
+
+    pub fn set_connect_info(&self, res: &ConnectResponse) {
+        let mut accounts = self.accounts.write().unwrap();
+        *accounts = res
+            .account_infoes
+            .iter()
+            .enumerate()
+            .map(|(i, account_info)| AccountEntry {
+                account_index: i as i32 + 1,
+                account_no: account_info.account_no.trim().into(),
+                account_name: account_info.account_name.clone(),
+                act_pdt_cdz3: account_info.act_pdt_cdz3.clone(),
+                stock_account_name: None,
+                derivatives_account_name: None,
+            })
+            .collect();
+    }
+
+    pub fn clear(&self) {
+        self.accounts.write().unwrap().clear();
+    }
+
+    pub fn set_stock_account_name(&self, account_index: i32, name: &str) {
+        self.update(account_index, |entry| {
+            entry.stock_account_name = Some(name.into())
+        });
+    }
+
+    pub fn set_derivatives_account_name(&self, account_index: i32, name: &str) {
+        self.update(account_index, |entry| {
+            entry.derivatives_account_name = Some(name.into())
+        });
+    }
+
+    pub fn get_accounts(&self) -> Vec<AccountEntry> {
+        self.accounts.read().unwrap().clone()
+    }
+
+    /**
+     * "123-45-67890" 과 "1234567890" 모두 같은 계좌로 취급
+     */
+    pub fn get_account_index(&self, account_no: &str) -> Result<i32, QvOpenApiError> {
+        let normalized = normalize_account_no(account_no);
+        self.accounts
+            .read()
+            .unwrap()
+            .iter()
+            .find(|entry| normalize_account_no(&entry.account_no) == normalized)
+            .map(|entry| entry.account_index)
+            .ok_or_else(|| QvOpenApiError::BadRequestError {
+                message: format!("Unknown account_no [{}]", account_no),
+            })
+    }
+
+    fn update<F: FnOnce(&mut AccountEntry)>(&self, account_index: i32, f: F) {
+        let mut accounts = self.accounts.write().unwrap();
+        if let Some(entry) = accounts
+            .iter_mut()
+            .find(|entry| entry.account_index == account_index)
+        {
+            f(entry);
+        }
+    }
+}
+
+fn normalize_account_no(account_no: &str) -> String {
+    account_no
+        .chars()
+        .filter(|ch| ch.is_ascii_alphanumeric())
+        .collect()
+}
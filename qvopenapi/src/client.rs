@@ -1,6 +1,9 @@
 use std::{
     collections::VecDeque,
-    sync::{Arc, Mutex, RwLock},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex, RwLock,
+    },
 };
 
 use serde_json::to_string_pretty;
@@ -11,6 +14,27 @@ pub trait QvOpenApiRequest: Send + Sync {
     fn before_post(&self) -> Result<(), QvOpenApiError>;
     fn call_lib(&self, tr_index: i32, hwnd: isize) -> Result<(), QvOpenApiError>;
     fn get_tr_code(&self) -> &str;
+
+    /**
+     * 창 thread에서 call_lib가 실패했을 때 호출됨
+     * 기본 동작은 CA_RECEIVEERROR, CA_RECEIVECOMPLETE 순서로 온 것처럼 알려서 응답을 기다리는 쪽을 끝냄
+     */
+    fn on_call_failed(
+        &self,
+        handler: &QvOpenApiClientMessageHandler,
+        tr_index: i32,
+        err: &QvOpenApiError,
+    ) {
+        if tr_index < 0 {
+            return;
+        }
+        let mut callbacks = handler.message_handler.lock().unwrap();
+        (callbacks.on_error)(&ErrorResponse {
+            tr_index,
+            error_msg: err.to_string(),
+        });
+        (callbacks.on_complete)(tr_index);
+    }
 }
 
 pub trait AbstractQvOpenApiClient {
@@ -48,10 +72,18 @@ pub trait AbstractQvOpenApiClient {
         self.get_handler().message_handler.lock().unwrap().on_data = callback;
     }
 
-    fn on_sise(&self, callback: Box<dyn FnMut(&DataResponse) + Send>) {
+    fn on_sise(&self, callback: Box<dyn FnMut(&SiseResponse) + Send>) {
         self.get_handler().message_handler.lock().unwrap().on_sise = callback;
     }
 
+    fn on_order_notice(&self, callback: Box<dyn FnMut(&OrderNotice) + Send>) {
+        self.get_handler()
+            .message_handler
+            .lock()
+            .unwrap()
+            .on_order_notice = callback;
+    }
+
     fn on_message(&self, callback: Box<dyn FnMut(&MessageResponse) + Send>) {
         self.get_handler()
             .message_handler
@@ -96,6 +128,50 @@ pub trait AbstractQvOpenApiClient {
         self.query(-1, Arc::new(DisconnectRequest {}))
     }
 
+    /**
+     * 실시간 등록
+     * 이미 다른 구독자가 등록한 종목은 wmcaAttach를 다시 호출하지 않음
+     * wmcaAttach는 창 thread에서 호출되므로, 거기서 실패하면 구독을 되돌리고 callback에 Err를 전달
+     */
+    fn subscribe(
+        &self,
+        kind: SiseKind,
+        codes: &[&str],
+        callback: SiseCallback,
+    ) -> Result<SubscriptionId, QvOpenApiError> {
+        let codes: Vec<String> = codes.iter().map(|code| code.to_string()).collect();
+        let (id, attach_codes) = self.get_handler().subscriptions.add(kind, &codes, callback);
+        if !attach_codes.is_empty() {
+            let req = Arc::new(AttachRequest {
+                subscription_id: id,
+                kind,
+                codes: attach_codes,
+            });
+            if let Err(err) = self.query(-1, req) {
+                self.get_handler().subscriptions.remove(id);
+                return Err(err);
+            }
+        }
+        Ok(id)
+    }
+
+    /**
+     * 실시간 취소
+     * 해당 종목의 마지막 구독자인 경우에만 wmcaDetach 호출
+     */
+    fn unsubscribe(&self, id: SubscriptionId) -> Result<(), QvOpenApiError> {
+        match self.get_handler().subscriptions.remove(id) {
+            Some((kind, detach_codes)) if !detach_codes.is_empty() => self.query(
+                -1,
+                Arc::new(DetachRequest {
+                    kind,
+                    codes: detach_codes,
+                }),
+            ),
+            _ => Ok(()),
+        }
+    }
+
     fn query(&self, tr_index: i32, req: Arc<dyn QvOpenApiRequest>) -> Result<(), QvOpenApiError>;
 }
 
@@ -135,7 +211,11 @@ pub struct QvOpenApiClient {
 pub struct QvOpenApiClientMessageHandler {
     hwnd_lock: RwLock<Option<isize>>,
     pub message_handler: Mutex<QvOpenApiClientMessageCallbacks>,
+    pub subscriptions: SubscriptionRegistry,
+    pub orders: OrderTracker,
     request_queue_lock: Mutex<VecDeque<(i32, Arc<dyn QvOpenApiRequest>)>>,
+    // 마지막 CA_CONNECTED에서 다시 등록한 (BC, 종목코드) 수
+    reattached_count: AtomicUsize,
 }
 
 impl QvOpenApiClientMessageHandler {
@@ -148,11 +228,15 @@ impl QvOpenApiClientMessageHandler {
                 on_socket_error: Box::new(|| {}),
                 on_data: Box::new(|_| {}),
                 on_sise: Box::new(|_| {}),
+                on_order_notice: Box::new(|_| {}),
                 on_message: Box::new(|_| {}),
                 on_complete: Box::new(|_| {}),
                 on_error: Box::new(|_| {}),
             }),
+            subscriptions: SubscriptionRegistry::new(),
+            orders: OrderTracker::new(),
             request_queue_lock: Mutex::new(VecDeque::new()),
+            reattached_count: AtomicUsize::new(0),
         }
     }
 }
@@ -162,7 +246,8 @@ pub struct QvOpenApiClientMessageCallbacks {
     pub on_disconnect: Box<dyn FnMut() + Send>,
     pub on_socket_error: Box<dyn FnMut() + Send>,
     pub on_data: Box<dyn FnMut(&DataResponse) + Send>,
-    pub on_sise: Box<dyn FnMut(&DataResponse) + Send>,
+    pub on_sise: Box<dyn FnMut(&SiseResponse) + Send>,
+    pub on_order_notice: Box<dyn FnMut(&OrderNotice) + Send>,
     pub on_message: Box<dyn FnMut(&MessageResponse) + Send>,
     pub on_complete: Box<dyn FnMut(i32) + Send>,
     pub on_error: Box<dyn FnMut(&ErrorResponse) + Send>,
@@ -188,18 +273,23 @@ impl QvOpenApiClientMessageHandler {
             CA_CONNECTED => {
                 let res = models::parse_connect(lparam)?;
                 debug!("CA_CONNECT {}", to_string_pretty(&res)?);
+                let reattached = self.reattach_subscriptions();
+                self.reattached_count.store(reattached, Ordering::Release);
                 let mut handler = self.message_handler.lock().unwrap();
                 (handler.on_connect)(&res);
                 Ok(())
             }
             CA_DISCONNECTED => {
                 debug!("CA_DISCONNECTED");
+                // 끊긴 세션의 주문 TR 응답과 통보는 다시 오지 않으므로 추적 중인 주문을 비움
+                self.orders.clear();
                 let mut handler = self.message_handler.lock().unwrap();
                 (handler.on_disconnect)();
                 Ok(())
             }
             CA_SOCKETERROR => {
                 debug!("CA_SOCKETERROR");
+                self.orders.clear();
                 let mut handler = self.message_handler.lock().unwrap();
                 (handler.on_socket_error)();
                 Ok(())
@@ -211,17 +301,27 @@ impl QvOpenApiClientMessageHandler {
                     res.tr_index,
                     to_string_pretty(&res)?
                 );
+                self.orders.record_response(&res);
                 let mut handler = self.message_handler.lock().unwrap();
                 (handler.on_data)(&res);
                 Ok(())
             }
             CA_RECEIVESISE => {
+                if let Some(mut notice) = models::parse_order_notice(lparam)? {
+                    self.orders.on_notice(&mut notice);
+                    debug!("CA_RECEIVESISE [ORDER] {}", to_string_pretty(&notice)?);
+                    let mut handler = self.message_handler.lock().unwrap();
+                    (handler.on_order_notice)(&notice);
+                    return Ok(());
+                }
                 let res = models::parse_sise(lparam)?;
                 debug!(
-                    "CA_RECEIVESISE [TR{}] {}",
-                    res.tr_index,
+                    "CA_RECEIVESISE [{}] [{}] {}",
+                    res.bc_code,
+                    res.code,
                     to_string_pretty(&res)?
                 );
+                self.subscriptions.dispatch(&res);
                 let mut handler = self.message_handler.lock().unwrap();
                 (handler.on_sise)(&res);
                 Ok(())
@@ -251,10 +351,21 @@ impl QvOpenApiClientMessageHandler {
                 Ok(())
             }
             CA_CUSTOM_EXECUTE_POSTED_COMMAND => {
-                let mut request_queue = self.request_queue_lock.lock().unwrap();
-                let hwnd = self.hwnd_lock.read().unwrap();
-                while let Some((tr_index, cmd)) = request_queue.pop_front() {
-                    cmd.call_lib(tr_index, hwnd.unwrap())?;
+                // 실패 처리에서 callback이 다시 요청을 넣을 수 있으므로 lock을 먼저 풂
+                let commands: Vec<(i32, Arc<dyn QvOpenApiRequest>)> =
+                    self.request_queue_lock.lock().unwrap().drain(..).collect();
+                let hwnd = self.hwnd_lock.read().unwrap().unwrap();
+                // 한 요청이 실패해도 뒤의 요청은 계속 보냄
+                for (tr_index, cmd) in commands {
+                    if let Err(err) = cmd.call_lib(tr_index, hwnd) {
+                        error!(
+                            "Failed to execute {} [TR{}]: {}",
+                            cmd.get_tr_code(),
+                            tr_index,
+                            err
+                        );
+                        cmd.on_call_failed(self, tr_index, &err);
+                    }
                 }
                 Ok(())
             }
@@ -262,6 +373,44 @@ impl QvOpenApiClientMessageHandler {
         }
     }
 
+    /**
+     * 창 thread에서 요청을 새로 넣음. 다음 CA_CUSTOM_EXECUTE_POSTED_COMMAND에서 보냄
+     */
+    pub(crate) fn post_request(&self, req: Arc<dyn QvOpenApiRequest>) {
+        let hwnd = match *self.hwnd_lock.read().unwrap() {
+            Some(hwnd) => hwnd,
+            None => {
+                error!("Window destroyed, dropping {}", req.get_tr_code());
+                return;
+            }
+        };
+        self.request_queue_lock.lock().unwrap().push_back((-1, req));
+        window_mgr::post_message_to_window(hwnd, WM_WMCAEVENT, CA_CUSTOM_EXECUTE_POSTED_COMMAND, 0);
+    }
+
+    /**
+     * 재접속하면 서버의 실시간 등록이 사라지므로 남아 있는 구독을 다시 등록
+     * 다시 등록한 (BC, 종목코드) 수 반환
+     */
+    fn reattach_subscriptions(&self) -> usize {
+        let hwnd = match *self.hwnd_lock.read().unwrap() {
+            Some(hwnd) => hwnd,
+            None => return 0,
+        };
+        let mut count = 0;
+        for (kind, codes) in self.subscriptions.get_active() {
+            match wmca_lib::subscribe(hwnd, kind, &codes) {
+                Ok(_) => count += codes.len(),
+                Err(err) => error!("Failed to re-attach {}: {}", kind.as_str(), err),
+            }
+        }
+        count
+    }
+
+    pub fn get_reattached_count(&self) -> usize {
+        self.reattached_count.load(Ordering::Acquire)
+    }
+
     pub fn on_destroy(&self) {
         let mut hwnd = self.hwnd_lock.write().unwrap();
         *hwnd = None;
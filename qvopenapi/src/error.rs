@@ -2,9 +2,9 @@ extern crate custom_error;
 extern crate libloading;
 
 use custom_error::custom_error;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-custom_error! {#[derive(Clone, Serialize)] pub QvOpenApiError
+custom_error! {#[derive(Clone, Serialize, Deserialize)] pub QvOpenApiError
     BadRequestError{ message: String } = "Bad request: {message}",
     WmcaDllLoadingError = "Failed to load wmca.dll",
     WindowCreationError = "Failed to create a window",
@@ -18,8 +18,11 @@ custom_error! {#[derive(Clone, Serialize)] pub QvOpenApiError
     ParseDateTimeError = "Failed to parse datetime",
     ParseNumberError{ input: String } = "Failed to parse [{input}] to number",
     ParseRatioError{ input: String } = "Failed to parse [{input}] to ratio",
+    ParseSignError{ input: String } = "Failed to parse [{input}] to sign",
     AlreadyConnectedError = "Already connected",
     UnimplementedBlockError{ block_name: String } = "Unimplemented block {block_name}",
+    SisePacketLengthError{ bc_code: String, len: usize } = "Real-time packet {bc_code} too short ({len} bytes)",
+    SiseBufferOverflowError{ capacity: usize } = "Real-time buffer overflowed ({capacity} events)",
     TransactionPoolFullError = "Transaction pool full",
     JsonParseError{ message: String } = "Failed to parse json: {message}",
     RequestTimeoutError = "Request timed out",
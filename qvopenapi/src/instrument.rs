@@ -0,0 +1,145 @@
+use std::sync::RwLock;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::*;
+
+/**
+ * p1003으로 조회한 선물옵션 종목코드 목록 (종목코드 -> 종목명, 상품유형)
+ * 같은 선옵구분으로 다시 조회하면 해당 구분의 목록만 교체
+ */
+#[derive(Default)]
+pub struct InstrumentCatalog {
+    instruments: RwLock<Vec<Instrument>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Instrument {
+    pub code: String,
+    pub name: String,
+    pub kind: InstrumentKind,
+    // p1003 선옵구분 (f:KRX선물 o:KRX옵션 u:내부선물 p:내부옵션 z:위클리옵션)
+    pub gubun: char,
+}
+
+/**
+ * 거래소 단축코드 (8자리) 첫 자리 기준
+ * 1:선물 2:콜옵션 3:풋옵션 4:스프레드
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum InstrumentKind {
+    Future,
+    Call,
+    Put,
+    Spread,
+    Unknown,
+}
+
+/**
+ * 거래소 단축코드 2~3번째 자리의 기초자산
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FuturesProduct {
+    Kospi200,
+    MiniKospi200,
+    Kosdaq150,
+}
+
+/**
+ * 전략 설정에서 종목을 지정하는 방법
+ * {"Code": "101W3000"} 또는 {"FrontMonth": "Kospi200"}
+ */
+#[derive(Debug, Clone, Deserialize)]
+pub enum InstrumentSelector {
+    Code(String),
+    FrontMonth(FuturesProduct),
+}
+
+impl Instrument {
+    pub fn new(code: &str, name: &str, gubun: char) -> Instrument {
+        let code = code.trim();
+        let kind = match code.chars().next() {
+            Some('1') => InstrumentKind::Future,
+            Some('2') => InstrumentKind::Call,
+            Some('3') => InstrumentKind::Put,
+            Some('4') => InstrumentKind::Spread,
+            _ => InstrumentKind::Unknown,
+        };
+        Instrument {
+            code: code.into(),
+            name: name.trim().into(),
+            kind,
+            gubun,
+        }
+    }
+
+    pub fn is_product(&self, product: FuturesProduct) -> bool {
+        self.code.get(1..3) == Some(product.product_code())
+    }
+}
+
+impl FuturesProduct {
+    pub fn product_code(&self) -> &'static str {
+        match self {
+            FuturesProduct::Kospi200 => "01",
+            FuturesProduct::MiniKospi200 => "05",
+            FuturesProduct::Kosdaq150 => "06",
+        }
+    }
+}
+
+impl InstrumentCatalog {
+    pub fn new() -> InstrumentCatalog {
+        Self::default()
+    }
+
+    pub fn set_instruments(&self, gubun: char, instruments: Vec<Instrument>) {
+        let mut current = self.instruments.write().unwrap();
+        current.retain(|instrument| instrument.gubun != gubun);
+        current.extend(instruments);
+    }
+
+    pub fn clear(&self) {
+        self.instruments.write().unwrap().clear();
+    }
+
+    pub fn get_instruments(&self) -> Vec<Instrument> {
+        self.instruments.read().unwrap().clone()
+    }
+
+    pub fn get_instrument(&self, code: &str) -> Option<Instrument> {
+        let code = code.trim();
+        self.instruments
+            .read()
+            .unwrap()
+            .iter()
+            .find(|instrument| instrument.code == code)
+            .cloned()
+    }
+
+    /**
+     * p1003은 만기 순서대로 내려오므로 목록에서 처음 나오는 선물을 최근월물로 취급
+     */
+    pub fn resolve(&self, selector: &InstrumentSelector) -> Result<Instrument, QvOpenApiError> {
+        match selector {
+            InstrumentSelector::Code(code) => {
+                self.get_instrument(code)
+                    .ok_or_else(|| QvOpenApiError::BadRequestError {
+                        message: format!("Unknown instrument code [{}]", code),
+                    })
+            }
+            InstrumentSelector::FrontMonth(product) => self
+                .instruments
+                .read()
+                .unwrap()
+                .iter()
+                .find(|instrument| {
+                    instrument.kind == InstrumentKind::Future && instrument.is_product(*product)
+                })
+                .cloned()
+                .ok_or_else(|| QvOpenApiError::BadRequestError {
+                    message: format!("No listed future for [{:?}]", product),
+                }),
+        }
+    }
+}
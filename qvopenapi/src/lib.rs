@@ -2,16 +2,26 @@ extern crate qvopenapi_bindings;
 extern crate qvopenapi_sys;
 #[macro_use]
 extern crate lazy_static;
+mod account;
 mod client;
 pub mod error;
+mod instrument;
 pub mod models;
+mod order_tracker;
+mod subscription;
 mod utils;
 mod window_mgr;
 mod wmca_lib;
 
+pub use account::{AccountEntry, AccountRegistry};
 pub use client::{
     AbstractQvOpenApiClient, QvOpenApiClient, QvOpenApiClientMessageHandler, QvOpenApiRequest,
 };
+pub use instrument::{
+    FuturesProduct, Instrument, InstrumentCatalog, InstrumentKind, InstrumentSelector,
+};
 use log::*;
+pub use order_tracker::{OrderTracker, TrackedOrder};
+pub use subscription::{SiseCallback, SubscriptionId, SubscriptionRegistry};
 pub use window_mgr::{WindowHelper, WindowStatus};
 pub use wmca_lib::{init, is_connected, set_port, set_server};
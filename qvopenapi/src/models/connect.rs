@@ -1,6 +1,9 @@
+use std::fmt;
+
 use chrono::TimeZone;
 use qvopenapi_bindings::LoginBlock;
 use serde::{Deserialize, Serialize};
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 use crate::{
     client::QvOpenApiRequest,
@@ -59,14 +62,26 @@ pub fn parse_connect(lparam: isize) -> std::result::Result<ConnectResponse, QvOp
 pub const TR_INDEX_CONNECT: i32 = 1;
 pub const TR_CODE_CONNECT: &str = "_connect";
 
-#[derive(Debug, Clone, Deserialize)]
+// 요청 큐에서 빠져 drop될 때 비밀번호를 메모리에서 지움
+#[derive(Clone, Deserialize, Zeroize, ZeroizeOnDrop)]
 pub struct ConnectRequest {
+    #[zeroize(skip)]
     pub account_type: AccountType,
     pub id: String,
     pub password: String,
     pub cert_password: String,
 }
 
+// 비밀번호가 로그에 남지 않도록 제외
+impl fmt::Debug for ConnectRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConnectRequest")
+            .field("account_type", &self.account_type)
+            .field("id", &self.id)
+            .finish_non_exhaustive()
+    }
+}
+
 #[derive(Debug, strum_macros::Display, Clone, Copy, Deserialize)]
 pub enum AccountType {
     QV,
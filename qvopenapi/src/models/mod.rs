@@ -1,6 +1,10 @@
 mod connect;
 mod message;
 mod query;
+mod sign;
+mod sise;
 pub use connect::*;
 pub use message::*;
 pub use query::*;
+pub use sign::*;
+pub use sise::*;
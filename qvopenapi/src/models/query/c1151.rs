@@ -0,0 +1,542 @@
+use std::ffi::c_char;
+use std::mem::size_of;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::utils::{parse_decimal, parse_number, parse_sign, parse_string, to_fixed_string};
+use crate::{error::*, models::*, QvOpenApiRequest};
+use qvopenapi_bindings::{
+    Tc1151InBlock, Tc1151OutBlock, Tc1151OutBlock2, Tc1151OutBlock3, Tc1151OutBlock4,
+    Tc1151OutBlock5,
+};
+
+pub const TR_CODE_C1151: &str = "c1151";
+
+/**
+ * 주식 현재가 조회 (ETF 포함)
+ */
+#[derive(Debug, Clone, Deserialize)]
+pub struct C1151Request {
+    pub formlang: char, //한영구분 (k:한글, 기타:영문)
+    pub code: String,   //종목코드 (단축코드 6자리)
+}
+
+impl C1151Request {
+    pub fn new(formlang: char, code: &str) -> C1151Request {
+        C1151Request {
+            formlang,
+            code: code.into(),
+        }
+    }
+
+    pub fn into_raw(&self) -> Result<Arc<RawQueryRequest<Tc1151InBlock>>, QvOpenApiError> {
+        Ok(Arc::new(RawQueryRequest::new(
+            TR_CODE_C1151,
+            // 시세 조회는 계좌와 무관
+            0,
+            Box::new(Tc1151InBlock {
+                formlang: [self.formlang as c_char],
+                _formlang: ' ' as c_char,
+                code: to_fixed_string(&self.code)?,
+                _code: ' ' as c_char,
+            }),
+        )))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C1151Output {
+    #[serde(rename = "c1151OutBlock")]
+    pub quote: C1151Response, //종목마스타기본자료
+    #[serde(rename = "c1151OutBlock2", default)]
+    pub trades: Vec<C1151Response2>, //변동거래량자료
+    #[serde(rename = "c1151OutBlock3", default)]
+    pub expected: Option<C1151Response3>, //예상체결
+    #[serde(rename = "c1151OutBlock4", default)]
+    pub etf: Option<C1151Response4>, //ETF자료
+    #[serde(rename = "c1151OutBlock5", default)]
+    pub base_index: Option<C1151Response5>, //베이스지수자료
+}
+
+impl TypedQueryRequest for C1151Request {
+    type Output = C1151Output;
+
+    fn to_query(&self) -> Result<Arc<dyn QvOpenApiRequest>, QvOpenApiError> {
+        Ok(self.into_raw()?)
+    }
+}
+
+pub fn parse_c1151_response(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Tc1151OutBlock));
+        Ok(json!(C1151Response {
+            code: parse_string(&res.code)?,
+            hname: parse_string(&res.hname)?,
+            price: parse_number(&res.price)?,
+            sign: parse_sign(&res.sign)?,
+            change: parse_number(&res.change)?,
+            chrate: parse_decimal(&res.chrate)?,
+            offer: parse_number(&res.offer)?,
+            bid: parse_number(&res.bid)?,
+            volume: parse_number(&res.volume)?,
+            volrate: parse_decimal(&res.volrate)?,
+            yurate: parse_decimal(&res.yurate)?,
+            value: parse_number(&res.value)?,
+            uplmtprice: parse_number(&res.uplmtprice)?,
+            high: parse_number(&res.high)?,
+            open: parse_number(&res.open)?,
+            opensign: parse_sign(&res.opensign)?,
+            openchange: parse_number(&res.openchange)?,
+            low: parse_number(&res.low)?,
+            dnlmtprice: parse_number(&res.dnlmtprice)?,
+            hotime: parse_string(&res.hotime)?,
+            offerho: parse_number(&res.offerho)?,
+            P_offer: parse_number(&res.P_offer)?,
+            S_offer: parse_number(&res.S_offer)?,
+            S4_offer: parse_number(&res.S4_offer)?,
+            S5_offer: parse_number(&res.S5_offer)?,
+            S6_offer: parse_number(&res.S6_offer)?,
+            S7_offer: parse_number(&res.S7_offer)?,
+            S8_offer: parse_number(&res.S8_offer)?,
+            S9_offer: parse_number(&res.S9_offer)?,
+            S10_offer: parse_number(&res.S10_offer)?,
+            bidho: parse_number(&res.bidho)?,
+            P_bid: parse_number(&res.P_bid)?,
+            S_bid: parse_number(&res.S_bid)?,
+            S4_bid: parse_number(&res.S4_bid)?,
+            S5_bid: parse_number(&res.S5_bid)?,
+            S6_bid: parse_number(&res.S6_bid)?,
+            S7_bid: parse_number(&res.S7_bid)?,
+            S8_bid: parse_number(&res.S8_bid)?,
+            S9_bid: parse_number(&res.S9_bid)?,
+            S10_bid: parse_number(&res.S10_bid)?,
+            offerrem: parse_number(&res.offerrem)?,
+            P_offerrem: parse_number(&res.P_offerrem)?,
+            S_offerrem: parse_number(&res.S_offerrem)?,
+            S4_offerrem: parse_number(&res.S4_offerrem)?,
+            S5_offerrem: parse_number(&res.S5_offerrem)?,
+            S6_offerrem: parse_number(&res.S6_offerrem)?,
+            S7_offerrem: parse_number(&res.S7_offerrem)?,
+            S8_offerrem: parse_number(&res.S8_offerrem)?,
+            S9_offerrem: parse_number(&res.S9_offerrem)?,
+            S10_offerrem: parse_number(&res.S10_offerrem)?,
+            bidrem: parse_number(&res.bidrem)?,
+            P_bidrem: parse_number(&res.P_bidrem)?,
+            S_bidrem: parse_number(&res.S_bidrem)?,
+            S4_bidrem: parse_number(&res.S4_bidrem)?,
+            S5_bidrem: parse_number(&res.S5_bidrem)?,
+            S6_bidrem: parse_number(&res.S6_bidrem)?,
+            S7_bidrem: parse_number(&res.S7_bidrem)?,
+            S8_bidrem: parse_number(&res.S8_bidrem)?,
+            S9_bidrem: parse_number(&res.S9_bidrem)?,
+            S10_bidrem: parse_number(&res.S10_bidrem)?,
+            T_offerrem: parse_number(&res.T_offerrem)?,
+            T_bidrem: parse_number(&res.T_bidrem)?,
+            O_offerrem: parse_number(&res.O_offerrem)?,
+            O_bidrem: parse_number(&res.O_bidrem)?,
+            pivot2upz7: parse_number(&res.pivot2upz7)?,
+            pivot1upz7: parse_number(&res.pivot1upz7)?,
+            pivotz7: parse_number(&res.pivotz7)?,
+            pivot1dnz7: parse_number(&res.pivot1dnz7)?,
+            pivot2dnz7: parse_number(&res.pivot2dnz7)?,
+            sosokz6: parse_string(&res.sosokz6)?,
+            jisunamez18: parse_string(&res.jisunamez18)?,
+            capsizez6: parse_string(&res.capsizez6)?,
+            output1z16: parse_string(&res.output1z16)?,
+            marcket1z16: parse_string(&res.marcket1z16)?,
+            marcket2z16: parse_string(&res.marcket2z16)?,
+            marcket3z16: parse_string(&res.marcket3z16)?,
+            marcket4z16: parse_string(&res.marcket4z16)?,
+            marcket5z16: parse_string(&res.marcket5z16)?,
+            marcket6z16: parse_string(&res.marcket6z16)?,
+            cbtext: parse_string(&res.cbtext)?,
+            parvalue: parse_number(&res.parvalue)?,
+            prepricetitlez12: parse_string(&res.prepricetitlez12)?,
+            prepricez7: parse_number(&res.prepricez7)?,
+            subprice: parse_number(&res.subprice)?,
+            gongpricez7: parse_number(&res.gongpricez7)?,
+            high5: parse_number(&res.high5)?,
+            low5: parse_number(&res.low5)?,
+            high20: parse_number(&res.high20)?,
+            low20: parse_number(&res.low20)?,
+            yhigh: parse_number(&res.yhigh)?,
+            yhighdate: parse_string(&res.yhighdate)?,
+            ylow: parse_number(&res.ylow)?,
+            ylowdate: parse_string(&res.ylowdate)?,
+            movlistingz8: parse_number(&res.movlistingz8)?,
+            listing: parse_number(&res.listing)?,
+            totpricez9: parse_number(&res.totpricez9)?,
+            tratimez5: parse_string(&res.tratimez5)?,
+            off_tra1: parse_string(&res.off_tra1)?,
+            bid_tra1: parse_string(&res.bid_tra1)?,
+            N_offvolume1: parse_number(&res.N_offvolume1)?,
+            N_bidvolume1: parse_number(&res.N_bidvolume1)?,
+            off_tra2: parse_string(&res.off_tra2)?,
+            bid_tra2: parse_string(&res.bid_tra2)?,
+            N_offvolume2: parse_number(&res.N_offvolume2)?,
+            N_bidvolume2: parse_number(&res.N_bidvolume2)?,
+            off_tra3: parse_string(&res.off_tra3)?,
+            bid_tra3: parse_string(&res.bid_tra3)?,
+            N_offvolume3: parse_number(&res.N_offvolume3)?,
+            N_bidvolume3: parse_number(&res.N_bidvolume3)?,
+            off_tra4: parse_string(&res.off_tra4)?,
+            bid_tra4: parse_string(&res.bid_tra4)?,
+            N_offvolume4: parse_number(&res.N_offvolume4)?,
+            N_bidvolume4: parse_number(&res.N_bidvolume4)?,
+            off_tra5: parse_string(&res.off_tra5)?,
+            bid_tra5: parse_string(&res.bid_tra5)?,
+            N_offvolume5: parse_number(&res.N_offvolume5)?,
+            N_bidvolume5: parse_number(&res.N_bidvolume5)?,
+            N_offvolall: parse_number(&res.N_offvolall)?,
+            N_bidvolall: parse_number(&res.N_bidvolall)?,
+            fortimez6: parse_string(&res.fortimez6)?,
+            forratez5: parse_decimal(&res.forratez5)?,
+            settdatez4: parse_string(&res.settdatez4)?,
+            cratez5: parse_decimal(&res.cratez5)?,
+            yudatez4: parse_string(&res.yudatez4)?,
+            mudatez4: parse_string(&res.mudatez4)?,
+            yuratez5: parse_decimal(&res.yuratez5)?,
+            muratez5: parse_decimal(&res.muratez5)?,
+            listdatez8: parse_string(&res.listdatez8)?,
+            listing2: parse_number(&res.listing2)?,
+            N_alloffvol: parse_number(&res.N_alloffvol)?,
+            N_allbidvol: parse_number(&res.N_allbidvol)?,
+        }))
+    }
+}
+
+pub fn parse_c1151_response2_array(
+    block_data: *const c_char,
+    block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let block_count = block_len as usize / size_of::<Tc1151OutBlock2>();
+        let res: &[Tc1151OutBlock2] =
+            core::slice::from_raw_parts(block_data as *const Tc1151OutBlock2, block_count);
+
+        let ret: Result<Vec<C1151Response2>, QvOpenApiError> =
+            res.iter().map(parse_c1151_response2).collect();
+        Ok(json!(ret?))
+    }
+}
+
+fn parse_c1151_response2(res: &Tc1151OutBlock2) -> Result<C1151Response2, QvOpenApiError> {
+    Ok(C1151Response2 {
+        time: parse_string(&res.time)?,
+        price: parse_number(&res.price)?,
+        sign: parse_sign(&res.sign)?,
+        change: parse_number(&res.change)?,
+        offer: parse_number(&res.offer)?,
+        bid: parse_number(&res.bid)?,
+        movolume: parse_number(&res.movolume)?,
+        volume: parse_number(&res.volume)?,
+    })
+}
+
+pub fn parse_c1151_response3(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Tc1151OutBlock3));
+        Ok(json!(C1151Response3 {
+            dongsi: parse_string(&res.dongsi)?,
+            jeqprice: parse_number(&res.jeqprice)?,
+            jeqsign: parse_sign(&res.jeqsign)?,
+            jeqchange: parse_number(&res.jeqchange)?,
+            jeqchrate: parse_decimal(&res.jeqchrate)?,
+            jeqvol: parse_number(&res.jeqvol)?,
+        }))
+    }
+}
+
+pub fn parse_c1151_response4(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Tc1151OutBlock4));
+        Ok(json!(C1151Response4 {
+            bu12: parse_string(&res.bu12)?,
+            nav: parse_decimal(&res.nav)?,
+            nsign: parse_sign(&res.nsign)?,
+            nchange: parse_decimal(&res.nchange)?,
+            prenav: parse_decimal(&res.prenav)?,
+            grate: parse_decimal(&res.grate)?,
+            gsign: parse_string(&res.gsign)?,
+            icuz18: parse_number(&res.icuz18)?,
+            totjo: parse_number(&res.totjo)?,
+            totvalue: parse_number(&res.totvalue)?,
+            terror: parse_decimal(&res.terror)?,
+            lpoffremain1: parse_number(&res.lpoffremain1)?,
+            lpoffremain2: parse_number(&res.lpoffremain2)?,
+            lpoffremain3: parse_number(&res.lpoffremain3)?,
+            lpoffremain4: parse_number(&res.lpoffremain4)?,
+            lpoffremain5: parse_number(&res.lpoffremain5)?,
+            lpoffremain6: parse_number(&res.lpoffremain6)?,
+            lpoffremain7: parse_number(&res.lpoffremain7)?,
+            lpoffremain8: parse_number(&res.lpoffremain8)?,
+            lpoffremain9: parse_number(&res.lpoffremain9)?,
+            lpoffremain10: parse_number(&res.lpoffremain10)?,
+            lpbidremain1: parse_number(&res.lpbidremain1)?,
+            lpbidremain2: parse_number(&res.lpbidremain2)?,
+            lpbidremain3: parse_number(&res.lpbidremain3)?,
+            lpbidremain4: parse_number(&res.lpbidremain4)?,
+            lpbidremain5: parse_number(&res.lpbidremain5)?,
+            lpbidremain6: parse_number(&res.lpbidremain6)?,
+            lpbidremain7: parse_number(&res.lpbidremain7)?,
+            lpbidremain8: parse_number(&res.lpbidremain8)?,
+            lpbidremain9: parse_number(&res.lpbidremain9)?,
+            lpbidremain10: parse_number(&res.lpbidremain10)?,
+            etf_copy_cd: parse_string(&res.etf_copy_cd)?,
+            etf_tax_cd: parse_string(&res.etf_tax_cd)?,
+        }))
+    }
+}
+
+pub fn parse_c1151_response5(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Tc1151OutBlock5));
+        Ok(json!(C1151Response5 {
+            jisucode: parse_string(&res.jisucode)?,
+            sectorcode: parse_string(&res.sectorcode)?,
+            jisuhnamez20: parse_string(&res.jisuhnamez20)?,
+            kp200jisu: parse_decimal(&res.kp200jisu)?,
+            kp200sign: parse_sign(&res.kp200sign)?,
+            kp200change: parse_decimal(&res.kp200change)?,
+            ubjisu: parse_decimal(&res.ubjisu)?,
+            ubsign: parse_sign(&res.ubsign)?,
+            ubchange: parse_decimal(&res.ubchange)?,
+            symbol: parse_string(&res.symbol)?,
+            eupcode: parse_string(&res.eupcode)?,
+            ubjiid: parse_string(&res.ubjiid)?,
+            ubjiid2: parse_string(&res.ubjiid2)?,
+        }))
+    }
+}
+
+#[allow(non_snake_case)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C1151Response {
+    pub code: String,                //종목코드
+    pub hname: String,               //종목명
+    pub price: Option<i64>,          //현재가
+    pub sign: Option<PriceSign>,     //등락부호
+    pub change: Option<i64>,         //등락폭
+    pub chrate: Option<f64>,         //등락률
+    pub offer: Option<i64>,          //매도호가
+    pub bid: Option<i64>,            //매수호가
+    pub volume: Option<i64>,         //거래량
+    pub volrate: Option<f64>,        //거래비율
+    pub yurate: Option<f64>,         //유동주회전율
+    pub value: Option<i64>,          //거래대금
+    pub uplmtprice: Option<i64>,     //상한가
+    pub high: Option<i64>,           //장중고가
+    pub open: Option<i64>,           //시가
+    pub opensign: Option<PriceSign>, //시가대비부호
+    pub openchange: Option<i64>,     //시가대비등락폭
+    pub low: Option<i64>,            //장중저가
+    pub dnlmtprice: Option<i64>,     //하한가
+    pub hotime: String,              //호가시간
+    pub offerho: Option<i64>,        //매도최우선호가
+    pub P_offer: Option<i64>,        //매도차선호가
+    pub S_offer: Option<i64>,        //매도차차선호가
+    pub S4_offer: Option<i64>,       //매도4차선호가
+    pub S5_offer: Option<i64>,       //매도5차선호가
+    pub S6_offer: Option<i64>,       //매도6차선호가
+    pub S7_offer: Option<i64>,       //매도7차선호가
+    pub S8_offer: Option<i64>,       //매도8차선호가
+    pub S9_offer: Option<i64>,       //매도9차선호가
+    pub S10_offer: Option<i64>,      //매도10차선호가
+    pub bidho: Option<i64>,          //매수최우선호가
+    pub P_bid: Option<i64>,          //매수차선호가
+    pub S_bid: Option<i64>,          //매수차차선호가
+    pub S4_bid: Option<i64>,         //매수4차선호가
+    pub S5_bid: Option<i64>,         //매수5차선호가
+    pub S6_bid: Option<i64>,         //매수6차선호가
+    pub S7_bid: Option<i64>,         //매수7차선호가
+    pub S8_bid: Option<i64>,         //매수8차선호가
+    pub S9_bid: Option<i64>,         //매수9차선호가
+    pub S10_bid: Option<i64>,        //매수10차선호가
+    pub offerrem: Option<i64>,       //매도최우선잔량
+    pub P_offerrem: Option<i64>,     //매도차선잔량
+    pub S_offerrem: Option<i64>,     //매도차차선잔량
+    pub S4_offerrem: Option<i64>,    //매도4차선잔량
+    pub S5_offerrem: Option<i64>,    //매도5차선잔량
+    pub S6_offerrem: Option<i64>,    //매도6차선잔량
+    pub S7_offerrem: Option<i64>,    //매도7차선잔량
+    pub S8_offerrem: Option<i64>,    //매도8차선잔량
+    pub S9_offerrem: Option<i64>,    //매도9차선잔량
+    pub S10_offerrem: Option<i64>,   //매도10차선잔량
+    pub bidrem: Option<i64>,         //매수최우선잔량
+    pub P_bidrem: Option<i64>,       //매수차선잔량
+    pub S_bidrem: Option<i64>,       //매수차차선잔량
+    pub S4_bidrem: Option<i64>,      //매수4차선잔량
+    pub S5_bidrem: Option<i64>,      //매수5차선잔량
+    pub S6_bidrem: Option<i64>,      //매수6차선잔량
+    pub S7_bidrem: Option<i64>,      //매수7차선잔량
+    pub S8_bidrem: Option<i64>,      //매수8차선잔량
+    pub S9_bidrem: Option<i64>,      //매수9차선잔량
+    pub S10_bidrem: Option<i64>,     //매수10차선잔량
+    pub T_offerrem: Option<i64>,     //총매도잔량
+    pub T_bidrem: Option<i64>,       //총매수잔량
+    pub O_offerrem: Option<i64>,     //시간외매도잔량
+    pub O_bidrem: Option<i64>,       //시간외매수잔량
+    pub pivot2upz7: Option<i64>,     //피봇2차저항
+    pub pivot1upz7: Option<i64>,     //피봇1차저항
+    pub pivotz7: Option<i64>,        //피봇가
+    pub pivot1dnz7: Option<i64>,     //피봇1차지지
+    pub pivot2dnz7: Option<i64>,     //피봇2차지지
+    pub sosokz6: String,             //코스피코스닥구분
+    pub jisunamez18: String,         //업종명
+    pub capsizez6: String,           //자본금규모
+    pub output1z16: String,          //결산월
+    pub marcket1z16: String,         //시장조치1
+    pub marcket2z16: String,         //시장조치2
+    pub marcket3z16: String,         //시장조치3
+    pub marcket4z16: String,         //시장조치4
+    pub marcket5z16: String,         //시장조치5
+    pub marcket6z16: String,         //시장조치6
+    pub cbtext: String,              //CB구분
+    pub parvalue: Option<i64>,       //액면가
+    pub prepricetitlez12: String,    //전일종가타이틀
+    pub prepricez7: Option<i64>,     //전일종가
+    pub subprice: Option<i64>,       //대용가
+    pub gongpricez7: Option<i64>,    //공모가
+    pub high5: Option<i64>,          //5일고가
+    pub low5: Option<i64>,           //5일저가
+    pub high20: Option<i64>,         //20일고가
+    pub low20: Option<i64>,          //20일저가
+    pub yhigh: Option<i64>,          //52주최고가
+    pub yhighdate: String,           //52주최고가일
+    pub ylow: Option<i64>,           //52주최저가
+    pub ylowdate: String,            //52주최저가일
+    pub movlistingz8: Option<i64>,   //유동주식수
+    pub listing: Option<i64>,        //상장주식수_천주
+    pub totpricez9: Option<i64>,     //시가총액
+    pub tratimez5: String,           //시간
+    pub off_tra1: String,            //매도거래원1
+    pub bid_tra1: String,            //매수거래원1
+    pub N_offvolume1: Option<i64>,   //매도거래량1
+    pub N_bidvolume1: Option<i64>,   //매수거래량1
+    pub off_tra2: String,            //매도거래원2
+    pub bid_tra2: String,            //매수거래원2
+    pub N_offvolume2: Option<i64>,   //매도거래량2
+    pub N_bidvolume2: Option<i64>,   //매수거래량2
+    pub off_tra3: String,            //매도거래원3
+    pub bid_tra3: String,            //매수거래원3
+    pub N_offvolume3: Option<i64>,   //매도거래량3
+    pub N_bidvolume3: Option<i64>,   //매수거래량3
+    pub off_tra4: String,            //매도거래원4
+    pub bid_tra4: String,            //매수거래원4
+    pub N_offvolume4: Option<i64>,   //매도거래량4
+    pub N_bidvolume4: Option<i64>,   //매수거래량4
+    pub off_tra5: String,            //매도거래원5
+    pub bid_tra5: String,            //매수거래원5
+    pub N_offvolume5: Option<i64>,   //매도거래량5
+    pub N_bidvolume5: Option<i64>,   //매수거래량5
+    pub N_offvolall: Option<i64>,    //매도외국인거래량
+    pub N_bidvolall: Option<i64>,    //매수외국인거래량
+    pub fortimez6: String,           //외국인시간
+    pub forratez5: Option<f64>,      //외국인지분율
+    pub settdatez4: String,          //결제일
+    pub cratez5: Option<f64>,        //잔고비율(%)
+    pub yudatez4: String,            //유상기준일
+    pub mudatez4: String,            //무상기준일
+    pub yuratez5: Option<f64>,       //유상배정비율
+    pub muratez5: Option<f64>,       //무상배정비율
+    pub listdatez8: String,          //상장일
+    pub listing2: Option<i64>,       //상장주식수_주
+    pub N_alloffvol: Option<i64>,    //전체거래원매도합
+    pub N_allbidvol: Option<i64>,    //전체거래원매수합
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C1151Response2 {
+    pub time: String,            //시간
+    pub price: Option<i64>,      //현재가
+    pub sign: Option<PriceSign>, //등락부호
+    pub change: Option<i64>,     //등락폭
+    pub offer: Option<i64>,      //매도호가
+    pub bid: Option<i64>,        //매수호가
+    pub movolume: Option<i64>,   //변동거래량
+    pub volume: Option<i64>,     //거래량
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C1151Response3 {
+    pub dongsi: String,             //동시호가구분
+    pub jeqprice: Option<i64>,      //예상체결가
+    pub jeqsign: Option<PriceSign>, //예상체결부호
+    pub jeqchange: Option<i64>,     //예상체결등락폭
+    pub jeqchrate: Option<f64>,     //예상체결등락률
+    pub jeqvol: Option<i64>,        //예상체결수량
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C1151Response4 {
+    pub bu12: String,               //ETF구분
+    pub nav: Option<f64>,           //장중/최종NAV
+    pub nsign: Option<PriceSign>,   //NAV등락부호
+    pub nchange: Option<f64>,       //NAV등락폭
+    pub prenav: Option<f64>,        //전일NAVV
+    pub grate: Option<f64>,         //괴리율
+    pub gsign: String,              //괴리율부호
+    pub icuz18: Option<i64>,        //CU당현금배당액(원)
+    pub totjo: Option<i64>,         //구성종목수
+    pub totvalue: Option<i64>,      //순자산총액(억원)
+    pub terror: Option<f64>,        //추적오차율
+    pub lpoffremain1: Option<i64>,  //LP매도최우선잔량
+    pub lpoffremain2: Option<i64>,  //LP매도차선잔량
+    pub lpoffremain3: Option<i64>,  //LP매도차차선잔량
+    pub lpoffremain4: Option<i64>,  //LP매도4차선잔량
+    pub lpoffremain5: Option<i64>,  //LP매도5차선잔량
+    pub lpoffremain6: Option<i64>,  //LP매도6차선잔량
+    pub lpoffremain7: Option<i64>,  //LP매도7차선잔량
+    pub lpoffremain8: Option<i64>,  //LP매도8차선잔량
+    pub lpoffremain9: Option<i64>,  //LP매도9차선잔량
+    pub lpoffremain10: Option<i64>, //LP매도10차선잔량
+    pub lpbidremain1: Option<i64>,  //LP매수최우선잔량
+    pub lpbidremain2: Option<i64>,  //LP매수차선잔량
+    pub lpbidremain3: Option<i64>,  //LP매수차차선잔량
+    pub lpbidremain4: Option<i64>,  //LP매수4차선잔량
+    pub lpbidremain5: Option<i64>,  //LP매수5차선잔량
+    pub lpbidremain6: Option<i64>,  //LP매수6차선잔량
+    pub lpbidremain7: Option<i64>,  //LP매수7차선잔량
+    pub lpbidremain8: Option<i64>,  //LP매수8차선잔량
+    pub lpbidremain9: Option<i64>,  //LP매수9차선잔량
+    pub lpbidremain10: Option<i64>, //LP매수10차선잔량
+    pub etf_copy_cd: String,        //ETF복제방법구분코드
+    pub etf_tax_cd: String,         //ETF과세유형코드
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C1151Response5 {
+    pub jisucode: String,             //지수코드
+    pub sectorcode: String,           //섹터코드
+    pub jisuhnamez20: String,         //지수명
+    pub kp200jisu: Option<f64>,       //지수
+    pub kp200sign: Option<PriceSign>, //등락부호
+    pub kp200change: Option<f64>,     //등락폭
+    pub ubjisu: Option<f64>,          //채권지수
+    pub ubsign: Option<PriceSign>,    //채권등락부호
+    pub ubchange: Option<f64>,        //채권등락폭
+    pub symbol: String,               //해외지수심볼
+    pub eupcode: String,              //기타업종코드
+    pub ubjiid: String,               //채권지수코드
+    pub ubjiid2: String,              //채권지수세부코드
+}
+
+pub const BLOCK_NAME_C1151_OUT: &str = "c1151OutBlock";
+pub const BLOCK_NAME_C1151_OUT2_ARRAY: &str = "c1151OutBlock2";
+pub const BLOCK_NAME_C1151_OUT3: &str = "c1151OutBlock3";
+pub const BLOCK_NAME_C1151_OUT4: &str = "c1151OutBlock4";
+pub const BLOCK_NAME_C1151_OUT5: &str = "c1151OutBlock5";
@@ -0,0 +1,591 @@
+use std::ffi::c_char;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::utils::{parse_number, parse_scaled, parse_sign, parse_string, to_fixed_string};
+use crate::{error::*, models::*, QvOpenApiRequest};
+use qvopenapi_bindings::{
+    Tc4113InBlock, Tc4113OutBlock1, Tc4113OutBlock2, Tc4113OutFuteq1, Tc4113OutFuteq2,
+    Tc4113OutHoga1, Tc4113OutHoga2, Tc4113OutHoga3, Tc4113OutKospi200, Tc4113OutSMaster,
+};
+
+pub const TR_CODE_C4113: &str = "c4113";
+
+/**
+ * 코스피200 지수 및 선물 현재가 조회
+ */
+#[derive(Debug, Clone, Deserialize)]
+pub struct C4113Request {
+    pub code: String, //종목코드 (선물 단축코드 8자리)
+}
+
+impl C4113Request {
+    pub fn new(code: &str) -> C4113Request {
+        C4113Request { code: code.into() }
+    }
+
+    pub fn into_raw(&self) -> Result<Arc<RawQueryRequest<Tc4113InBlock>>, QvOpenApiError> {
+        Ok(Arc::new(RawQueryRequest::new(
+            TR_CODE_C4113,
+            // 시세 조회는 계좌와 무관
+            0,
+            Box::new(Tc4113InBlock {
+                fuitemz9: to_fixed_string(&self.code)?,
+                _fuitemz9: ' ' as c_char,
+            }),
+        )))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C4113Output {
+    #[serde(rename = "c4113OutKospi200", default)]
+    pub kospi200: Option<C4113ResponseKospi200>, //코스피200지수
+    #[serde(rename = "c4113OutSMaster", default)]
+    pub smaster: Option<C4113ResponseSMaster>, //선물 기본정보
+    #[serde(rename = "c4113OutBlock1", default)]
+    pub master1: Option<C4113Response1>, //코스피선물Master1
+    #[serde(rename = "c4113OutBlock2", default)]
+    pub master2: Option<C4113Response2>, //코스피선물Master2
+    #[serde(rename = "c4113OutHoga1", default)]
+    pub hoga1: Option<C4113ResponseHoga1>, //코스피선물호가1
+    #[serde(rename = "c4113OutHoga2", default)]
+    pub hoga2: Option<C4113ResponseHoga2>, //코스피선물호가2
+    #[serde(rename = "c4113OutHoga3", default)]
+    pub spread_hoga: Option<C4113ResponseHoga3>, //코스피선물스프레드호가3
+    #[serde(rename = "c4113OutFuteq1", default)]
+    pub expected1: Option<C4113ResponseFuteq1>, //선물예상체결1
+    #[serde(rename = "c4113OutFuteq2", default)]
+    pub expected2: Option<C4113ResponseFuteq2>, //선물예상체결2
+}
+
+impl TypedQueryRequest for C4113Request {
+    type Output = C4113Output;
+
+    fn to_query(&self) -> Result<Arc<dyn QvOpenApiRequest>, QvOpenApiError> {
+        Ok(self.into_raw()?)
+    }
+}
+
+pub fn parse_c4113_response_kospi200(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Tc4113OutKospi200));
+        Ok(json!(C4113ResponseKospi200 {
+            fuitem: parse_string(&res.fuitem)?,
+            fucurr: parse_scaled(&res.fucurr, 2)?,
+            fusign: parse_sign(&res.fusign)?,
+            fuchange: parse_scaled(&res.fuchange, 2)?,
+            fuopen: parse_scaled(&res.fuopen, 2)?,
+            fuhigh: parse_scaled(&res.fuhigh, 2)?,
+            fulow: parse_scaled(&res.fulow, 2)?,
+            fuvolall: parse_number(&res.fuvolall)?,
+        }))
+    }
+}
+
+pub fn parse_c4113_response_smaster(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Tc4113OutSMaster));
+        Ok(json!(C4113ResponseSMaster {
+            fuitemz8: parse_string(&res.fuitemz8)?,
+            fuspcurr: parse_scaled(&res.fuspcurr, 2)?,
+            fuspsign: parse_sign(&res.fuspsign)?,
+            fuspchange: parse_scaled(&res.fuspchange, 2)?,
+            fuspchrate: parse_scaled(&res.fuspchrate, 2)?,
+            fuspopen: parse_scaled(&res.fuspopen, 2)?,
+            fusphigh: parse_scaled(&res.fusphigh, 2)?,
+            fusplow: parse_scaled(&res.fusplow, 2)?,
+            fuspvolall: parse_number(&res.fuspvolall)?,
+            fuspvalall: parse_number(&res.fuspvalall)?,
+            fuspcurr1: parse_scaled(&res.fuspcurr1, 2)?,
+            fuspcurr2: parse_scaled(&res.fuspcurr2, 2)?,
+            fudynhprice: parse_scaled(&res.fudynhprice, 2)?,
+            fudynlprice: parse_scaled(&res.fudynlprice, 2)?,
+            fudynpriceflag: parse_string(&res.fudynpriceflag)?,
+        }))
+    }
+}
+
+pub fn parse_c4113_response1(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Tc4113OutBlock1));
+        Ok(json!(C4113Response1 {
+            fuitem: parse_string(&res.fuitem)?,
+            fuchetime: parse_string(&res.fuchetime)?,
+            fuhname: parse_string(&res.fuhname)?,
+            fucurr: parse_scaled(&res.fucurr, 2)?,
+            fusign: parse_sign(&res.fusign)?,
+            fuchange: parse_scaled(&res.fuchange, 2)?,
+            fuchrate: parse_scaled(&res.fuchrate, 2)?,
+            fubasis: parse_scaled(&res.fubasis, 2)?,
+            futheoryprice: parse_scaled(&res.futheoryprice, 2)?,
+            fugrate: parse_scaled(&res.fugrate, 2)?,
+            fugratio: parse_scaled(&res.fugratio, 2)?,
+            fuvolall: parse_number(&res.fuvolall)?,
+            fuvalall: parse_number(&res.fuvalall)?,
+            fuopenyak: parse_number(&res.fuopenyak)?,
+            fupreopenyak: parse_number(&res.fupreopenyak)?,
+            fujgubun: parse_string(&res.fujgubun)?,
+            fuopen: parse_scaled(&res.fuopen, 2)?,
+            fuhigh: parse_scaled(&res.fuhigh, 2)?,
+            fulow: parse_scaled(&res.fulow, 2)?,
+            fudynhprice: parse_scaled(&res.fudynhprice, 2)?,
+            fudynlprice: parse_scaled(&res.fudynlprice, 2)?,
+            fudynpriceflag: parse_string(&res.fudynpriceflag)?,
+        }))
+    }
+}
+
+pub fn parse_c4113_response2(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Tc4113OutBlock2));
+        Ok(json!(C4113Response2 {
+            fuitem: parse_string(&res.fuitem)?,
+            fuchetime: parse_string(&res.fuchetime)?,
+            fuhname: parse_string(&res.fuhname)?,
+            fucurr: parse_scaled(&res.fucurr, 2)?,
+            fusign: parse_sign(&res.fusign)?,
+            fuchange: parse_scaled(&res.fuchange, 2)?,
+            fuchrate: parse_scaled(&res.fuchrate, 2)?,
+            fubasis: parse_scaled(&res.fubasis, 2)?,
+            futheoryprice: parse_scaled(&res.futheoryprice, 2)?,
+            fugrate: parse_scaled(&res.fugrate, 2)?,
+            fugratio: parse_scaled(&res.fugratio, 2)?,
+            fuvolall: parse_number(&res.fuvolall)?,
+            fuvalall: parse_number(&res.fuvalall)?,
+            fuopenyak: parse_number(&res.fuopenyak)?,
+            fupreopenyak: parse_number(&res.fupreopenyak)?,
+            fujgubun: parse_string(&res.fujgubun)?,
+            fuopen: parse_scaled(&res.fuopen, 2)?,
+            fuhigh: parse_scaled(&res.fuhigh, 2)?,
+            fulow: parse_scaled(&res.fulow, 2)?,
+            fudynhprice: parse_scaled(&res.fudynhprice, 2)?,
+            fudynlprice: parse_scaled(&res.fudynlprice, 2)?,
+            fudynpriceflag: parse_string(&res.fudynpriceflag)?,
+        }))
+    }
+}
+
+pub fn parse_c4113_response_hoga1(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Tc4113OutHoga1));
+        Ok(json!(C4113ResponseHoga1 {
+            fuitem: parse_string(&res.fuitem)?,
+            fuhotime: parse_string(&res.fuhotime)?,
+            fuoffer: parse_scaled(&res.fuoffer, 2)?,
+            fujoffer: parse_scaled(&res.fujoffer, 2)?,
+            fujjoffer: parse_scaled(&res.fujjoffer, 2)?,
+            fuj4offer: parse_scaled(&res.fuj4offer, 2)?,
+            fuj5offer: parse_scaled(&res.fuj5offer, 2)?,
+            fuofferjan: parse_number(&res.fuofferjan)?,
+            fujofferjan: parse_number(&res.fujofferjan)?,
+            fujjofferjan: parse_number(&res.fujjofferjan)?,
+            fuj4offerjan: parse_number(&res.fuj4offerjan)?,
+            fuj5offerjan: parse_number(&res.fuj5offerjan)?,
+            fubid: parse_scaled(&res.fubid, 2)?,
+            fujbid: parse_scaled(&res.fujbid, 2)?,
+            fujjbid: parse_scaled(&res.fujjbid, 2)?,
+            fuj4bid: parse_scaled(&res.fuj4bid, 2)?,
+            fuj5bid: parse_scaled(&res.fuj5bid, 2)?,
+            fubidjan: parse_number(&res.fubidjan)?,
+            fujbidjan: parse_number(&res.fujbidjan)?,
+            fujjbidjan: parse_number(&res.fujjbidjan)?,
+            fuj4bidjan: parse_number(&res.fuj4bidjan)?,
+            fuj5bidjan: parse_number(&res.fuj5bidjan)?,
+            futofferjan: parse_number(&res.futofferjan)?,
+            futbidjan: parse_number(&res.futbidjan)?,
+            fuoffersu: parse_number(&res.fuoffersu)?,
+            fujoffersu: parse_number(&res.fujoffersu)?,
+            fujjoffersu: parse_number(&res.fujjoffersu)?,
+            fuj4offersu: parse_number(&res.fuj4offersu)?,
+            fuj5offersu: parse_number(&res.fuj5offersu)?,
+            fubidsu: parse_number(&res.fubidsu)?,
+            fujbidsu: parse_number(&res.fujbidsu)?,
+            fujjbidsu: parse_number(&res.fujjbidsu)?,
+            fuj4bidsu: parse_number(&res.fuj4bidsu)?,
+            fuj5bidsu: parse_number(&res.fuj5bidsu)?,
+            futoffersu: parse_number(&res.futoffersu)?,
+            futbidsu: parse_number(&res.futbidsu)?,
+            fuhname: parse_string(&res.fuhname)?,
+        }))
+    }
+}
+
+pub fn parse_c4113_response_hoga2(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Tc4113OutHoga2));
+        Ok(json!(C4113ResponseHoga2 {
+            fuitem: parse_string(&res.fuitem)?,
+            fuhotime: parse_string(&res.fuhotime)?,
+            fuoffer: parse_scaled(&res.fuoffer, 2)?,
+            fujoffer: parse_scaled(&res.fujoffer, 2)?,
+            fujjoffer: parse_scaled(&res.fujjoffer, 2)?,
+            fuj4offer: parse_scaled(&res.fuj4offer, 2)?,
+            fuj5offer: parse_scaled(&res.fuj5offer, 2)?,
+            fuofferjan: parse_number(&res.fuofferjan)?,
+            fujofferjan: parse_number(&res.fujofferjan)?,
+            fujjofferjan: parse_number(&res.fujjofferjan)?,
+            fuj4offerjan: parse_number(&res.fuj4offerjan)?,
+            fuj5offerjan: parse_number(&res.fuj5offerjan)?,
+            fubid: parse_scaled(&res.fubid, 2)?,
+            fujbid: parse_scaled(&res.fujbid, 2)?,
+            fujjbid: parse_scaled(&res.fujjbid, 2)?,
+            fuj4bid: parse_scaled(&res.fuj4bid, 2)?,
+            fuj5bid: parse_scaled(&res.fuj5bid, 2)?,
+            fubidjan: parse_number(&res.fubidjan)?,
+            fujbidjan: parse_number(&res.fujbidjan)?,
+            fujjbidjan: parse_number(&res.fujjbidjan)?,
+            fuj4bidjan: parse_number(&res.fuj4bidjan)?,
+            fuj5bidjan: parse_number(&res.fuj5bidjan)?,
+            futofferjan: parse_number(&res.futofferjan)?,
+            futbidjan: parse_number(&res.futbidjan)?,
+            fuoffersu: parse_number(&res.fuoffersu)?,
+            fujoffersu: parse_number(&res.fujoffersu)?,
+            fujjoffersu: parse_number(&res.fujjoffersu)?,
+            fuj4offersu: parse_number(&res.fuj4offersu)?,
+            fuj5offersu: parse_number(&res.fuj5offersu)?,
+            fubidsu: parse_number(&res.fubidsu)?,
+            fujbidsu: parse_number(&res.fujbidsu)?,
+            fujjbidsu: parse_number(&res.fujjbidsu)?,
+            fuj4bidsu: parse_number(&res.fuj4bidsu)?,
+            fuj5bidsu: parse_number(&res.fuj5bidsu)?,
+            futoffersu: parse_number(&res.futoffersu)?,
+            futbidsu: parse_number(&res.futbidsu)?,
+            fuhname: parse_string(&res.fuhname)?,
+        }))
+    }
+}
+
+pub fn parse_c4113_response_hoga3(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Tc4113OutHoga3));
+        Ok(json!(C4113ResponseHoga3 {
+            fuspfuitem: parse_string(&res.fuspfuitem)?,
+            fusphname: parse_string(&res.fusphname)?,
+            fusphotime: parse_string(&res.fusphotime)?,
+            fuspoffer: parse_scaled(&res.fuspoffer, 2)?,
+            fuspjoffer: parse_scaled(&res.fuspjoffer, 2)?,
+            fuspjjoffer: parse_scaled(&res.fuspjjoffer, 2)?,
+            fuspj4offer: parse_scaled(&res.fuspj4offer, 2)?,
+            fuspj5offer: parse_scaled(&res.fuspj5offer, 2)?,
+            fuspofferjan: parse_number(&res.fuspofferjan)?,
+            fuspjofferjan: parse_number(&res.fuspjofferjan)?,
+            fuspjjofferjan: parse_number(&res.fuspjjofferjan)?,
+            fuspj4offerjan: parse_number(&res.fuspj4offerjan)?,
+            fuspj5offerjan: parse_number(&res.fuspj5offerjan)?,
+            fuspbid: parse_scaled(&res.fuspbid, 2)?,
+            fuspjbid: parse_scaled(&res.fuspjbid, 2)?,
+            fuspjjbid: parse_scaled(&res.fuspjjbid, 2)?,
+            fuspj4bid: parse_scaled(&res.fuspj4bid, 2)?,
+            fuspj5bid: parse_scaled(&res.fuspj5bid, 2)?,
+            fuspbidjan: parse_number(&res.fuspbidjan)?,
+            fuspjbidjan: parse_number(&res.fuspjbidjan)?,
+            fuspjjbidjan: parse_number(&res.fuspjjbidjan)?,
+            fuspj4bidjan: parse_number(&res.fuspj4bidjan)?,
+            fuspj5bidjan: parse_number(&res.fuspj5bidjan)?,
+            fusptofferjan: parse_number(&res.fusptofferjan)?,
+            fusptbidjan: parse_number(&res.fusptbidjan)?,
+            fuspoffersu: parse_number(&res.fuspoffersu)?,
+            fuspjoffersu: parse_number(&res.fuspjoffersu)?,
+            fuspjjoffersu: parse_number(&res.fuspjjoffersu)?,
+            fuspj4offersu: parse_number(&res.fuspj4offersu)?,
+            fuspj5offersu: parse_number(&res.fuspj5offersu)?,
+            fuspbidsu: parse_number(&res.fuspbidsu)?,
+            fuspjbidsu: parse_number(&res.fuspjbidsu)?,
+            fuspjjbidsu: parse_number(&res.fuspjjbidsu)?,
+            fuspj4bidsu: parse_number(&res.fuspj4bidsu)?,
+            fuspj5bidsu: parse_number(&res.fuspj5bidsu)?,
+            fusptoffersu: parse_number(&res.fusptoffersu)?,
+            fusptbidsu: parse_number(&res.fusptbidsu)?,
+        }))
+    }
+}
+
+pub fn parse_c4113_response_futeq1(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Tc4113OutFuteq1));
+        Ok(json!(C4113ResponseFuteq1 {
+            dongsi: parse_string(&res.dongsi)?,
+            jeqprice: parse_scaled(&res.jeqprice, 2)?,
+            jeqsign: parse_sign(&res.jeqsign)?,
+            jeqchange: parse_scaled(&res.jeqchange, 2)?,
+            jeqchrate: parse_scaled(&res.jeqchrate, 2)?,
+        }))
+    }
+}
+
+pub fn parse_c4113_response_futeq2(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Tc4113OutFuteq2));
+        Ok(json!(C4113ResponseFuteq2 {
+            dongsi: parse_string(&res.dongsi)?,
+            jeqprice: parse_scaled(&res.jeqprice, 2)?,
+            jeqsign: parse_sign(&res.jeqsign)?,
+            jeqchange: parse_scaled(&res.jeqchange, 2)?,
+            jeqchrate: parse_scaled(&res.jeqchrate, 2)?,
+        }))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C4113ResponseKospi200 {
+    pub fuitem: String,            //종목코드
+    pub fucurr: Option<f64>,       //현물지수
+    pub fusign: Option<PriceSign>, //전일비부호
+    pub fuchange: Option<f64>,     //전일비
+    pub fuopen: Option<f64>,       //시가
+    pub fuhigh: Option<f64>,       //고가
+    pub fulow: Option<f64>,        //저가
+    pub fuvolall: Option<i64>,     //거래량
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C4113ResponseSMaster {
+    pub fuitemz8: String,            //종목코드
+    pub fuspcurr: Option<f64>,       //지수
+    pub fuspsign: Option<PriceSign>, //전일비부호
+    pub fuspchange: Option<f64>,     //전일비
+    pub fuspchrate: Option<f64>,     //등락률
+    pub fuspopen: Option<f64>,       //시가
+    pub fusphigh: Option<f64>,       //고가
+    pub fusplow: Option<f64>,        //저가
+    pub fuspvolall: Option<i64>,     //거래량
+    pub fuspvalall: Option<i64>,     //누적거래대금(백만원)
+    pub fuspcurr1: Option<f64>,      //의제약정가(근월물)
+    pub fuspcurr2: Option<f64>,      //의제약정가(원월물)
+    pub fudynhprice: Option<f64>,    //실시간상한가
+    pub fudynlprice: Option<f64>,    //실시간하한가
+    pub fudynpriceflag: String,      //동적가격제한여부
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C4113Response1 {
+    pub fuitem: String,             //종목코드
+    pub fuchetime: String,          //체결시간
+    pub fuhname: String,            //한글명
+    pub fucurr: Option<f64>,        //현재가
+    pub fusign: Option<PriceSign>,  //전일대비부호
+    pub fuchange: Option<f64>,      //전일대비
+    pub fuchrate: Option<f64>,      //등락률
+    pub fubasis: Option<f64>,       //베이시스
+    pub futheoryprice: Option<f64>, //이론가
+    pub fugrate: Option<f64>,       //괴리도
+    pub fugratio: Option<f64>,      //괴리율
+    pub fuvolall: Option<i64>,      //누적체결수량
+    pub fuvalall: Option<i64>,      //누적거래대금(백만원)
+    pub fuopenyak: Option<i64>,     //미결제약정수량
+    pub fupreopenyak: Option<i64>,  //미결제약정전일
+    pub fujgubun: String,           //장운용
+    pub fuopen: Option<f64>,        //시가
+    pub fuhigh: Option<f64>,        //고가
+    pub fulow: Option<f64>,         //저가
+    pub fudynhprice: Option<f64>,   //실시간상한가
+    pub fudynlprice: Option<f64>,   //실시간하한가
+    pub fudynpriceflag: String,     //동적가격제한여부
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C4113Response2 {
+    pub fuitem: String,             //종목코드
+    pub fuchetime: String,          //체결시간
+    pub fuhname: String,            //한글명
+    pub fucurr: Option<f64>,        //현재가
+    pub fusign: Option<PriceSign>,  //전일대비부호
+    pub fuchange: Option<f64>,      //전일대비
+    pub fuchrate: Option<f64>,      //등락률
+    pub fubasis: Option<f64>,       //베이시스
+    pub futheoryprice: Option<f64>, //이론가
+    pub fugrate: Option<f64>,       //괴리도
+    pub fugratio: Option<f64>,      //괴리율
+    pub fuvolall: Option<i64>,      //누적체결수량
+    pub fuvalall: Option<i64>,      //누적거래대금(백만원)
+    pub fuopenyak: Option<i64>,     //미결제약정수량
+    pub fupreopenyak: Option<i64>,  //미결제약정전일
+    pub fujgubun: String,           //장운용
+    pub fuopen: Option<f64>,        //시가
+    pub fuhigh: Option<f64>,        //고가
+    pub fulow: Option<f64>,         //저가
+    pub fudynhprice: Option<f64>,   //실시간상한가
+    pub fudynlprice: Option<f64>,   //실시간하한가
+    pub fudynpriceflag: String,     //동적가격제한여부
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C4113ResponseHoga1 {
+    pub fuitem: String,            //종목코드
+    pub fuhotime: String,          //호가시간
+    pub fuoffer: Option<f64>,      //매도우선호가
+    pub fujoffer: Option<f64>,     //차선매도호가
+    pub fujjoffer: Option<f64>,    //차차선매도호가
+    pub fuj4offer: Option<f64>,    //4차선매도호가
+    pub fuj5offer: Option<f64>,    //5차선매도호가
+    pub fuofferjan: Option<i64>,   //매도잔량
+    pub fujofferjan: Option<i64>,  //차선매도호가잔량
+    pub fujjofferjan: Option<i64>, //차차선매도호가잔량
+    pub fuj4offerjan: Option<i64>, //4차선매도호가잔량
+    pub fuj5offerjan: Option<i64>, //5차선매도호가잔량
+    pub fubid: Option<f64>,        //매수우선호가
+    pub fujbid: Option<f64>,       //차선매수호가
+    pub fujjbid: Option<f64>,      //차차선매수호가
+    pub fuj4bid: Option<f64>,      //4차선매수호가
+    pub fuj5bid: Option<f64>,      //5차선매수호가
+    pub fubidjan: Option<i64>,     //매수잔량
+    pub fujbidjan: Option<i64>,    //차선매수호가잔량
+    pub fujjbidjan: Option<i64>,   //차차선매수호가잔량
+    pub fuj4bidjan: Option<i64>,   //4차선매수호가잔량
+    pub fuj5bidjan: Option<i64>,   //5차선매수호가잔량
+    pub futofferjan: Option<i64>,  //총매도잔량
+    pub futbidjan: Option<i64>,    //총매수잔량
+    pub fuoffersu: Option<i64>,    //매도최우선건수
+    pub fujoffersu: Option<i64>,   //매도차선건수
+    pub fujjoffersu: Option<i64>,  //매도차차선건수
+    pub fuj4offersu: Option<i64>,  //매도4차선건수
+    pub fuj5offersu: Option<i64>,  //매도5차선건수
+    pub fubidsu: Option<i64>,      //매수최우선건수
+    pub fujbidsu: Option<i64>,     //매수차선건수
+    pub fujjbidsu: Option<i64>,    //매수차차선건수
+    pub fuj4bidsu: Option<i64>,    //매수4차선건수
+    pub fuj5bidsu: Option<i64>,    //매수5차선건수
+    pub futoffersu: Option<i64>,   //총매도건수
+    pub futbidsu: Option<i64>,     //총매수건수
+    pub fuhname: String,           //한글명
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C4113ResponseHoga2 {
+    pub fuitem: String,            //종목코드
+    pub fuhotime: String,          //호가시간
+    pub fuoffer: Option<f64>,      //매도우선호가
+    pub fujoffer: Option<f64>,     //차선매도호가
+    pub fujjoffer: Option<f64>,    //차차선매도호가
+    pub fuj4offer: Option<f64>,    //4차선매도호가
+    pub fuj5offer: Option<f64>,    //5차선매도호가
+    pub fuofferjan: Option<i64>,   //매도잔량
+    pub fujofferjan: Option<i64>,  //차선매도호가잔량
+    pub fujjofferjan: Option<i64>, //차차선매도호가잔량
+    pub fuj4offerjan: Option<i64>, //4차선매도호가잔량
+    pub fuj5offerjan: Option<i64>, //5차선매도호가잔량
+    pub fubid: Option<f64>,        //매수우선호가
+    pub fujbid: Option<f64>,       //차선매수호가
+    pub fujjbid: Option<f64>,      //차차선매수호가
+    pub fuj4bid: Option<f64>,      //4차선매수호가
+    pub fuj5bid: Option<f64>,      //5차선매수호가
+    pub fubidjan: Option<i64>,     //매수잔량
+    pub fujbidjan: Option<i64>,    //차선매수호가잔량
+    pub fujjbidjan: Option<i64>,   //차차선매수호가잔량
+    pub fuj4bidjan: Option<i64>,   //4차선매수호가잔량
+    pub fuj5bidjan: Option<i64>,   //5차선매수호가잔량
+    pub futofferjan: Option<i64>,  //총매도잔량
+    pub futbidjan: Option<i64>,    //총매수잔량
+    pub fuoffersu: Option<i64>,    //매도최우선건수
+    pub fujoffersu: Option<i64>,   //매도차선건수
+    pub fujjoffersu: Option<i64>,  //매도차차선건수
+    pub fuj4offersu: Option<i64>,  //매도4차선건수
+    pub fuj5offersu: Option<i64>,  //매도5차선건수
+    pub fubidsu: Option<i64>,      //매수최우선건수
+    pub fujbidsu: Option<i64>,     //매수차선건수
+    pub fujjbidsu: Option<i64>,    //매수차차선건수
+    pub fuj4bidsu: Option<i64>,    //매수4차선건수
+    pub fuj5bidsu: Option<i64>,    //매수5차선건수
+    pub futoffersu: Option<i64>,   //총매도건수
+    pub futbidsu: Option<i64>,     //총매수건수
+    pub fuhname: String,           //한글명
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C4113ResponseHoga3 {
+    pub fuspfuitem: String,          //종목코드
+    pub fusphname: String,           //한글명
+    pub fusphotime: String,          //호가시간
+    pub fuspoffer: Option<f64>,      //매도우선호가
+    pub fuspjoffer: Option<f64>,     //차선매도호가
+    pub fuspjjoffer: Option<f64>,    //차차선매도호가
+    pub fuspj4offer: Option<f64>,    //4차선매도호가
+    pub fuspj5offer: Option<f64>,    //5차선매도호가
+    pub fuspofferjan: Option<i64>,   //매도잔량
+    pub fuspjofferjan: Option<i64>,  //차선매도호가잔량
+    pub fuspjjofferjan: Option<i64>, //차차선매도호가잔량
+    pub fuspj4offerjan: Option<i64>, //4차선매도호가잔량
+    pub fuspj5offerjan: Option<i64>, //5차선매도호가잔량
+    pub fuspbid: Option<f64>,        //매수우선호가
+    pub fuspjbid: Option<f64>,       //차선매수호가
+    pub fuspjjbid: Option<f64>,      //차차선매수호가
+    pub fuspj4bid: Option<f64>,      //4차선매수호가
+    pub fuspj5bid: Option<f64>,      //5차선매수호가
+    pub fuspbidjan: Option<i64>,     //매수잔량
+    pub fuspjbidjan: Option<i64>,    //차선매수호가잔량
+    pub fuspjjbidjan: Option<i64>,   //차차선매수호가잔량
+    pub fuspj4bidjan: Option<i64>,   //4차선매수호가잔량
+    pub fuspj5bidjan: Option<i64>,   //5차선매수호가잔량
+    pub fusptofferjan: Option<i64>,  //총매도잔량
+    pub fusptbidjan: Option<i64>,    //총매수잔량
+    pub fuspoffersu: Option<i64>,    //매도최우선건수
+    pub fuspjoffersu: Option<i64>,   //매도차선건수
+    pub fuspjjoffersu: Option<i64>,  //매도차차선건수
+    pub fuspj4offersu: Option<i64>,  //매도4차선건수
+    pub fuspj5offersu: Option<i64>,  //매도5차선건수
+    pub fuspbidsu: Option<i64>,      //매수최우선건수
+    pub fuspjbidsu: Option<i64>,     //매수차선건수
+    pub fuspjjbidsu: Option<i64>,    //매수차차선건수
+    pub fuspj4bidsu: Option<i64>,    //매수4차선건수
+    pub fuspj5bidsu: Option<i64>,    //매수5차선건수
+    pub fusptoffersu: Option<i64>,   //총매도건수
+    pub fusptbidsu: Option<i64>,     //총매수건수
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C4113ResponseFuteq1 {
+    pub dongsi: String,             //동시호가구분
+    pub jeqprice: Option<f64>,      //예상체결가
+    pub jeqsign: Option<PriceSign>, //예상체결부호
+    pub jeqchange: Option<f64>,     //예상체결등락폭
+    pub jeqchrate: Option<f64>,     //예상체결등락률
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C4113ResponseFuteq2 {
+    pub dongsi: String,             //동시호가구분
+    pub jeqprice: Option<f64>,      //예상체결가
+    pub jeqsign: Option<PriceSign>, //예상체결부호
+    pub jeqchange: Option<f64>,     //예상체결등락폭
+    pub jeqchrate: Option<f64>,     //예상체결등락률
+}
+
+pub const BLOCK_NAME_C4113_OUT_KOSPI200: &str = "c4113OutKospi200";
+pub const BLOCK_NAME_C4113_OUT_SMASTER: &str = "c4113OutSMaster";
+pub const BLOCK_NAME_C4113_OUT1: &str = "c4113OutBlock1";
+pub const BLOCK_NAME_C4113_OUT2: &str = "c4113OutBlock2";
+pub const BLOCK_NAME_C4113_OUT_HOGA1: &str = "c4113OutHoga1";
+pub const BLOCK_NAME_C4113_OUT_HOGA2: &str = "c4113OutHoga2";
+pub const BLOCK_NAME_C4113_OUT_HOGA3: &str = "c4113OutHoga3";
+pub const BLOCK_NAME_C4113_OUT_FUTEQ1: &str = "c4113OutFuteq1";
+pub const BLOCK_NAME_C4113_OUT_FUTEQ2: &str = "c4113OutFuteq2";
@@ -0,0 +1,373 @@
+use std::ffi::c_char;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::utils::{parse_decimal, parse_number, parse_sign, parse_string, to_fixed_string};
+use crate::{error::*, models::*, QvOpenApiRequest};
+use qvopenapi_bindings::{Tc4801InBlock, Tc4801OutBlock, Tc4801OutBlock1, Tc4801OutBlock2};
+
+pub const TR_CODE_C4801: &str = "c4801";
+
+/**
+ * 주식선물 현재가 조회
+ */
+#[derive(Debug, Clone, Deserialize)]
+pub struct C4801Request {
+    pub formlang: char, //한영구분 (k:한글, 기타:영문)
+    pub code: String,   //종목코드 (거래소 단축코드 8자리)
+}
+
+impl C4801Request {
+    pub fn new(formlang: char, code: &str) -> C4801Request {
+        C4801Request {
+            formlang,
+            code: code.into(),
+        }
+    }
+
+    pub fn into_raw(&self) -> Result<Arc<RawQueryRequest<Tc4801InBlock>>, QvOpenApiError> {
+        Ok(Arc::new(RawQueryRequest::new(
+            TR_CODE_C4801,
+            // 시세 조회는 계좌와 무관
+            0,
+            Box::new(Tc4801InBlock {
+                formlang: [self.formlang as c_char],
+                _formlang: ' ' as c_char,
+                fuitemz9: to_fixed_string(&self.code)?,
+                _fuitemz9: ' ' as c_char,
+            }),
+        )))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C4801Output {
+    #[serde(rename = "c4801OutBlock")]
+    pub master: C4801Response, //주식선물MASTER기본자료
+    #[serde(rename = "c4801OutBlock1", default)]
+    pub underlying: Option<C4801Response1>, //기초자산
+    #[serde(rename = "c4801OutBlock2", default)]
+    pub expected: Option<C4801Response2>, //주식선물예상체결
+}
+
+impl TypedQueryRequest for C4801Request {
+    type Output = C4801Output;
+
+    fn to_query(&self) -> Result<Arc<dyn QvOpenApiRequest>, QvOpenApiError> {
+        Ok(self.into_raw()?)
+    }
+}
+
+pub fn parse_c4801_response(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Tc4801OutBlock));
+        Ok(json!(C4801Response {
+            expcode: parse_string(&res.expcode)?,
+            hname: parse_string(&res.hname)?,
+            ename: parse_string(&res.ename)?,
+            sname: parse_string(&res.sname)?,
+            baseprice: parse_number(&res.baseprice)?,
+            hprice: parse_number(&res.hprice)?,
+            lprice: parse_number(&res.lprice)?,
+            preclose: parse_number(&res.preclose)?,
+            unit: parse_string(&res.unit)?,
+            openyak: parse_number(&res.openyak)?,
+            fusign: parse_sign(&res.fusign)?,
+            fuchange: parse_number(&res.fuchange)?,
+            fucurr: parse_number(&res.fucurr)?,
+            fuopen: parse_number(&res.fuopen)?,
+            fuhigh: parse_number(&res.fuhigh)?,
+            fulow: parse_number(&res.fulow)?,
+            fuvolall: parse_number(&res.fuvolall)?,
+            fuspvolall: parse_number(&res.fuspvolall)?,
+            fuvalall: parse_number(&res.fuvalall)?,
+            hotime: parse_string(&res.hotime)?,
+            offer: parse_number(&res.offer)?,
+            bid: parse_number(&res.bid)?,
+            offerjan: parse_number(&res.offerjan)?,
+            bidjan: parse_number(&res.bidjan)?,
+            S2offer: parse_number(&res.S2offer)?,
+            S2bid: parse_number(&res.S2bid)?,
+            S2offerjan: parse_number(&res.S2offerjan)?,
+            S2bidjan: parse_number(&res.S2bidjan)?,
+            S3offer: parse_number(&res.S3offer)?,
+            S3bid: parse_number(&res.S3bid)?,
+            S3offerjan: parse_number(&res.S3offerjan)?,
+            S3bidjan: parse_number(&res.S3bidjan)?,
+            S4offer: parse_number(&res.S4offer)?,
+            S4bid: parse_number(&res.S4bid)?,
+            S4offerjan: parse_number(&res.S4offerjan)?,
+            S4bidjan: parse_number(&res.S4bidjan)?,
+            S5offer: parse_number(&res.S5offer)?,
+            S5bid: parse_number(&res.S5bid)?,
+            S5offerjan: parse_number(&res.S5offerjan)?,
+            S5bidjan: parse_number(&res.S5bidjan)?,
+            S6offer: parse_number(&res.S6offer)?,
+            S6bid: parse_number(&res.S6bid)?,
+            S6offerjan: parse_number(&res.S6offerjan)?,
+            S6bidjan: parse_number(&res.S6bidjan)?,
+            S7offer: parse_number(&res.S7offer)?,
+            S7bid: parse_number(&res.S7bid)?,
+            S7offerjan: parse_number(&res.S7offerjan)?,
+            S7bidjan: parse_number(&res.S7bidjan)?,
+            S8offer: parse_number(&res.S8offer)?,
+            S8bid: parse_number(&res.S8bid)?,
+            S8offerjan: parse_number(&res.S8offerjan)?,
+            S8bidjan: parse_number(&res.S8bidjan)?,
+            S9offer: parse_number(&res.S9offer)?,
+            S9bid: parse_number(&res.S9bid)?,
+            S9offerjan: parse_number(&res.S9offerjan)?,
+            S9bidjan: parse_number(&res.S9bidjan)?,
+            S0offer: parse_number(&res.S0offer)?,
+            S0bid: parse_number(&res.S0bid)?,
+            S0offerjan: parse_number(&res.S0offerjan)?,
+            S0bidjan: parse_number(&res.S0bidjan)?,
+            offersu: parse_number(&res.offersu)?,
+            bidsu: parse_number(&res.bidsu)?,
+            S2offersu: parse_number(&res.S2offersu)?,
+            S2bidsu: parse_number(&res.S2bidsu)?,
+            S3offersu: parse_number(&res.S3offersu)?,
+            S3bidsu: parse_number(&res.S3bidsu)?,
+            S4offersu: parse_number(&res.S4offersu)?,
+            S4bidsu: parse_number(&res.S4bidsu)?,
+            S5offersu: parse_number(&res.S5offersu)?,
+            S5bidsu: parse_number(&res.S5bidsu)?,
+            S6offersu: parse_number(&res.S6offersu)?,
+            S6bidsu: parse_number(&res.S6bidsu)?,
+            S7offersu: parse_number(&res.S7offersu)?,
+            S7bidsu: parse_number(&res.S7bidsu)?,
+            S8offersu: parse_number(&res.S8offersu)?,
+            S8bidsu: parse_number(&res.S8bidsu)?,
+            S9offersu: parse_number(&res.S9offersu)?,
+            S9bidsu: parse_number(&res.S9bidsu)?,
+            S0offersu: parse_number(&res.S0offersu)?,
+            S0bidsu: parse_number(&res.S0bidsu)?,
+            tofferjan: parse_number(&res.tofferjan)?,
+            tobidjan: parse_number(&res.tobidjan)?,
+            toffersu: parse_number(&res.toffersu)?,
+            tbidsu: parse_number(&res.tbidsu)?,
+            theorytime: parse_string(&res.theorytime)?,
+            theoryprice: parse_decimal(&res.theoryprice)?,
+            fuchrate: parse_decimal(&res.fuchrate)?,
+            fupivot2upz7: parse_number(&res.fupivot2upz7)?,
+            fupivot1upz7: parse_number(&res.fupivot1upz7)?,
+            fupivotz7: parse_number(&res.fupivotz7)?,
+            fupivot1dnz7: parse_number(&res.fupivot1dnz7)?,
+            fupivot2dnz7: parse_number(&res.fupivot2dnz7)?,
+            fubasis: parse_decimal(&res.fubasis)?,
+            fugrate: parse_decimal(&res.fugrate)?,
+            fugratio: parse_decimal(&res.fugratio)?,
+            fupreopenyak: parse_number(&res.fupreopenyak)?,
+            fulisthprice: parse_number(&res.fulisthprice)?,
+            fulisthdate: parse_string(&res.fulisthdate)?,
+            fulistlprice: parse_number(&res.fulistlprice)?,
+            fulistldate: parse_string(&res.fulistldate)?,
+            fulastdate: parse_string(&res.fulastdate)?,
+            fujandatecnt: parse_number(&res.fujandatecnt)?,
+            fucdratio: parse_decimal(&res.fucdratio)?,
+            fuopenchange: parse_number(&res.fuopenchange)?,
+            fudynhprice: parse_number(&res.fudynhprice)?,
+            fudynlprice: parse_number(&res.fudynlprice)?,
+            fudynpriceflag: parse_string(&res.fudynpriceflag)?,
+            exlmtgb: parse_string(&res.exlmtgb)?,
+            uplmtgb: parse_string(&res.uplmtgb)?,
+            dnlmtgb: parse_string(&res.dnlmtgb)?,
+        }))
+    }
+}
+
+pub fn parse_c4801_response1(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Tc4801OutBlock1));
+        Ok(json!(C4801Response1 {
+            shcode: parse_string(&res.shcode)?,
+            hname: parse_string(&res.hname)?,
+            price: parse_number(&res.price)?,
+            sign: parse_sign(&res.sign)?,
+            change: parse_number(&res.change)?,
+            market: parse_string(&res.market)?,
+            chrate: parse_decimal(&res.chrate)?,
+            offer: parse_number(&res.offer)?,
+            bid: parse_number(&res.bid)?,
+            volume: parse_number(&res.volume)?,
+            volrate: parse_decimal(&res.volrate)?,
+            uplmtprice: parse_number(&res.uplmtprice)?,
+            high: parse_number(&res.high)?,
+            open: parse_number(&res.open)?,
+            low: parse_number(&res.low)?,
+            dnlmtprice: parse_number(&res.dnlmtprice)?,
+        }))
+    }
+}
+
+pub fn parse_c4801_response2(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Tc4801OutBlock2));
+        Ok(json!(C4801Response2 {
+            dongsi: parse_string(&res.dongsi)?,
+            jeqprice: parse_number(&res.jeqprice)?,
+            jeqsign: parse_sign(&res.jeqsign)?,
+            jeqchange: parse_number(&res.jeqchange)?,
+            jeqchrate: parse_decimal(&res.jeqchrate)?,
+        }))
+    }
+}
+
+#[allow(non_snake_case)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C4801Response {
+    pub expcode: String,           //종목코드
+    pub hname: String,             //한글명
+    pub ename: String,             //영문명
+    pub sname: String,             //단축명
+    pub baseprice: Option<i64>,    //기준가격
+    pub hprice: Option<i64>,       //상한가
+    pub lprice: Option<i64>,       //하한가
+    pub preclose: Option<i64>,     //전일종가
+    pub unit: String,              //거래단위
+    pub openyak: Option<i64>,      //미결제약정수량
+    pub fusign: Option<PriceSign>, //전일대비부호
+    pub fuchange: Option<i64>,     //전일대비
+    pub fucurr: Option<i64>,       //현재가
+    pub fuopen: Option<i64>,       //시가
+    pub fuhigh: Option<i64>,       //고가
+    pub fulow: Option<i64>,        //저가
+    pub fuvolall: Option<i64>,     //누적체결수량(계약)
+    pub fuspvolall: Option<i64>,   //스프레드체결수량
+    pub fuvalall: Option<i64>,     //누적거래대금(천원)
+    pub hotime: String,            //호가시간
+    pub offer: Option<i64>,        //매도우선호가
+    pub bid: Option<i64>,          //매수우선호가
+    pub offerjan: Option<i64>,     //매도잔량
+    pub bidjan: Option<i64>,       //매수잔량
+    pub S2offer: Option<i64>,      //매도2차호가
+    pub S2bid: Option<i64>,        //매수2차호가
+    pub S2offerjan: Option<i64>,   //매도2차잔량
+    pub S2bidjan: Option<i64>,     //매수2차잔량
+    pub S3offer: Option<i64>,      //매도3차호가
+    pub S3bid: Option<i64>,        //매수3차호가
+    pub S3offerjan: Option<i64>,   //매도3차잔량
+    pub S3bidjan: Option<i64>,     //매수3차잔량
+    pub S4offer: Option<i64>,      //매도4차호가
+    pub S4bid: Option<i64>,        //매수4차호가
+    pub S4offerjan: Option<i64>,   //매도4차잔량
+    pub S4bidjan: Option<i64>,     //매수4차잔량
+    pub S5offer: Option<i64>,      //매도5차호가
+    pub S5bid: Option<i64>,        //매수5차호가
+    pub S5offerjan: Option<i64>,   //매도5차잔량
+    pub S5bidjan: Option<i64>,     //매수5차잔량
+    pub S6offer: Option<i64>,      //매도6차호가
+    pub S6bid: Option<i64>,        //매수6차호가
+    pub S6offerjan: Option<i64>,   //매도6차잔량
+    pub S6bidjan: Option<i64>,     //매수6차잔량
+    pub S7offer: Option<i64>,      //매도7차호가
+    pub S7bid: Option<i64>,        //매수7차호가
+    pub S7offerjan: Option<i64>,   //매도7차잔량
+    pub S7bidjan: Option<i64>,     //매수7차잔량
+    pub S8offer: Option<i64>,      //매도8차호가
+    pub S8bid: Option<i64>,        //매수8차호가
+    pub S8offerjan: Option<i64>,   //매도8차잔량
+    pub S8bidjan: Option<i64>,     //매수8차잔량
+    pub S9offer: Option<i64>,      //매도9차호가
+    pub S9bid: Option<i64>,        //매수9차호가
+    pub S9offerjan: Option<i64>,   //매도9차잔량
+    pub S9bidjan: Option<i64>,     //매수9차잔량
+    pub S0offer: Option<i64>,      //매도10차호가
+    pub S0bid: Option<i64>,        //매수10차호가
+    pub S0offerjan: Option<i64>,   //매도10차잔량
+    pub S0bidjan: Option<i64>,     //매수10차잔량
+    pub offersu: Option<i64>,      //매도건수
+    pub bidsu: Option<i64>,        //매수건수
+    pub S2offersu: Option<i64>,    //매도2차건수
+    pub S2bidsu: Option<i64>,      //매수2차건수
+    pub S3offersu: Option<i64>,    //매도3차건수
+    pub S3bidsu: Option<i64>,      //매수3차건수
+    pub S4offersu: Option<i64>,    //매도4차건수
+    pub S4bidsu: Option<i64>,      //매수4차건수
+    pub S5offersu: Option<i64>,    //매도5차건수
+    pub S5bidsu: Option<i64>,      //매수5차건수
+    pub S6offersu: Option<i64>,    //매도6차건수
+    pub S6bidsu: Option<i64>,      //매수6차건수
+    pub S7offersu: Option<i64>,    //매도7차건수
+    pub S7bidsu: Option<i64>,      //매수7차건수
+    pub S8offersu: Option<i64>,    //매도8차건수
+    pub S8bidsu: Option<i64>,      //매수8차건수
+    pub S9offersu: Option<i64>,    //매도9차건수
+    pub S9bidsu: Option<i64>,      //매수9차건수
+    pub S0offersu: Option<i64>,    //매도10차건수
+    pub S0bidsu: Option<i64>,      //매수10차건수
+    pub tofferjan: Option<i64>,    //총매도잔량
+    pub tobidjan: Option<i64>,     //총매수잔량
+    pub toffersu: Option<i64>,     //총매도건수
+    pub tbidsu: Option<i64>,       //총매수건수
+    pub theorytime: String,        //이론가시간
+    pub theoryprice: Option<f64>,  //이론가
+    pub fuchrate: Option<f64>,     //등락률
+    pub fupivot2upz7: Option<i64>, //피봇2차저항
+    pub fupivot1upz7: Option<i64>, //피봇1차저항
+    pub fupivotz7: Option<i64>,    //피봇가
+    pub fupivot1dnz7: Option<i64>, //피봇1차지지
+    pub fupivot2dnz7: Option<i64>, //피봇2차지지
+    pub fubasis: Option<f64>,      //베이시스
+    pub fugrate: Option<f64>,      //괴리도
+    pub fugratio: Option<f64>,     //괴리율
+    pub fupreopenyak: Option<i64>, //미결제약정전일
+    pub fulisthprice: Option<i64>, //상장후최고가
+    pub fulisthdate: String,       //상장후최고일
+    pub fulistlprice: Option<i64>, //상장후최저가
+    pub fulistldate: String,       //상장후최저일
+    pub fulastdate: String,        //최종거래일
+    pub fujandatecnt: Option<i64>, //잔존일
+    pub fucdratio: Option<f64>,    //무위험이자율
+    pub fuopenchange: Option<i64>, //시가대비등락
+    pub fudynhprice: Option<i64>,  //실시간상한가
+    pub fudynlprice: Option<i64>,  //실시간하한가
+    pub fudynpriceflag: String,    //동적가격제한여부
+    pub exlmtgb: String,           //가격확대예정구분
+    pub uplmtgb: String,           //가격제한확대상한단계
+    pub dnlmtgb: String,           //가격제한확대하한단계
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C4801Response1 {
+    pub shcode: String,          //종목코드
+    pub hname: String,           //종목명
+    pub price: Option<i64>,      //현재가
+    pub sign: Option<PriceSign>, //등락부호
+    pub change: Option<i64>,     //등락폭
+    pub market: String,          //락구분
+    pub chrate: Option<f64>,     //등락률
+    pub offer: Option<i64>,      //매도호가
+    pub bid: Option<i64>,        //매수호가
+    pub volume: Option<i64>,     //거래량
+    pub volrate: Option<f64>,    //거래비율
+    pub uplmtprice: Option<i64>, //상한가
+    pub high: Option<i64>,       //고가
+    pub open: Option<i64>,       //시가
+    pub low: Option<i64>,        //저가
+    pub dnlmtprice: Option<i64>, //하한가
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C4801Response2 {
+    pub dongsi: String,             //동시호가구분
+    pub jeqprice: Option<i64>,      //예상체결가
+    pub jeqsign: Option<PriceSign>, //예상체결부호
+    pub jeqchange: Option<i64>,     //예상체결등락폭
+    pub jeqchrate: Option<f64>,     //예상체결등락률
+}
+
+pub const BLOCK_NAME_C4801_OUT: &str = "c4801OutBlock";
+pub const BLOCK_NAME_C4801_OUT1: &str = "c4801OutBlock1";
+pub const BLOCK_NAME_C4801_OUT2: &str = "c4801OutBlock2";
@@ -0,0 +1,317 @@
+use std::ffi::c_char;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::utils::{parse_decimal, parse_number, parse_sign, parse_string, to_fixed_string};
+use crate::{error::*, models::*, QvOpenApiRequest};
+use qvopenapi_bindings::{
+    Tc4805InBlock, Tc4805OutHoga3, Tc4805OutSMaster, Tc4805OutSpread, Tc4805OutUnder,
+};
+
+pub const TR_CODE_C4805: &str = "c4805";
+
+/**
+ * 주식선물 스프레드 현재가 조회
+ */
+#[derive(Debug, Clone, Deserialize)]
+pub struct C4805Request {
+    pub code: String, //종목코드 (스프레드 단축코드 8자리)
+}
+
+impl C4805Request {
+    pub fn new(code: &str) -> C4805Request {
+        C4805Request { code: code.into() }
+    }
+
+    pub fn into_raw(&self) -> Result<Arc<RawQueryRequest<Tc4805InBlock>>, QvOpenApiError> {
+        Ok(Arc::new(RawQueryRequest::new(
+            TR_CODE_C4805,
+            // 시세 조회는 계좌와 무관
+            0,
+            Box::new(Tc4805InBlock {
+                fuitemz9: to_fixed_string(&self.code)?,
+                _fuitemz9: ' ' as c_char,
+            }),
+        )))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C4805Output {
+    #[serde(rename = "c4805OutUnder", default)]
+    pub underlying: Option<C4805ResponseUnder>, //기초자산
+    #[serde(rename = "c4805OutSMaster", default)]
+    pub smaster: Option<C4805ResponseSMaster>, //스프레드 기본정보
+    #[serde(rename = "c4805OutHoga3", default)]
+    pub hoga: Option<C4805ResponseHoga3>, //주식선물호가3
+    #[serde(rename = "c4805OutSpread", default)]
+    pub spread: Option<C4805ResponseSpread>, //선물SPREAD
+}
+
+impl TypedQueryRequest for C4805Request {
+    type Output = C4805Output;
+
+    fn to_query(&self) -> Result<Arc<dyn QvOpenApiRequest>, QvOpenApiError> {
+        Ok(self.into_raw()?)
+    }
+}
+
+pub fn parse_c4805_response_under(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Tc4805OutUnder));
+        Ok(json!(C4805ResponseUnder {
+            shcode: parse_string(&res.shcode)?,
+            hname: parse_string(&res.hname)?,
+            price: parse_number(&res.price)?,
+            sign: parse_sign(&res.sign)?,
+            change: parse_number(&res.change)?,
+        }))
+    }
+}
+
+pub fn parse_c4805_response_smaster(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Tc4805OutSMaster));
+        Ok(json!(C4805ResponseSMaster {
+            fuitemz8: parse_string(&res.fuitemz8)?,
+            fuspcurr: parse_number(&res.fuspcurr)?,
+            fuspsign: parse_sign(&res.fuspsign)?,
+            fuspchange: parse_number(&res.fuspchange)?,
+            fuspchrate: parse_decimal(&res.fuspchrate)?,
+            fuspopen: parse_number(&res.fuspopen)?,
+            fusphigh: parse_number(&res.fusphigh)?,
+            fusplow: parse_number(&res.fusplow)?,
+            fuspvolall: parse_number(&res.fuspvolall)?,
+            fuspvalall: parse_number(&res.fuspvalall)?,
+            fuspcurr1: parse_number(&res.fuspcurr1)?,
+            fuspcurr2: parse_number(&res.fuspcurr2)?,
+            fuitem1: parse_string(&res.fuitem1)?,
+            fuitem2: parse_string(&res.fuitem2)?,
+            fudynhprice: parse_number(&res.fudynhprice)?,
+            fudynlprice: parse_number(&res.fudynlprice)?,
+            fudynpriceflag: parse_string(&res.fudynpriceflag)?,
+        }))
+    }
+}
+
+pub fn parse_c4805_response_hoga3(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Tc4805OutHoga3));
+        Ok(json!(C4805ResponseHoga3 {
+            fuspfuitem: parse_string(&res.fuspfuitem)?,
+            fusphname: parse_string(&res.fusphname)?,
+            fusphotime: parse_string(&res.fusphotime)?,
+            offer: parse_number(&res.offer)?,
+            bid: parse_number(&res.bid)?,
+            offerjan: parse_number(&res.offerjan)?,
+            bidjan: parse_number(&res.bidjan)?,
+            S2offer: parse_number(&res.S2offer)?,
+            S2bid: parse_number(&res.S2bid)?,
+            S2offerjan: parse_number(&res.S2offerjan)?,
+            S2bidjan: parse_number(&res.S2bidjan)?,
+            S3offer: parse_number(&res.S3offer)?,
+            S3bid: parse_number(&res.S3bid)?,
+            S3offerjan: parse_number(&res.S3offerjan)?,
+            S3bidjan: parse_number(&res.S3bidjan)?,
+            S4offer: parse_number(&res.S4offer)?,
+            S4bid: parse_number(&res.S4bid)?,
+            S4offerjan: parse_number(&res.S4offerjan)?,
+            S4bidjan: parse_number(&res.S4bidjan)?,
+            S5offer: parse_number(&res.S5offer)?,
+            S5bid: parse_number(&res.S5bid)?,
+            S5offerjan: parse_number(&res.S5offerjan)?,
+            S5bidjan: parse_number(&res.S5bidjan)?,
+            S6offer: parse_number(&res.S6offer)?,
+            S6bid: parse_number(&res.S6bid)?,
+            S6offerjan: parse_number(&res.S6offerjan)?,
+            S6bidjan: parse_number(&res.S6bidjan)?,
+            S7offer: parse_number(&res.S7offer)?,
+            S7bid: parse_number(&res.S7bid)?,
+            S7offerjan: parse_number(&res.S7offerjan)?,
+            S7bidjan: parse_number(&res.S7bidjan)?,
+            S8offer: parse_number(&res.S8offer)?,
+            S8bid: parse_number(&res.S8bid)?,
+            S8offerjan: parse_number(&res.S8offerjan)?,
+            S8bidjan: parse_number(&res.S8bidjan)?,
+            S9offer: parse_number(&res.S9offer)?,
+            S9bid: parse_number(&res.S9bid)?,
+            S9offerjan: parse_number(&res.S9offerjan)?,
+            S9bidjan: parse_number(&res.S9bidjan)?,
+            S0offer: parse_number(&res.S0offer)?,
+            S0bid: parse_number(&res.S0bid)?,
+            S0offerjan: parse_number(&res.S0offerjan)?,
+            S0bidjan: parse_number(&res.S0bidjan)?,
+            offersu: parse_number(&res.offersu)?,
+            bidsu: parse_number(&res.bidsu)?,
+            S2offersu: parse_number(&res.S2offersu)?,
+            S2bidsu: parse_number(&res.S2bidsu)?,
+            S3offersu: parse_number(&res.S3offersu)?,
+            S3bidsu: parse_number(&res.S3bidsu)?,
+            S4offersu: parse_number(&res.S4offersu)?,
+            S4bidsu: parse_number(&res.S4bidsu)?,
+            S5offersu: parse_number(&res.S5offersu)?,
+            S5bidsu: parse_number(&res.S5bidsu)?,
+            S6offersu: parse_number(&res.S6offersu)?,
+            S6bidsu: parse_number(&res.S6bidsu)?,
+            S7offersu: parse_number(&res.S7offersu)?,
+            S7bidsu: parse_number(&res.S7bidsu)?,
+            S8offersu: parse_number(&res.S8offersu)?,
+            S8bidsu: parse_number(&res.S8bidsu)?,
+            S9offersu: parse_number(&res.S9offersu)?,
+            S9bidsu: parse_number(&res.S9bidsu)?,
+            S0offersu: parse_number(&res.S0offersu)?,
+            S0bidsu: parse_number(&res.S0bidsu)?,
+            tofferjan: parse_number(&res.tofferjan)?,
+            tobidjan: parse_number(&res.tobidjan)?,
+            toffersu: parse_number(&res.toffersu)?,
+            tbidsu: parse_number(&res.tbidsu)?,
+            undershcode: parse_string(&res.undershcode)?,
+            underhname: parse_string(&res.underhname)?,
+            eitem: parse_string(&res.eitem)?,
+            bp_jgubun: parse_string(&res.bp_jgubun)?,
+        }))
+    }
+}
+
+pub fn parse_c4805_response_spread(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Tc4805OutSpread));
+        Ok(json!(C4805ResponseSpread {
+            thspread: parse_number(&res.thspread)?,
+            respread: parse_number(&res.respread)?,
+            fugrate1: parse_number(&res.fugrate1)?,
+        }))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C4805ResponseUnder {
+    pub shcode: String,          //종목코드
+    pub hname: String,           //종목명
+    pub price: Option<i64>,      //현재가
+    pub sign: Option<PriceSign>, //전일비부호
+    pub change: Option<i64>,     //전일비
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C4805ResponseSMaster {
+    pub fuitemz8: String,            //종목코드
+    pub fuspcurr: Option<i64>,       //지수
+    pub fuspsign: Option<PriceSign>, //전일비부호
+    pub fuspchange: Option<i64>,     //전일비
+    pub fuspchrate: Option<f64>,     //등락률
+    pub fuspopen: Option<i64>,       //시가
+    pub fusphigh: Option<i64>,       //고가
+    pub fusplow: Option<i64>,        //저가
+    pub fuspvolall: Option<i64>,     //거래량
+    pub fuspvalall: Option<i64>,     //누적거래대금(천원)
+    pub fuspcurr1: Option<i64>,      //의제약정가(근월물)
+    pub fuspcurr2: Option<i64>,      //의제약정가(원월물)
+    pub fuitem1: String,             //종목코드(근월물)
+    pub fuitem2: String,             //종목코드(원월물)
+    pub fudynhprice: Option<i64>,    //실시간상한가
+    pub fudynlprice: Option<i64>,    //실시간하한가
+    pub fudynpriceflag: String,      //동적가격제한여부
+}
+
+#[allow(non_snake_case)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C4805ResponseHoga3 {
+    pub fuspfuitem: String,      //종목코드
+    pub fusphname: String,       //한글명
+    pub fusphotime: String,      //호가시간
+    pub offer: Option<i64>,      //매도우선호가
+    pub bid: Option<i64>,        //매수우선호가
+    pub offerjan: Option<i64>,   //매도잔량
+    pub bidjan: Option<i64>,     //매수잔량
+    pub S2offer: Option<i64>,    //2차매도호가
+    pub S2bid: Option<i64>,      //2차매수호가
+    pub S2offerjan: Option<i64>, //2차매도잔량
+    pub S2bidjan: Option<i64>,   //2차매수잔량
+    pub S3offer: Option<i64>,    //3차매도호가
+    pub S3bid: Option<i64>,      //3차매수호가
+    pub S3offerjan: Option<i64>, //3차매도잔량
+    pub S3bidjan: Option<i64>,   //3차매수잔량
+    pub S4offer: Option<i64>,    //4차매도호가
+    pub S4bid: Option<i64>,      //4차매수호가
+    pub S4offerjan: Option<i64>, //4차매도잔량
+    pub S4bidjan: Option<i64>,   //4차매수잔량
+    pub S5offer: Option<i64>,    //5차매도호가
+    pub S5bid: Option<i64>,      //5차매수호가
+    pub S5offerjan: Option<i64>, //5차매도잔량
+    pub S5bidjan: Option<i64>,   //5차매수잔량
+    pub S6offer: Option<i64>,    //6차매도호가
+    pub S6bid: Option<i64>,      //6차매수호가
+    pub S6offerjan: Option<i64>, //6차매도잔량
+    pub S6bidjan: Option<i64>,   //6차매수잔량
+    pub S7offer: Option<i64>,    //7차매도호가
+    pub S7bid: Option<i64>,      //7차매수호가
+    pub S7offerjan: Option<i64>, //7차매도잔량
+    pub S7bidjan: Option<i64>,   //7차매수잔량
+    pub S8offer: Option<i64>,    //8차매도호가
+    pub S8bid: Option<i64>,      //8차매수호가
+    pub S8offerjan: Option<i64>, //8차매도잔량
+    pub S8bidjan: Option<i64>,   //8차매수잔량
+    pub S9offer: Option<i64>,    //9차매도호가
+    pub S9bid: Option<i64>,      //9차매수호가
+    pub S9offerjan: Option<i64>, //9차매도잔량
+    pub S9bidjan: Option<i64>,   //9차매수잔량
+    pub S0offer: Option<i64>,    //10차매도호가
+    pub S0bid: Option<i64>,      //10차매수호가
+    pub S0offerjan: Option<i64>, //10차매도잔량
+    pub S0bidjan: Option<i64>,   //10차매수잔량
+    pub offersu: Option<i64>,    //매도건수
+    pub bidsu: Option<i64>,      //매수건수
+    pub S2offersu: Option<i64>,  //2차매도건수
+    pub S2bidsu: Option<i64>,    //2차매수건수
+    pub S3offersu: Option<i64>,  //3차매도건수
+    pub S3bidsu: Option<i64>,    //3차매수건수
+    pub S4offersu: Option<i64>,  //4차매도건수
+    pub S4bidsu: Option<i64>,    //4차매수건수
+    pub S5offersu: Option<i64>,  //5차매도건수
+    pub S5bidsu: Option<i64>,    //5차매수건수
+    pub S6offersu: Option<i64>,  //6차매도건수
+    pub S6bidsu: Option<i64>,    //6차매수건수
+    pub S7offersu: Option<i64>,  //7차매도건수
+    pub S7bidsu: Option<i64>,    //7차매수건수
+    pub S8offersu: Option<i64>,  //8차매도건수
+    pub S8bidsu: Option<i64>,    //8차매수건수
+    pub S9offersu: Option<i64>,  //9차매도건수
+    pub S9bidsu: Option<i64>,    //9차매수건수
+    pub S0offersu: Option<i64>,  //10차매도건수
+    pub S0bidsu: Option<i64>,    //10차매수건수
+    pub tofferjan: Option<i64>,  //총매도잔량
+    pub tobidjan: Option<i64>,   //총매수잔량
+    pub toffersu: Option<i64>,   //총매도건수
+    pub tbidsu: Option<i64>,     //총매수건수
+    pub undershcode: String,     //기초자산종목코드
+    pub underhname: String,      //기초자산종목명
+    pub eitem: String,           //기초대상주식
+    pub bp_jgubun: String,       //BP용장구분
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C4805ResponseSpread {
+    pub thspread: Option<i64>, //이론스프레드
+    pub respread: Option<i64>, //실제스프레드
+    pub fugrate1: Option<i64>, //괴리
+}
+
+pub const BLOCK_NAME_C4805_OUT_UNDER: &str = "c4805OutUnder";
+pub const BLOCK_NAME_C4805_OUT_SMASTER: &str = "c4805OutSMaster";
+pub const BLOCK_NAME_C4805_OUT_HOGA3: &str = "c4805OutHoga3";
+pub const BLOCK_NAME_C4805_OUT_SPREAD: &str = "c4805OutSpread";
@@ -0,0 +1,136 @@
+use std::ffi::c_char;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::utils::{
+    assert_code_in, assert_non_negative, assert_positive, parse_number, to_fixed_number,
+    to_fixed_string,
+};
+use crate::{error::*, models::*, QvOpenApiRequest};
+use qvopenapi_bindings::{Tc8101InBlock, Tc8101OutBlock};
+
+pub const TR_CODE_C8101: &str = "c8101";
+
+// 00:보통가 03:시장가 05:조건부지정가 12:최유리지정가 13:최우선지정가 31:시간외단일가 61:장전시간외 71:장후시간외
+// C0:IOC보통가 F0:FOK보통가 C3:IOC시장가 F3:FOK시장가 C2:IOC최유리 F2:FOK최유리
+pub(crate) const STOCK_TRADE_TYPE_CODES: [&str; 14] = [
+    "00", "03", "05", "12", "13", "31", "61", "71", "C0", "F0", "C3", "F3", "C2", "F2",
+];
+// 주문단가를 지정하는 매매유형. 나머지는 주문단가 0으로 주문
+const STOCK_PRICED_TRADE_TYPE_CODES: [&str; 5] = ["00", "05", "31", "C0", "F0"];
+
+/**
+ * 주식 매도 주문
+ */
+#[derive(Debug, Clone, Deserialize)]
+pub struct C8101Request {
+    pub account_index: i32,
+    pub issue_code: String,    //종목번호
+    pub order_qty: i64,        //주문수량
+    pub order_unit_price: i64, //주문단가
+    pub trade_type: String,    //매매유형
+    pub short_sell_flag: char, //공매도가능여부
+}
+
+impl C8101Request {
+    pub fn new(
+        account_index: i32,
+        issue_code: &str,
+        order_qty: i64,
+        order_unit_price: i64,
+        trade_type: &str,
+        short_sell_flag: char,
+    ) -> C8101Request {
+        C8101Request {
+            account_index,
+            issue_code: issue_code.into(),
+            order_qty,
+            order_unit_price,
+            trade_type: trade_type.into(),
+            short_sell_flag,
+        }
+    }
+
+    pub fn into_raw(&self) -> Result<Arc<RawQueryRequest<Tc8101InBlock>>, QvOpenApiError> {
+        assert_positive("order_qty", self.order_qty)?;
+        assert_stock_order_price(&self.trade_type, self.order_unit_price)?;
+
+        Ok(Arc::new(RawQueryRequest::new(
+            TR_CODE_C8101,
+            self.account_index,
+            Box::new(Tc8101InBlock {
+                pswd_noz8: [' ' as c_char; 44],
+                _pswd_noz8: ' ' as c_char,
+                issue_codez6: to_fixed_string(&self.issue_code)?,
+                _issue_codez6: ' ' as c_char,
+                order_qtyz12: to_fixed_number(self.order_qty)?,
+                _order_qtyz12: ' ' as c_char,
+                order_unit_pricez10: to_fixed_number(self.order_unit_price)?,
+                _order_unit_pricez10: ' ' as c_char,
+                trade_typez2: to_fixed_string(&self.trade_type)?,
+                _trade_typez2: ' ' as c_char,
+                shsll_pos_flagz1: [self.short_sell_flag as c_char],
+                _shsll_pos_flagz1: ' ' as c_char,
+                trad_pswd_no_1z8: [' ' as c_char; 44],
+                _trad_pswd_no_1z8: ' ' as c_char,
+                trad_pswd_no_2z8: [' ' as c_char; 44],
+                _trad_pswd_no_2z8: ' ' as c_char,
+            }),
+        )))
+    }
+}
+
+/**
+ * 매매유형이 허용된 값인지, 주문단가가 매매유형에 맞는지 확인
+ * 지정가 계열은 0보다 커야 하고, 시장가 계열은 0을 허용
+ */
+pub(crate) fn assert_stock_order_price(
+    trade_type: &str,
+    order_unit_price: i64,
+) -> Result<(), QvOpenApiError> {
+    assert_code_in("trade_type", trade_type, &STOCK_TRADE_TYPE_CODES)?;
+    if STOCK_PRICED_TRADE_TYPE_CODES.contains(&trade_type) {
+        assert_positive("order_unit_price", order_unit_price)
+    } else {
+        assert_non_negative("order_unit_price", order_unit_price)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C8101Output {
+    #[serde(rename = "c8101OutBlock")]
+    pub order: C8101Response, //주문 결과
+}
+
+impl TypedQueryRequest for C8101Request {
+    type Output = C8101Output;
+
+    fn to_query(&self) -> Result<Arc<dyn QvOpenApiRequest>, QvOpenApiError> {
+        Ok(self.into_raw()?)
+    }
+}
+
+pub fn parse_c8101_response(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Tc8101OutBlock));
+        Ok(json!(C8101Response {
+            order_noz10: parse_number(&res.order_noz10)?,
+            order_qtyz12: parse_number(&res.order_qtyz12)?,
+            order_unit_pricez10: parse_number(&res.order_unit_pricez10)?,
+        }))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C8101Response {
+    pub order_noz10: Option<i64>,         //주문번호
+    pub order_qtyz12: Option<i64>,        //주문수량
+    pub order_unit_pricez10: Option<i64>, //주문단가
+}
+
+pub const BLOCK_NAME_C8101_OUT: &str = "c8101OutBlock";
@@ -0,0 +1,122 @@
+use std::ffi::c_char;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::c8103::STOCK_ALL_PART_TYPE_CODES;
+use crate::utils::{
+    assert_code, assert_non_negative, assert_positive, parse_number, parse_string, to_fixed_number,
+    to_fixed_string,
+};
+use crate::{error::*, models::*, QvOpenApiRequest};
+use qvopenapi_bindings::{Tc8104InBlock, Tc8104OutBlock};
+
+pub const TR_CODE_C8104: &str = "c8104";
+
+/**
+ * 주식 취소 주문
+ */
+#[derive(Debug, Clone, Deserialize)]
+pub struct C8104Request {
+    pub account_index: i32,
+    pub issue_code: String,  //종목번호
+    pub canc_qty: i64,       //취소수량
+    pub orgnl_order_no: i64, //원주문번호
+    pub all_part_type: char, //취소구분
+}
+
+impl C8104Request {
+    pub fn new(
+        account_index: i32,
+        issue_code: &str,
+        canc_qty: i64,
+        orgnl_order_no: i64,
+        all_part_type: char,
+    ) -> C8104Request {
+        C8104Request {
+            account_index,
+            issue_code: issue_code.into(),
+            canc_qty,
+            orgnl_order_no,
+            all_part_type,
+        }
+    }
+
+    pub fn into_raw(&self) -> Result<Arc<RawQueryRequest<Tc8104InBlock>>, QvOpenApiError> {
+        assert_code(
+            "all_part_type",
+            self.all_part_type,
+            STOCK_ALL_PART_TYPE_CODES,
+        )?;
+        // 잔량 취소는 수량을 0으로 보냄
+        if self.all_part_type == '1' {
+            assert_positive("canc_qty", self.canc_qty)?;
+        } else {
+            assert_non_negative("canc_qty", self.canc_qty)?;
+        }
+        assert_positive("orgnl_order_no", self.orgnl_order_no)?;
+
+        Ok(Arc::new(RawQueryRequest::new(
+            TR_CODE_C8104,
+            self.account_index,
+            Box::new(Tc8104InBlock {
+                pswd_noz8: [' ' as c_char; 44],
+                _pswd_noz8: ' ' as c_char,
+                issue_codez6: to_fixed_string(&self.issue_code)?,
+                _issue_codez6: ' ' as c_char,
+                canc_qtyz12: to_fixed_number(self.canc_qty)?,
+                _canc_qtyz12: ' ' as c_char,
+                orgnl_order_noz10: to_fixed_number(self.orgnl_order_no)?,
+                _orgnl_order_noz10: ' ' as c_char,
+                all_part_typez1: [self.all_part_type as c_char],
+                _all_part_typez1: ' ' as c_char,
+                trad_pswd_no_1z8: [' ' as c_char; 44],
+                _trad_pswd_no_1z8: ' ' as c_char,
+                trad_pswd_no_2z8: [' ' as c_char; 44],
+                _trad_pswd_no_2z8: ' ' as c_char,
+            }),
+        )))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C8104Output {
+    #[serde(rename = "c8104OutBlock")]
+    pub order: C8104Response, //주문 결과
+}
+
+impl TypedQueryRequest for C8104Request {
+    type Output = C8104Output;
+
+    fn to_query(&self) -> Result<Arc<dyn QvOpenApiRequest>, QvOpenApiError> {
+        Ok(self.into_raw()?)
+    }
+}
+
+pub fn parse_c8104_response(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Tc8104OutBlock));
+        Ok(json!(C8104Response {
+            orgnl_order_noz10: parse_number(&res.orgnl_order_noz10)?,
+            order_noz10: parse_number(&res.order_noz10)?,
+            mom_order_noz10: parse_number(&res.mom_order_noz10)?,
+            issue_codez6: parse_string(&res.issue_codez6)?,
+            canc_qtyz12: parse_number(&res.canc_qtyz12)?,
+        }))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C8104Response {
+    pub orgnl_order_noz10: Option<i64>, //원주문번호
+    pub order_noz10: Option<i64>,       //주문번호
+    pub mom_order_noz10: Option<i64>,   //모주문번호
+    pub issue_codez6: String,           //후종목번호
+    pub canc_qtyz12: Option<i64>,       //취소수량
+}
+
+pub const BLOCK_NAME_C8104_OUT: &str = "c8104OutBlock";
@@ -0,0 +1,110 @@
+use std::ffi::c_char;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::c8101::assert_stock_order_price;
+use crate::utils::{assert_positive, parse_number, to_fixed_number, to_fixed_string};
+use crate::{error::*, models::*, QvOpenApiRequest};
+use qvopenapi_bindings::{Tc8142InBlock, Tc8142OutBlock};
+
+pub const TR_CODE_C8142: &str = "c8142";
+
+/**
+ * 신용 매수 주문
+ */
+#[derive(Debug, Clone, Deserialize)]
+pub struct C8142Request {
+    pub account_index: i32,
+    pub issue_code: String,    //종목번호
+    pub order_qty: i64,        //주문수량
+    pub order_unit_price: i64, //주문단가
+    pub trade_type: String,    //매매유형
+    pub order_cond: char,      //주문조건
+}
+
+impl C8142Request {
+    pub fn new(
+        account_index: i32,
+        issue_code: &str,
+        order_qty: i64,
+        order_unit_price: i64,
+        trade_type: &str,
+        order_cond: char,
+    ) -> C8142Request {
+        C8142Request {
+            account_index,
+            issue_code: issue_code.into(),
+            order_qty,
+            order_unit_price,
+            trade_type: trade_type.into(),
+            order_cond,
+        }
+    }
+
+    pub fn into_raw(&self) -> Result<Arc<RawQueryRequest<Tc8142InBlock>>, QvOpenApiError> {
+        assert_positive("order_qty", self.order_qty)?;
+        assert_stock_order_price(&self.trade_type, self.order_unit_price)?;
+
+        Ok(Arc::new(RawQueryRequest::new(
+            TR_CODE_C8142,
+            self.account_index,
+            Box::new(Tc8142InBlock {
+                password_noz8: [' ' as c_char; 44],
+                _password_noz8: ' ' as c_char,
+                issue_codez12: to_fixed_string(&self.issue_code)?,
+                _issue_codez12: ' ' as c_char,
+                order_qtyz12: to_fixed_number(self.order_qty)?,
+                _order_qtyz12: ' ' as c_char,
+                order_unit_pricez10: to_fixed_number(self.order_unit_price)?,
+                _order_unit_pricez10: ' ' as c_char,
+                trade_typez2: to_fixed_string(&self.trade_type)?,
+                _trade_typez2: ' ' as c_char,
+                order_condz1: [self.order_cond as c_char],
+                _order_condz1: ' ' as c_char,
+                trad_pswd_no_1z8: [' ' as c_char; 44],
+                _trad_pswd_no_1z8: ' ' as c_char,
+                trad_pswd_no_2z8: [' ' as c_char; 44],
+                _trad_pswd_no_2z8: ' ' as c_char,
+            }),
+        )))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C8142Output {
+    #[serde(rename = "c8142OutBlock")]
+    pub order: C8142Response, //주문 결과
+}
+
+impl TypedQueryRequest for C8142Request {
+    type Output = C8142Output;
+
+    fn to_query(&self) -> Result<Arc<dyn QvOpenApiRequest>, QvOpenApiError> {
+        Ok(self.into_raw()?)
+    }
+}
+
+pub fn parse_c8142_response(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Tc8142OutBlock));
+        Ok(json!(C8142Response {
+            order_noz10: parse_number(&res.order_noz10)?,
+            order_qtyz12: parse_number(&res.order_qtyz12)?,
+            order_unit_pricez10: parse_number(&res.order_unit_pricez10)?,
+        }))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C8142Response {
+    pub order_noz10: Option<i64>,         //주문번호
+    pub order_qtyz12: Option<i64>,        //주문수량
+    pub order_unit_pricez10: Option<i64>, //주문단가
+}
+
+pub const BLOCK_NAME_C8142_OUT: &str = "c8142OutBlock";
@@ -0,0 +1,143 @@
+use std::ffi::c_char;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::utils::{
+    assert_code, assert_non_negative, assert_positive, parse_number, parse_string, to_fixed_number,
+    to_fixed_string,
+};
+use crate::{error::*, models::*, QvOpenApiRequest};
+use qvopenapi_bindings::{Tc8143InBlock, Tc8143OutBlock};
+
+pub const TR_CODE_C8143: &str = "c8143";
+
+// 1:일부정정 3:잔량정정 7:일부취소 9:잔량취소
+const CREDIT_ALL_PART_TYPE_CODES: &str = "1379";
+
+/**
+ * 신용 정정 주문
+ */
+#[derive(Debug, Clone, Deserialize)]
+pub struct C8143Request {
+    pub account_index: i32,
+    pub issue_code: String,  //종목번호
+    pub crctn_qty: i64,      //정정수량
+    pub crctn_price: i64,    //정정단가
+    pub orgnl_order_no: i64, //원주문번호
+    pub all_part_type: char, //정정구분
+    pub order_cond: char,    //주문조건
+}
+
+impl C8143Request {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        account_index: i32,
+        issue_code: &str,
+        crctn_qty: i64,
+        crctn_price: i64,
+        orgnl_order_no: i64,
+        all_part_type: char,
+        order_cond: char,
+    ) -> C8143Request {
+        C8143Request {
+            account_index,
+            issue_code: issue_code.into(),
+            crctn_qty,
+            crctn_price,
+            orgnl_order_no,
+            all_part_type,
+            order_cond,
+        }
+    }
+
+    pub fn into_raw(&self) -> Result<Arc<RawQueryRequest<Tc8143InBlock>>, QvOpenApiError> {
+        assert_code(
+            "all_part_type",
+            self.all_part_type,
+            CREDIT_ALL_PART_TYPE_CODES,
+        )?;
+        // 잔량 정정/취소는 수량을 0으로 보냄
+        if matches!(self.all_part_type, '1' | '7') {
+            assert_positive("crctn_qty", self.crctn_qty)?;
+        } else {
+            assert_non_negative("crctn_qty", self.crctn_qty)?;
+        }
+        // 취소는 단가를 쓰지 않으므로 0 허용
+        if matches!(self.all_part_type, '1' | '3') {
+            assert_positive("crctn_price", self.crctn_price)?;
+        } else {
+            assert_non_negative("crctn_price", self.crctn_price)?;
+        }
+        assert_positive("orgnl_order_no", self.orgnl_order_no)?;
+
+        Ok(Arc::new(RawQueryRequest::new(
+            TR_CODE_C8143,
+            self.account_index,
+            Box::new(Tc8143InBlock {
+                pswd_noz8: [' ' as c_char; 44],
+                _pswd_noz8: ' ' as c_char,
+                issue_codez12: to_fixed_string(&self.issue_code)?,
+                _issue_codez12: ' ' as c_char,
+                crctn_qtyz12: to_fixed_number(self.crctn_qty)?,
+                _crctn_qtyz12: ' ' as c_char,
+                crctn_pricez10: to_fixed_number(self.crctn_price)?,
+                _crctn_pricez10: ' ' as c_char,
+                orgnl_order_noz10: to_fixed_number(self.orgnl_order_no)?,
+                _orgnl_order_noz10: ' ' as c_char,
+                all_part_typez1: [self.all_part_type as c_char],
+                _all_part_typez1: ' ' as c_char,
+                order_condz1: [self.order_cond as c_char],
+                _order_condz1: ' ' as c_char,
+                trad_pswd_no_1z8: [' ' as c_char; 44],
+                _trad_pswd_no_1z8: ' ' as c_char,
+                trad_pswd_no_2z8: [' ' as c_char; 44],
+                _trad_pswd_no_2z8: ' ' as c_char,
+            }),
+        )))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C8143Output {
+    #[serde(rename = "c8143OutBlock")]
+    pub order: C8143Response, //주문 결과
+}
+
+impl TypedQueryRequest for C8143Request {
+    type Output = C8143Output;
+
+    fn to_query(&self) -> Result<Arc<dyn QvOpenApiRequest>, QvOpenApiError> {
+        Ok(self.into_raw()?)
+    }
+}
+
+pub fn parse_c8143_response(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Tc8143OutBlock));
+        Ok(json!(C8143Response {
+            orgnl_order_noz10: parse_number(&res.orgnl_order_noz10)?,
+            order_noz10: parse_number(&res.order_noz10)?,
+            mom_order_noz10: parse_number(&res.mom_order_noz10)?,
+            af_issue_codez12: parse_string(&res.af_issue_codez12)?,
+            crctn_qtyz12: parse_number(&res.crctn_qtyz12)?,
+            crctn_pricez10: parse_number(&res.crctn_pricez10)?,
+        }))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C8143Response {
+    pub orgnl_order_noz10: Option<i64>, //원주문번호
+    pub order_noz10: Option<i64>,       //주문번호
+    pub mom_order_noz10: Option<i64>,   //모주문번호
+    pub af_issue_codez12: String,       //후종목번호
+    pub crctn_qtyz12: Option<i64>,      //정정수량
+    pub crctn_pricez10: Option<i64>,    //정정단가
+}
+
+pub const BLOCK_NAME_C8143_OUT: &str = "c8143OutBlock";
@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 
 use crate::utils::{parse_number, parse_ratio, parse_ratio_str, parse_string};
-use crate::{error::*, models::*};
+use crate::{error::*, models::*, QvOpenApiRequest};
 use qvopenapi_bindings::{Tc8201InBlock, Tc8201OutBlock, Tc8201OutBlock1};
 
 pub const TR_CODE_C8201: &str = "c8201";
@@ -39,6 +39,22 @@ impl C8201Request {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C8201Output {
+    #[serde(rename = "c8201OutBlock")]
+    pub summary: C8201Response, //계좌 합계
+    #[serde(rename = "c8201OutBlock1", default)]
+    pub holdings: Vec<C8201Response1>, //종목별 잔고
+}
+
+impl TypedQueryRequest for C8201Request {
+    type Output = C8201Output;
+
+    fn to_query(&self) -> Result<Arc<dyn QvOpenApiRequest>, QvOpenApiError> {
+        Ok(self.into_raw())
+    }
+}
+
 pub fn parse_c8201_response(
     block_data: *const c_char,
     _block_len: i32,
@@ -117,8 +133,8 @@ fn parse_c8201_response1(res: &Tc8201OutBlock1) -> Result<C8201Response1, QvOpen
     })
 }
 
-#[derive(Debug, Clone, Serialize)]
-struct C8201Response {
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C8201Response {
     pub dpsit_amtz16: Option<i64>,       //예수금
     pub mrgn_amtz16: Option<i64>,        //신용융자금
     pub mgint_npaid_amtz16: Option<i64>, //이자미납금
@@ -151,8 +167,8 @@ struct C8201Response {
     pub pft_rtz15: Option<f64>,          //수익율
 }
 
-#[derive(Debug, Clone, Serialize)]
-struct C8201Response1 {
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C8201Response1 {
     pub issue_codez6: String,            //종목번호
     pub issue_namez40: String,           //종목명
     pub bal_typez6: String,              //잔고유형
@@ -0,0 +1,182 @@
+use std::ffi::c_char;
+use std::mem::size_of;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::utils::{assert_code, parse_decimal, parse_number, parse_string};
+use crate::{error::*, models::*, QvOpenApiRequest};
+use qvopenapi_bindings::{Tc8311InBlock, Tc8311OutBlock, Tc8311OutBlock1};
+
+pub const TR_CODE_C8311: &str = "c8311";
+
+// 1:지수선물 2:지수옵션 3:주식옵션 4:지수선/옵 5:스타지수 6:주식선물 8:주식선옵 9:전체 O:지수선옵/위클리
+const FO_BALANCE_JOB_GUBUN_CODES: &str = "12345689O";
+
+/**
+ * 선물옵션 잔고조회
+ */
+#[derive(Debug, Clone, Deserialize)]
+pub struct C8311Request {
+    pub account_index: i32,
+    pub job_gubun: char, //구분 (9:전체)
+}
+
+impl C8311Request {
+    pub fn new(account_index: i32, job_gubun: char) -> C8311Request {
+        C8311Request {
+            account_index,
+            job_gubun,
+        }
+    }
+
+    pub fn into_raw(&self) -> Result<Arc<RawQueryRequest<Tc8311InBlock>>, QvOpenApiError> {
+        assert_code("job_gubun", self.job_gubun, FO_BALANCE_JOB_GUBUN_CODES)?;
+
+        Ok(Arc::new(RawQueryRequest::new(
+            TR_CODE_C8311,
+            self.account_index,
+            Box::new(Tc8311InBlock {
+                in_pswdz8: [' ' as c_char; 44],
+                _in_pswdz8: ' ' as c_char,
+                job_gubunz1: [self.job_gubun as c_char],
+                _job_gubunz1: ' ' as c_char,
+                trobj_stock_codez12: [' ' as c_char; 12],
+                _trobj_stock_codez12: ' ' as c_char,
+            }),
+        )))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C8311Output {
+    #[serde(rename = "c8311OutBlock")]
+    pub summary: C8311Response, //계좌 합계
+    #[serde(rename = "c8311OutBlock1", default)]
+    pub positions: Vec<C8311Response1>, //종목별 잔고
+}
+
+impl TypedQueryRequest for C8311Request {
+    type Output = C8311Output;
+
+    fn to_query(&self) -> Result<Arc<dyn QvOpenApiRequest>, QvOpenApiError> {
+        Ok(self.into_raw()?)
+    }
+}
+
+pub fn parse_c8311_response(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Tc8311OutBlock));
+        Ok(json!(C8311Response {
+            out_acnamez30: parse_string(&res.out_acnamez30)?,
+            out_ttamtz14: parse_number(&res.out_ttamtz14)?,
+            out_cashz14: parse_number(&res.out_cashz14)?,
+            out_mrgn_ttamtz14: parse_number(&res.out_mrgn_ttamtz14)?,
+            out_mrgn_cashz14: parse_number(&res.out_mrgn_cashz14)?,
+            out_order_ttamtz14: parse_number(&res.out_order_ttamtz14)?,
+            out_order_cashz14: parse_number(&res.out_order_cashz14)?,
+            out_tot_ttamtz14: parse_number(&res.out_tot_ttamtz14)?,
+            out_tot_cashz14: parse_number(&res.out_tot_cashz14)?,
+            out_substz14: parse_number(&res.out_substz14)?,
+            out_renewz14: parse_number(&res.out_renewz14)?,
+            out_stlmtz14: parse_number(&res.out_stlmtz14)?,
+            out_opt_sellz14: parse_number(&res.out_opt_sellz14)?,
+            out_opt_buyz14: parse_number(&res.out_opt_buyz14)?,
+            out_opt_valuez14: parse_number(&res.out_opt_valuez14)?,
+            out_pred_substz14: parse_number(&res.out_pred_substz14)?,
+            out_thday_substz14: parse_number(&res.out_thday_substz14)?,
+            out_pred_amtz14: parse_number(&res.out_pred_amtz14)?,
+            out_opt_hangz14: parse_number(&res.out_opt_hangz14)?,
+            out_opt_baiz14: parse_number(&res.out_opt_baiz14)?,
+            out_thday_amtz14: parse_number(&res.out_thday_amtz14)?,
+            out_rcblez14: parse_number(&res.out_rcblez14)?,
+            out_ovamtz14: parse_number(&res.out_ovamtz14)?,
+            out_ftr_cmsnz14: parse_number(&res.out_ftr_cmsnz14)?,
+            out_opt_cmsnz14: parse_number(&res.out_opt_cmsnz14)?,
+            out_afterz14: parse_number(&res.out_afterz14)?,
+            out_asset_ttamtz14: parse_number(&res.out_asset_ttamtz14)?,
+            out_asset_ttcashz14: parse_number(&res.out_asset_ttcashz14)?,
+        }))
+    }
+}
+
+pub fn parse_c8311_response1_array(
+    block_data: *const c_char,
+    block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let block_count = block_len as usize / size_of::<Tc8311OutBlock1>();
+        let res: &[Tc8311OutBlock1] =
+            core::slice::from_raw_parts(block_data as *const Tc8311OutBlock1, block_count);
+
+        let ret: Result<Vec<C8311Response1>, QvOpenApiError> =
+            res.iter().map(parse_c8311_response1).collect();
+        Ok(json!(ret?))
+    }
+}
+
+fn parse_c8311_response1(res: &Tc8311OutBlock1) -> Result<C8311Response1, QvOpenApiError> {
+    Ok(C8311Response1 {
+        out_issuez9: parse_string(&res.out_issuez9)?,
+        out_isnamez30: parse_string(&res.out_isnamez30)?,
+        out_slbyz6: parse_string(&res.out_slbyz6)?,
+        out_qtyz14: parse_number(&res.out_qtyz14)?,
+        out_averz12: parse_decimal(&res.out_averz12)?,
+        out_pricez12: parse_decimal(&res.out_pricez12)?,
+        out_lsnpfz14: parse_number(&res.out_lsnpfz14)?,
+        today_revs_odqtyz12: parse_number(&res.today_revs_odqtyz12)?,
+        sell_posbl_qtyz12: parse_number(&res.sell_posbl_qtyz12)?,
+    })
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C8311Response {
+    pub out_acnamez30: String,            //계좌명
+    pub out_ttamtz14: Option<i64>,        //예탁총액
+    pub out_cashz14: Option<i64>,         //예탁현금
+    pub out_mrgn_ttamtz14: Option<i64>,   //증거금총액
+    pub out_mrgn_cashz14: Option<i64>,    //증거금현금
+    pub out_order_ttamtz14: Option<i64>,  //주문가능총액
+    pub out_order_cashz14: Option<i64>,   //주문가능현금
+    pub out_tot_ttamtz14: Option<i64>,    //총평가총액
+    pub out_tot_cashz14: Option<i64>,     //총평가현금
+    pub out_substz14: Option<i64>,        //대용금액
+    pub out_renewz14: Option<i64>,        //정산차금
+    pub out_stlmtz14: Option<i64>,        //최종차금
+    pub out_opt_sellz14: Option<i64>,     //옵션매도대금
+    pub out_opt_buyz14: Option<i64>,      //옵션매수대금
+    pub out_opt_valuez14: Option<i64>,    //옵션평가금액
+    pub out_pred_substz14: Option<i64>,   //전일대용매도
+    pub out_thday_substz14: Option<i64>,  //당일대용매도
+    pub out_pred_amtz14: Option<i64>,     //전일가입금
+    pub out_opt_hangz14: Option<i64>,     //옵션행사금액
+    pub out_opt_baiz14: Option<i64>,      //옵션배정금액
+    pub out_thday_amtz14: Option<i64>,    //당일가입금
+    pub out_rcblez14: Option<i64>,        //미수금
+    pub out_ovamtz14: Option<i64>,        //연체료
+    pub out_ftr_cmsnz14: Option<i64>,     //선물수수료
+    pub out_opt_cmsnz14: Option<i64>,     //옵션수수료
+    pub out_afterz14: Option<i64>,        //세후이용료
+    pub out_asset_ttamtz14: Option<i64>,  //순자산총액
+    pub out_asset_ttcashz14: Option<i64>, //순자산현금
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C8311Response1 {
+    pub out_issuez9: String,              //종목코드
+    pub out_isnamez30: String,            //종목명
+    pub out_slbyz6: String,               //매매구분
+    pub out_qtyz14: Option<i64>,          //잔고수량
+    pub out_averz12: Option<f64>,         //평균가
+    pub out_pricez12: Option<f64>,        //현재가
+    pub out_lsnpfz14: Option<i64>,        //평가손익
+    pub today_revs_odqtyz12: Option<i64>, //주문수량
+    pub sell_posbl_qtyz12: Option<i64>,   //청산가능수량
+}
+
+pub const BLOCK_NAME_C8311_OUT: &str = "c8311OutBlock";
+pub const BLOCK_NAME_C8311_OUT1_ARRAY: &str = "c8311OutBlock1";
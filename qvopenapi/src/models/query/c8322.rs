@@ -0,0 +1,211 @@
+use std::ffi::c_char;
+use std::mem::size_of;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::utils::{
+    assert_code, from_cp949, parse_decimal, parse_number, parse_string, to_fixed_string,
+};
+use crate::{error::*, models::*, QvOpenApiRequest};
+use qvopenapi_bindings::{Tc8322InBlock, Tc8322OutBlock, Tc8322OutBlock1, Tc8322OutBlock_IN};
+
+pub const TR_CODE_C8322: &str = "c8322";
+
+/**
+ * 선물옵션 주문체결조회
+ * 다음 페이지는 c8322OutBlock_IN의 CTS를 넣어 with_next()로 다시 조회
+ */
+#[derive(Debug, Clone, Deserialize)]
+pub struct C8322Request {
+    pub account_index: i32,
+    pub order_date: String, //주문일자 (YYYYMMDD)
+    pub issue_code: String, //종목번호
+    pub conc_gubun: char,   //체결구분 (0:전체 1:미체결 2:체결)
+    pub sort_gubun: char,   //정렬구분 (0:주문번호순 1:주문번호역순)
+    pub sl_buy_type: char,  //매수도구분 (0:전체 1:매도 2:매수)
+    pub issue_gubun: char,  //종목구분 (0:전체 1:선물 2:옵션 O:지수선옵/위클리)
+    pub disp_gubun: char,   //조회구분 (1:주문시간순 2:체결시간순)
+    pub cts: String,        //CTS (연속조회 키)
+    pub is_page_up: bool,   //ISPAGEUP (다음 페이지 조회)
+}
+
+impl C8322Request {
+    pub fn new(account_index: i32, order_date: &str) -> C8322Request {
+        C8322Request {
+            account_index,
+            order_date: order_date.into(),
+            issue_code: "".into(),
+            conc_gubun: '0',
+            sort_gubun: '0',
+            sl_buy_type: '0',
+            issue_gubun: '0',
+            disp_gubun: '1',
+            cts: "".into(),
+            is_page_up: false,
+        }
+    }
+
+    pub fn with_next(&self, cts: &str) -> C8322Request {
+        C8322Request {
+            cts: cts.into(),
+            is_page_up: true,
+            ..self.clone()
+        }
+    }
+
+    pub fn into_raw(&self) -> Result<Arc<RawQueryRequest<Tc8322InBlock>>, QvOpenApiError> {
+        assert_code("conc_gubun", self.conc_gubun, "012")?;
+        assert_code("sort_gubun", self.sort_gubun, "01")?;
+        assert_code("sl_buy_type", self.sl_buy_type, "012")?;
+        assert_code("issue_gubun", self.issue_gubun, "012O")?;
+        assert_code("disp_gubun", self.disp_gubun, "12")?;
+
+        Ok(Arc::new(RawQueryRequest::new(
+            TR_CODE_C8322,
+            self.account_index,
+            Box::new(Tc8322InBlock {
+                pswd_noz8: [' ' as c_char; 44],
+                _pswd_noz8: ' ' as c_char,
+                order_datez8: to_fixed_string(&self.order_date)?,
+                _order_datez8: ' ' as c_char,
+                issue_codez9: to_fixed_string(&self.issue_code)?,
+                _issue_codez9: ' ' as c_char,
+                conc_gubunz1: [self.conc_gubun as c_char],
+                _conc_gubunz1: ' ' as c_char,
+                sort_gubunz1: [self.sort_gubun as c_char],
+                _sort_gubunz1: ' ' as c_char,
+                sl_buy_typez1: [self.sl_buy_type as c_char],
+                _sl_buy_typez1: ' ' as c_char,
+                issue_gubunz1: [self.issue_gubun as c_char],
+                _issue_gubunz1: ' ' as c_char,
+                disp_gubunz1: [self.disp_gubun as c_char],
+                _disp_gubunz1: ' ' as c_char,
+                cts_areaz67: to_fixed_string(&self.cts)?,
+                _cts_areaz67: ' ' as c_char,
+                trad_pswd1z8: [' ' as c_char; 44],
+                _trad_pswd1z8: ' ' as c_char,
+                trad_pswd2z8: [' ' as c_char; 44],
+                _trad_pswd2z8: ' ' as c_char,
+                IsPageUp: [if self.is_page_up { NEXT_PAGE_FLAG } else { ' ' } as c_char],
+                _IsPageUp: ' ' as c_char,
+            }),
+        )))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C8322Output {
+    #[serde(rename = "c8322OutBlock")]
+    pub summary: C8322Response, //화면출력
+    #[serde(rename = "c8322OutBlock1", default)]
+    pub fills: Vec<C8322Response1>, //주문체결 내역
+    #[serde(rename = "c8322OutBlock_IN", default)]
+    pub next: Option<C8322ResponseIn>, //다음 페이지 정보
+}
+
+impl TypedQueryRequest for C8322Request {
+    type Output = C8322Output;
+
+    fn to_query(&self) -> Result<Arc<dyn QvOpenApiRequest>, QvOpenApiError> {
+        Ok(self.into_raw()?)
+    }
+}
+
+pub fn parse_c8322_response(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Tc8322OutBlock));
+        Ok(json!(C8322Response {
+            order_datez8: parse_string(&res.order_datez8)?,
+            accnt_namez40: parse_string(&res.accnt_namez40)?,
+        }))
+    }
+}
+
+pub fn parse_c8322_response1_array(
+    block_data: *const c_char,
+    block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let block_count = block_len as usize / size_of::<Tc8322OutBlock1>();
+        let res: &[Tc8322OutBlock1] =
+            core::slice::from_raw_parts(block_data as *const Tc8322OutBlock1, block_count);
+
+        let ret: Result<Vec<C8322Response1>, QvOpenApiError> =
+            res.iter().map(parse_c8322_response1).collect();
+        Ok(json!(ret?))
+    }
+}
+
+fn parse_c8322_response1(res: &Tc8322OutBlock1) -> Result<C8322Response1, QvOpenApiError> {
+    Ok(C8322Response1 {
+        order_noz10: parse_number(&res.order_noz10)?,
+        orgnl_order_noz10: parse_number(&res.orgnl_order_noz10)?,
+        conc_noz6: parse_number(&res.conc_noz6)?,
+        order_datez8: parse_string(&res.order_datez8)?,
+        slbuy_typez10: parse_string(&res.slbuy_typez10)?,
+        slby_typez15: parse_string(&res.slby_typez15)?,
+        issue_codez9: parse_string(&res.issue_codez9)?,
+        issue_namez40: parse_string(&res.issue_namez40)?,
+        order_qtyz12: parse_number(&res.order_qtyz12)?,
+        order_pricez12: parse_decimal(&res.order_pricez12)?,
+        conc_qtyz12: parse_number(&res.conc_qtyz12)?,
+        index_conc_pricez12: parse_decimal(&res.index_conc_pricez12)?,
+        unconc_qtyz12: parse_number(&res.unconc_qtyz12)?,
+        rqst_typez6: parse_string(&res.rqst_typez6)?,
+        rqst_timez8: parse_string(&res.rqst_timez8)?,
+    })
+}
+
+pub fn parse_c8322_response_in(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Tc8322OutBlock_IN));
+        Ok(json!(C8322ResponseIn {
+            // CTS는 그대로 다시 입력해야 하므로 trim하지 않음
+            cts_areaz67: from_cp949(&res.cts_areaz67),
+            nextbutton: parse_string(&res.nextbutton)?,
+        }))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C8322Response {
+    pub order_datez8: String,  //주문일자
+    pub accnt_namez40: String, //계좌명
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C8322Response1 {
+    pub order_noz10: Option<i64>,         //주문번호
+    pub orgnl_order_noz10: Option<i64>,   //원주문번호
+    pub conc_noz6: Option<i64>,           //체결번호
+    pub order_datez8: String,             //주문일자
+    pub slbuy_typez10: String,            //주문구분
+    pub slby_typez15: String,             //매매구분
+    pub issue_codez9: String,             //종목번호
+    pub issue_namez40: String,            //종목명
+    pub order_qtyz12: Option<i64>,        //주문수량
+    pub order_pricez12: Option<f64>,      //주문단가
+    pub conc_qtyz12: Option<i64>,         //체결수량
+    pub index_conc_pricez12: Option<f64>, //체결평균단가
+    pub unconc_qtyz12: Option<i64>,       //미체결수량
+    pub rqst_typez6: String,              //처리구분
+    pub rqst_timez8: String,              //처리시간
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct C8322ResponseIn {
+    pub cts_areaz67: String, //CTS
+    pub nextbutton: String,  //NEXTBUTTON
+}
+
+pub const BLOCK_NAME_C8322_OUT: &str = "c8322OutBlock";
+pub const BLOCK_NAME_C8322_OUT1_ARRAY: &str = "c8322OutBlock1";
+pub const BLOCK_NAME_C8322_OUT_IN: &str = "c8322OutBlock_IN";
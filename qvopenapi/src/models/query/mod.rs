@@ -1,13 +1,70 @@
+mod c1101;
+mod c1151;
+mod c4113;
+mod c4801;
+mod c4805;
+mod c8101;
+mod c8102;
+mod c8103;
+mod c8104;
+mod c8141;
+mod c8142;
+mod c8143;
 mod c8201;
+mod c8311;
+mod c8322;
+mod orderable;
+mod p1003;
+mod p8101;
+mod p8104;
+mod p8105;
+mod p8301;
+mod p8302;
+mod s1701;
+mod s4101;
+mod s4201;
+mod s8120;
+mod s8301;
+mod s8302;
+pub use c1101::*;
+pub use c1151::*;
+pub use c4113::*;
+pub use c4801::*;
+pub use c4805::*;
+pub use c8101::*;
+pub use c8102::*;
+pub use c8103::*;
+pub use c8104::*;
+pub use c8141::*;
+pub use c8142::*;
+pub use c8143::*;
 pub use c8201::*;
+pub use c8311::*;
+pub use c8322::*;
+pub use orderable::*;
+pub use p1003::*;
+pub use p8101::*;
+pub use p8104::*;
+pub use p8105::*;
+pub use p8301::*;
+pub use p8302::*;
 use qvopenapi_bindings::OutDataBlock;
-use serde::Serialize;
+pub use s1701::*;
+pub use s4101::*;
+pub use s4201::*;
+pub use s8120::*;
+pub use s8301::*;
+pub use s8302::*;
+use serde::{de::DeserializeOwned, Serialize};
 
 use serde_json::Value;
-use std::ffi::c_char;
+use std::{ffi::c_char, sync::Arc};
 
 use crate::{client::QvOpenApiRequest, error::*, utils::from_cp949_ptr, wmca_lib};
 
+// 연속 조회 TR(s8120, c8322)의 ISPAGEUP / NEXTBUTTON 값. 다음 페이지가 없으면 공백
+pub const NEXT_PAGE_FLAG: char = 'N';
+
 pub fn parse_data(lparam: isize) -> std::result::Result<DataResponse, QvOpenApiError> {
     let data_block = lparam as *const OutDataBlock<c_char>;
     unsafe {
@@ -26,32 +83,83 @@ pub fn parse_data(lparam: isize) -> std::result::Result<DataResponse, QvOpenApiE
     }
 }
 
-pub fn parse_sise(lparam: isize) -> std::result::Result<DataResponse, QvOpenApiError> {
-    let data_block = lparam as *const OutDataBlock<c_char>;
-    unsafe {
-        let tr_index = (*data_block).tr_index;
-        let data = (*data_block).p_data;
-        let block_name = from_cp949_ptr((*data).block_name);
-        let block_data = (*data).sz_data.offset(3); //앞쪽 3바이트는 패킷유형과 압축구분이므로 skip
-        let block_len = (*data).len;
-
-        Ok(DataResponse {
-            tr_index,
-            block_data: parse_block(block_name.as_str(), block_data, block_len)?,
-            block_name,
-            block_len,
-        })
-    }
-}
-
 fn parse_block(
     block_name: &str,
     block_data: *const c_char,
     block_len: i32,
 ) -> Result<Value, QvOpenApiError> {
     match block_name {
+        BLOCK_NAME_C1101_OUT => parse_c1101_response(block_data, block_len),
+        BLOCK_NAME_C1101_OUT2_ARRAY => parse_c1101_response2_array(block_data, block_len),
+        BLOCK_NAME_C1101_OUT3 => parse_c1101_response3(block_data, block_len),
+        BLOCK_NAME_C1151_OUT => parse_c1151_response(block_data, block_len),
+        BLOCK_NAME_C1151_OUT2_ARRAY => parse_c1151_response2_array(block_data, block_len),
+        BLOCK_NAME_C1151_OUT3 => parse_c1151_response3(block_data, block_len),
+        BLOCK_NAME_C1151_OUT4 => parse_c1151_response4(block_data, block_len),
+        BLOCK_NAME_C1151_OUT5 => parse_c1151_response5(block_data, block_len),
+        BLOCK_NAME_C4113_OUT_KOSPI200 => parse_c4113_response_kospi200(block_data, block_len),
+        BLOCK_NAME_C4113_OUT_SMASTER => parse_c4113_response_smaster(block_data, block_len),
+        BLOCK_NAME_C4113_OUT1 => parse_c4113_response1(block_data, block_len),
+        BLOCK_NAME_C4113_OUT2 => parse_c4113_response2(block_data, block_len),
+        BLOCK_NAME_C4113_OUT_HOGA1 => parse_c4113_response_hoga1(block_data, block_len),
+        BLOCK_NAME_C4113_OUT_HOGA2 => parse_c4113_response_hoga2(block_data, block_len),
+        BLOCK_NAME_C4113_OUT_HOGA3 => parse_c4113_response_hoga3(block_data, block_len),
+        BLOCK_NAME_C4113_OUT_FUTEQ1 => parse_c4113_response_futeq1(block_data, block_len),
+        BLOCK_NAME_C4113_OUT_FUTEQ2 => parse_c4113_response_futeq2(block_data, block_len),
+        BLOCK_NAME_C4801_OUT => parse_c4801_response(block_data, block_len),
+        BLOCK_NAME_C4801_OUT1 => parse_c4801_response1(block_data, block_len),
+        BLOCK_NAME_C4801_OUT2 => parse_c4801_response2(block_data, block_len),
+        BLOCK_NAME_C4805_OUT_UNDER => parse_c4805_response_under(block_data, block_len),
+        BLOCK_NAME_C4805_OUT_SMASTER => parse_c4805_response_smaster(block_data, block_len),
+        BLOCK_NAME_C4805_OUT_HOGA3 => parse_c4805_response_hoga3(block_data, block_len),
+        BLOCK_NAME_C4805_OUT_SPREAD => parse_c4805_response_spread(block_data, block_len),
+        BLOCK_NAME_C8101_OUT => parse_c8101_response(block_data, block_len),
+        BLOCK_NAME_C8102_OUT => parse_c8102_response(block_data, block_len),
+        BLOCK_NAME_C8103_OUT => parse_c8103_response(block_data, block_len),
+        BLOCK_NAME_C8104_OUT => parse_c8104_response(block_data, block_len),
+        BLOCK_NAME_C8141_OUT => parse_c8141_response(block_data, block_len),
+        BLOCK_NAME_C8142_OUT => parse_c8142_response(block_data, block_len),
+        BLOCK_NAME_C8143_OUT => parse_c8143_response(block_data, block_len),
         BLOCK_NAME_C8201_OUT => parse_c8201_response(block_data, block_len),
         BLOCK_NAME_C8201_OUT1_ARRAY => parse_c8201_response1_array(block_data, block_len),
+        BLOCK_NAME_C8311_OUT => parse_c8311_response(block_data, block_len),
+        BLOCK_NAME_C8311_OUT1_ARRAY => parse_c8311_response1_array(block_data, block_len),
+        BLOCK_NAME_C8322_OUT => parse_c8322_response(block_data, block_len),
+        BLOCK_NAME_C8322_OUT1_ARRAY => parse_c8322_response1_array(block_data, block_len),
+        BLOCK_NAME_C8322_OUT_IN => parse_c8322_response_in(block_data, block_len),
+        BLOCK_NAME_P1003_OUT_ARRAY => parse_p1003_response_array(block_data, block_len),
+        BLOCK_NAME_P8101_OUT => parse_p8101_response(block_data, block_len),
+        BLOCK_NAME_P8101_OUT1_ARRAY => parse_p8101_response1_array(block_data, block_len),
+        BLOCK_NAME_P8104_OUT => parse_p8104_response(block_data, block_len),
+        BLOCK_NAME_P8105_OUT => parse_p8105_response(block_data, block_len),
+        BLOCK_NAME_P8301_OUT => parse_p8301_response(block_data, block_len),
+        BLOCK_NAME_P8302_OUT => parse_p8302_response(block_data, block_len),
+        BLOCK_NAME_P8302_OUT1_ARRAY => parse_p8302_response1_array(block_data, block_len),
+        BLOCK_NAME_S1701_OUT => parse_s1701_response(block_data, block_len),
+        BLOCK_NAME_S1701_OUT1_ARRAY => parse_s1701_response1_array(block_data, block_len),
+        BLOCK_NAME_S1701_OUT2 => parse_s1701_response2(block_data, block_len),
+        BLOCK_NAME_S1701_OUT3_ARRAY => parse_s1701_response3_array(block_data, block_len),
+        BLOCK_NAME_S1701_OUT4 => parse_s1701_response4(block_data, block_len),
+        BLOCK_NAME_S1701_OUT5 => parse_s1701_response5(block_data, block_len),
+        BLOCK_NAME_S4101_OUT => parse_s4101_response(block_data, block_len),
+        BLOCK_NAME_S4101_OUT1 => parse_s4101_response1(block_data, block_len),
+        BLOCK_NAME_S4101_OUT2_ARRAY => parse_s4101_response2_array(block_data, block_len),
+        BLOCK_NAME_S4101_OUT3_ARRAY => parse_s4101_response3_array(block_data, block_len),
+        BLOCK_NAME_S4101_OUT4_ARRAY => parse_s4101_response4_array(block_data, block_len),
+        BLOCK_NAME_S4101_OUT5_ARRAY => parse_s4101_response5_array(block_data, block_len),
+        BLOCK_NAME_S4101_OUT6 => parse_s4101_response6(block_data, block_len),
+        BLOCK_NAME_S4201_OUT => parse_s4201_response(block_data, block_len),
+        BLOCK_NAME_S4201_OUT1 => parse_s4201_response1(block_data, block_len),
+        BLOCK_NAME_S4201_OUT2_ARRAY => parse_s4201_response2_array(block_data, block_len),
+        BLOCK_NAME_S4201_OUT3 => parse_s4201_response3(block_data, block_len),
+        BLOCK_NAME_S4201_OUT4_ARRAY => parse_s4201_response4_array(block_data, block_len),
+        BLOCK_NAME_S4201_OUT5 => parse_s4201_response5(block_data, block_len),
+        BLOCK_NAME_S4201_OUT6 => parse_s4201_response6(block_data, block_len),
+        BLOCK_NAME_S8120_OUT => parse_s8120_response(block_data, block_len),
+        BLOCK_NAME_S8120_OUT1_ARRAY => parse_s8120_response1_array(block_data, block_len),
+        BLOCK_NAME_S8120_OUT_IN => parse_s8120_response_in(block_data, block_len),
+        BLOCK_NAME_S8301_OUT => parse_s8301_response(block_data, block_len),
+        BLOCK_NAME_S8302_OUT => parse_s8302_response(block_data, block_len),
         _ => Err(QvOpenApiError::UnimplementedBlockError {
             block_name: block_name.into(),
         }),
@@ -94,6 +202,51 @@ impl<T> RawQueryRequest<T> {
     }
 }
 
+/**
+ * 응답 타입이 정해진 TR 요청
+ * Output은 TR 결과의 "result"(블록 이름 -> 블록)를 그대로 역직렬화한 것
+ */
+pub trait TypedQueryRequest {
+    type Output: DeserializeOwned;
+
+    fn to_query(&self) -> Result<Arc<dyn QvOpenApiRequest>, QvOpenApiError>;
+}
+
+/**
+ * {result, messages, error_type, errors} 형태의 TR 결과를 Output으로 변환
+ * 조회가 거부되어 필수 블록이 없으면 마지막 메시지를 QvApiMessageError로 반환
+ */
+pub fn parse_typed_output<R: DeserializeOwned>(mut output: Value) -> Result<R, QvOpenApiError> {
+    if !output["error_type"].is_null() {
+        return Err(serde_json::from_value(output["error_type"].take())?);
+    }
+
+    match serde_json::from_value(output["result"].take()) {
+        Ok(result) => Ok(result),
+        Err(err) => {
+            if let Some(message) = output["messages"]
+                .as_array()
+                .and_then(|messages| messages.last())
+            {
+                return Err(QvOpenApiError::QvApiMessageError {
+                    message_code: message["msg_code"].as_str().unwrap_or("").into(),
+                    message: message["msg"].as_str().unwrap_or("").into(),
+                });
+            }
+            if let Some(error) = output["errors"]
+                .as_array()
+                .and_then(|errors| errors.first())
+            {
+                return Err(QvOpenApiError::QvApiMessageError {
+                    message_code: "".into(),
+                    message: error["error_msg"].as_str().unwrap_or("").into(),
+                });
+            }
+            Err(err.into())
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct DataResponse {
     pub tr_index: i32,
@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{error::*, models::*, QvOpenApiRequest};
+
+/**
+ * 주문 전 가능수량 조회
+ * StockBuy: 주식 매수가능수량 (p8105)
+ * StockSell: 주식 매도가능수량 (p8104)
+ * FuturesOptions: 선물옵션 주문가능수량 (p8301)
+ */
+#[derive(Debug, Clone, Deserialize)]
+pub enum MaxOrderableRequest {
+    StockBuy(P8105Request),
+    StockSell(P8104Request),
+    FuturesOptions(P8301Request),
+}
+
+impl MaxOrderableRequest {
+    pub fn into_raw(&self) -> Result<Arc<dyn QvOpenApiRequest>, QvOpenApiError> {
+        Ok(match self {
+            MaxOrderableRequest::StockBuy(req) => req.into_raw()?,
+            MaxOrderableRequest::StockSell(req) => req.into_raw()?,
+            MaxOrderableRequest::FuturesOptions(req) => req.into_raw()?,
+        })
+    }
+}
+
+/**
+ * MaxOrderableRequest에 맞는 TR의 출력
+ */
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum MaxOrderableOutput {
+    StockBuy(P8105Output),
+    StockSell(P8104Output),
+    FuturesOptions(P8301Output),
+}
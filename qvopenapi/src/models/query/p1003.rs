@@ -0,0 +1,90 @@
+use std::ffi::c_char;
+use std::mem::size_of;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::utils::{assert_code, parse_string};
+use crate::{error::*, models::*, QvOpenApiRequest};
+use qvopenapi_bindings::{Tp1003InBlock, Tp1003OutBlock};
+
+pub const TR_CODE_P1003: &str = "p1003";
+
+// f:KRX선물 o:KRX옵션 u:내부선물 p:내부옵션 z:위클리옵션
+const FO_CODE_LIST_GUBUN_CODES: &str = "foupz";
+
+/**
+ * 선물옵션 코드 조회
+ */
+#[derive(Debug, Clone, Deserialize)]
+pub struct P1003Request {
+    pub formlang: char, //한영구분 (k:한글, 기타:영문)
+    pub gubun: char,    //선옵구분
+}
+
+impl P1003Request {
+    pub fn new(formlang: char, gubun: char) -> P1003Request {
+        P1003Request { formlang, gubun }
+    }
+
+    pub fn into_raw(&self) -> Result<Arc<RawQueryRequest<Tp1003InBlock>>, QvOpenApiError> {
+        assert_code("gubun", self.gubun, FO_CODE_LIST_GUBUN_CODES)?;
+
+        Ok(Arc::new(RawQueryRequest::new(
+            TR_CODE_P1003,
+            // 시세 조회는 계좌와 무관
+            0,
+            Box::new(Tp1003InBlock {
+                formlang: [self.formlang as c_char],
+                _formlang: ' ' as c_char,
+                gubun: [self.gubun as c_char],
+                _gubun: ' ' as c_char,
+            }),
+        )))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct P1003Output {
+    #[serde(rename = "p1003OutBlock", default)]
+    pub codes: Vec<P1003Response>, //종목코드 목록
+}
+
+impl TypedQueryRequest for P1003Request {
+    type Output = P1003Output;
+
+    fn to_query(&self) -> Result<Arc<dyn QvOpenApiRequest>, QvOpenApiError> {
+        Ok(self.into_raw()?)
+    }
+}
+
+pub fn parse_p1003_response_array(
+    block_data: *const c_char,
+    block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let block_count = block_len as usize / size_of::<Tp1003OutBlock>();
+        let res: &[Tp1003OutBlock] =
+            core::slice::from_raw_parts(block_data as *const Tp1003OutBlock, block_count);
+
+        let ret: Result<Vec<P1003Response>, QvOpenApiError> =
+            res.iter().map(parse_p1003_response).collect();
+        Ok(json!(ret?))
+    }
+}
+
+fn parse_p1003_response(res: &Tp1003OutBlock) -> Result<P1003Response, QvOpenApiError> {
+    Ok(P1003Response {
+        codez8: parse_string(&res.codez8)?,
+        namez30: parse_string(&res.namez30)?,
+    })
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct P1003Response {
+    pub codez8: String,  //종목코드
+    pub namez30: String, //종목명
+}
+
+pub const BLOCK_NAME_P1003_OUT_ARRAY: &str = "p1003OutBlock";
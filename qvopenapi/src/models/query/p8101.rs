@@ -0,0 +1,135 @@
+use std::ffi::c_char;
+use std::mem::size_of;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::utils::{assert_code, parse_number, parse_string};
+use crate::{error::*, models::*, QvOpenApiRequest};
+use qvopenapi_bindings::{Tp8101InBlock, Tp8101OutBlock, Tp8101OutBlock1};
+
+pub const TR_CODE_P8101: &str = "p8101";
+
+// 1:현금 2:융자 3:채권 4:대주 5:대출주식 6:융자주식합계 7:대출주식합계 8:융자주식 및 대출주식
+// 9:융자주식합계+대출주식합계 A:전체
+const STOCK_SELLABLE_GUBUN_CODES: &str = "123456789A";
+
+/**
+ * 주식 계좌명 및 잔고별 매도가능수량
+ */
+#[derive(Debug, Clone, Deserialize)]
+pub struct P8101Request {
+    pub account_index: i32,
+    pub gubun: char, //구분 (A:전체)
+}
+
+impl P8101Request {
+    pub fn new(account_index: i32, gubun: char) -> P8101Request {
+        P8101Request {
+            account_index,
+            gubun,
+        }
+    }
+
+    pub fn into_raw(&self) -> Result<Arc<RawQueryRequest<Tp8101InBlock>>, QvOpenApiError> {
+        assert_code("gubun", self.gubun, STOCK_SELLABLE_GUBUN_CODES)?;
+
+        Ok(Arc::new(RawQueryRequest::new(
+            TR_CODE_P8101,
+            self.account_index,
+            Box::new(Tp8101InBlock {
+                pswd_noz8: [' ' as c_char; 44],
+                _pswd_noz8: ' ' as c_char,
+                gubunz1: [self.gubun as c_char],
+                _gubunz1: ' ' as c_char,
+            }),
+        )))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct P8101Output {
+    #[serde(rename = "p8101OutBlock")]
+    pub account: P8101Response, //계좌명
+    #[serde(rename = "p8101OutBlock1", default)]
+    pub balances: Vec<P8101Response1>, //잔고별 매도가능수량
+}
+
+impl TypedQueryRequest for P8101Request {
+    type Output = P8101Output;
+
+    fn to_query(&self) -> Result<Arc<dyn QvOpenApiRequest>, QvOpenApiError> {
+        Ok(self.into_raw()?)
+    }
+}
+
+pub fn parse_p8101_response(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Tp8101OutBlock));
+        Ok(json!(P8101Response {
+            accnt_namez30: parse_string(&res.accnt_namez30)?,
+        }))
+    }
+}
+
+pub fn parse_p8101_response1_array(
+    block_data: *const c_char,
+    block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let block_count = block_len as usize / size_of::<Tp8101OutBlock1>();
+        let res: &[Tp8101OutBlock1] =
+            core::slice::from_raw_parts(block_data as *const Tp8101OutBlock1, block_count);
+
+        let ret: Result<Vec<P8101Response1>, QvOpenApiError> =
+            res.iter().map(parse_p8101_response1).collect();
+        Ok(json!(ret?))
+    }
+}
+
+fn parse_p8101_response1(res: &Tp8101OutBlock1) -> Result<P8101Response1, QvOpenApiError> {
+    Ok(P8101Response1 {
+        gubunz1: parse_string(&res.gubunz1)?,
+        gubun_namez6: parse_string(&res.gubun_namez6)?,
+        issue_codez12: parse_string(&res.issue_codez12)?,
+        issue_namez30: parse_string(&res.issue_namez30)?,
+        mrgn_typez10: parse_string(&res.mrgn_typez10)?,
+        lend_datez10: parse_string(&res.lend_datez10)?,
+        taxtn_typez10: parse_string(&res.taxtn_typez10)?,
+        bal_qtyz12: parse_number(&res.bal_qtyz12)?,
+        sell_rcble_qtyz12: parse_number(&res.sell_rcble_qtyz12)?,
+        buy_rcble_qtyz12: parse_number(&res.buy_rcble_qtyz12)?,
+        sell_psqtyz12: parse_number(&res.sell_psqtyz12)?,
+        today_sell_rcble_qz12: parse_number(&res.today_sell_rcble_qz12)?,
+        avrg_purch_uprc: parse_number(&res.avrg_purch_uprc)?,
+    })
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct P8101Response {
+    pub accnt_namez30: String, //계좌명
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct P8101Response1 {
+    pub gubunz1: String,                    //구분 (1:현금 2:융자 3:채권 4:대주)
+    pub gubun_namez6: String,               //구분명
+    pub issue_codez12: String,              //종목코드
+    pub issue_namez30: String,              //종목명
+    pub mrgn_typez10: String,               //신용구분
+    pub lend_datez10: String,               //대출일자
+    pub taxtn_typez10: String,              //과세유형
+    pub bal_qtyz12: Option<i64>,            //잔고수량
+    pub sell_rcble_qtyz12: Option<i64>,     //매도미결제
+    pub buy_rcble_qtyz12: Option<i64>,      //매수미결제
+    pub sell_psqtyz12: Option<i64>,         //매도가능수량
+    pub today_sell_rcble_qz12: Option<i64>, //당일매도미체결수량
+    pub avrg_purch_uprc: Option<i64>,       //매입단가
+}
+
+pub const BLOCK_NAME_P8101_OUT: &str = "p8101OutBlock";
+pub const BLOCK_NAME_P8101_OUT1_ARRAY: &str = "p8101OutBlock1";
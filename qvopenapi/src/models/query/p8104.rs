@@ -0,0 +1,87 @@
+use std::ffi::c_char;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::utils::{assert_code, parse_number, parse_string, to_fixed_string};
+use crate::{error::*, models::*, QvOpenApiRequest};
+use qvopenapi_bindings::{Tp8104InBlock, Tp8104OutBlock};
+
+pub const TR_CODE_P8104: &str = "p8104";
+
+/**
+ * 개별주식 매도가능수량 (현금주식)
+ */
+#[derive(Debug, Clone, Deserialize)]
+pub struct P8104Request {
+    pub account_index: i32,
+    pub issue_code: String, //종목코드
+    pub gubun: char,        //구분 (1:현금 2:신용(유통융자))
+    pub loan_date: String,  //대출일 (YYYYMMDD, 신용일 때만)
+}
+
+impl P8104Request {
+    pub fn new(account_index: i32, issue_code: &str, gubun: char, loan_date: &str) -> P8104Request {
+        P8104Request {
+            account_index,
+            issue_code: issue_code.into(),
+            gubun,
+            loan_date: loan_date.into(),
+        }
+    }
+
+    pub fn into_raw(&self) -> Result<Arc<RawQueryRequest<Tp8104InBlock>>, QvOpenApiError> {
+        assert_code("gubun", self.gubun, "12")?;
+
+        Ok(Arc::new(RawQueryRequest::new(
+            TR_CODE_P8104,
+            self.account_index,
+            Box::new(Tp8104InBlock {
+                pswd_noz8: [' ' as c_char; 44],
+                _pswd_noz8: ' ' as c_char,
+                issue_codez6: to_fixed_string(&self.issue_code)?,
+                _issue_codez6: ' ' as c_char,
+                gubunz1: [self.gubun as c_char],
+                _gubunz1: ' ' as c_char,
+                the_datez8: to_fixed_string(&self.loan_date)?,
+                _the_datez8: ' ' as c_char,
+            }),
+        )))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct P8104Output {
+    #[serde(rename = "p8104OutBlock")]
+    pub orderable: P8104Response, //가능수량
+}
+
+impl TypedQueryRequest for P8104Request {
+    type Output = P8104Output;
+
+    fn to_query(&self) -> Result<Arc<dyn QvOpenApiRequest>, QvOpenApiError> {
+        Ok(self.into_raw()?)
+    }
+}
+
+pub fn parse_p8104_response(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Tp8104OutBlock));
+        Ok(json!(P8104Response {
+            issue_codez6: parse_string(&res.issue_codez6)?,
+            order_qtyz12: parse_number(&res.order_qtyz12)?,
+        }))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct P8104Response {
+    pub issue_codez6: String,      //종목코드
+    pub order_qtyz12: Option<i64>, //매도가능수량
+}
+
+pub const BLOCK_NAME_P8104_OUT: &str = "p8104OutBlock";
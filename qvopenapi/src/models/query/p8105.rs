@@ -0,0 +1,161 @@
+use std::ffi::c_char;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::utils::{assert_code, parse_number, to_fixed_number, to_fixed_string};
+use crate::{error::*, models::*, QvOpenApiRequest};
+use qvopenapi_bindings::{Tp8105InBlock, Tp8105OutBlock};
+
+pub const TR_CODE_P8105: &str = "p8105";
+
+// 01:보통 05:시장가 06:조건부지정가 12:최유리지정가 13:최우선지정가 61:장전시간외 71:시간외종가 81:시간외단일가
+const PRICE_TYPE_CODES: [&str; 8] = ["01", "05", "06", "12", "13", "61", "71", "81"];
+
+/**
+ * 주식 매수가능수량
+ */
+#[derive(Debug, Clone, Deserialize)]
+pub struct P8105Request {
+    pub account_index: i32,
+    pub ost_dit_cd: char,       //구분코드 (1:현금 2:신용 3:대출)
+    pub sby_dit_cd: char,       //매매구분코드 (1:매도상환 2:매수신규)
+    pub iem_gb: char,           //종목구분 (1:주식 2:ELW 3:신주인수권 4:기타)
+    pub issue_code: String,     //종목코드
+    pub price_type: String,     //호가유형코드
+    pub order_price: i64,       //주문가격
+    pub credit_loan_cd: String, //신용대출코드 (01:유통융자 02:자기융자 03:유통대주 04:자기대주)
+    pub loan_date: String,      //대출일자 (YYYYMMDD)
+}
+
+impl P8105Request {
+    pub fn new(
+        account_index: i32,
+        issue_code: &str,
+        price_type: &str,
+        order_price: i64,
+    ) -> P8105Request {
+        P8105Request {
+            account_index,
+            ost_dit_cd: '1',
+            sby_dit_cd: '2',
+            iem_gb: '1',
+            issue_code: issue_code.into(),
+            price_type: price_type.into(),
+            order_price,
+            credit_loan_cd: "".into(),
+            loan_date: "".into(),
+        }
+    }
+
+    pub fn into_raw(&self) -> Result<Arc<RawQueryRequest<Tp8105InBlock>>, QvOpenApiError> {
+        assert_code("ost_dit_cd", self.ost_dit_cd, "123")?;
+        assert_code("sby_dit_cd", self.sby_dit_cd, "12")?;
+        assert_code("iem_gb", self.iem_gb, "1234")?;
+        if !PRICE_TYPE_CODES.contains(&self.price_type.as_str()) {
+            return Err(QvOpenApiError::BadRequestError {
+                message: format!(
+                    "Invalid price_type [{}], expected one of {:?}",
+                    self.price_type, PRICE_TYPE_CODES
+                ),
+            });
+        }
+
+        Ok(Arc::new(RawQueryRequest::new(
+            TR_CODE_P8105,
+            self.account_index,
+            Box::new(Tp8105InBlock {
+                pwdz8: [' ' as c_char; 44],
+                _pwdz8: ' ' as c_char,
+                ost_dit_cdz1: [self.ost_dit_cd as c_char],
+                _ost_dit_cdz1: ' ' as c_char,
+                sby_dit_cdz1: [self.sby_dit_cd as c_char],
+                _sby_dit_cdz1: ' ' as c_char,
+                iem_gbz1: [self.iem_gb as c_char],
+                _iem_gbz1: ' ' as c_char,
+                iem_cdz12: to_fixed_string(&self.issue_code)?,
+                _iem_cdz12: ' ' as c_char,
+                // 2:신시스템 호가구분 (2자리)
+                nmn_pr_tp_gbz1: ['2' as c_char],
+                _nmn_pr_tp_gbz1: ' ' as c_char,
+                nmn_pr_tp_cdz2: to_fixed_string(&self.price_type)?,
+                _nmn_pr_tp_cdz2: ' ' as c_char,
+                orr_prz18: to_fixed_number(self.order_price)?,
+                _orr_prz18: ' ' as c_char,
+                // 2:HTS,인터넷
+                mdi_tp_cdz1: ['2' as c_char],
+                _mdi_tp_cdz1: ' ' as c_char,
+                cfd_lon_cdz2: to_fixed_string(&self.credit_loan_cd)?,
+                _cfd_lon_cdz2: ' ' as c_char,
+                lon_dtz8: to_fixed_string(&self.loan_date)?,
+                _lon_dtz8: ' ' as c_char,
+            }),
+        )))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct P8105Output {
+    #[serde(rename = "p8105OutBlock")]
+    pub orderable: P8105Response, //가능수량
+}
+
+impl TypedQueryRequest for P8105Request {
+    type Output = P8105Output;
+
+    fn to_query(&self) -> Result<Arc<dyn QvOpenApiRequest>, QvOpenApiError> {
+        Ok(self.into_raw()?)
+    }
+}
+
+pub fn parse_p8105_response(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Tp8105OutBlock));
+        Ok(json!(P8105Response {
+            dcaz18: parse_number(&res.dcaz18)?,
+            nxt_dd_dcaz18: parse_number(&res.nxt_dd_dcaz18)?,
+            nxt2_dd_dcaz18: parse_number(&res.nxt2_dd_dcaz18)?,
+            max_pbl_amtz18: parse_number(&res.max_pbl_amtz18)?,
+            max_pbl_qtyz18: parse_number(&res.max_pbl_qtyz18)?,
+            rvb_orn_max_pbl_feez18: parse_number(&res.rvb_orn_max_pbl_feez18)?,
+            csh_orr_pbl_amtz18: parse_number(&res.csh_orr_pbl_amtz18)?,
+            csh_orr_pbl_qtyz18: parse_number(&res.csh_orr_pbl_qtyz18)?,
+            ost_fee1z18: parse_number(&res.ost_fee1z18)?,
+            cfd_rvb_orr_pbl_amtz18: parse_number(&res.cfd_rvb_orr_pbl_amtz18)?,
+            cfd_rvb_orr_pbl_qtyz18: parse_number(&res.cfd_rvb_orr_pbl_qtyz18)?,
+            cfd_max_pbl_feez18: parse_number(&res.cfd_max_pbl_feez18)?,
+            cfd_orr_pbl_amtz18: parse_number(&res.cfd_orr_pbl_amtz18)?,
+            cfd_orr_pbl_qtyz18: parse_number(&res.cfd_orr_pbl_qtyz18)?,
+            ost_fee2z18: parse_number(&res.ost_fee2z18)?,
+            sdr_xps1z18: parse_number(&res.sdr_xps1z18)?,
+            sdr_xpsz18: parse_number(&res.sdr_xpsz18)?,
+        }))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct P8105Response {
+    pub dcaz18: Option<i64>,                 //예수금
+    pub nxt_dd_dcaz18: Option<i64>,          //익일예수금
+    pub nxt2_dd_dcaz18: Option<i64>,         //익익일예수금
+    pub max_pbl_amtz18: Option<i64>,         //최대가능금액 (미수가능금액)
+    pub max_pbl_qtyz18: Option<i64>,         //최대가능수량 (미수가능수량)
+    pub rvb_orn_max_pbl_feez18: Option<i64>, //미수발생최대가능수수료
+    pub csh_orr_pbl_amtz18: Option<i64>,     //현금주문가능금액
+    pub csh_orr_pbl_qtyz18: Option<i64>,     //현금주문가능수량
+    pub ost_fee1z18: Option<i64>,            //현금수수료
+    pub cfd_rvb_orr_pbl_amtz18: Option<i64>, //신용미수주문가능금액
+    pub cfd_rvb_orr_pbl_qtyz18: Option<i64>, //신용미수주문가능수량
+    pub cfd_max_pbl_feez18: Option<i64>,     //신용최대가능수수료
+    pub cfd_orr_pbl_amtz18: Option<i64>,     //신용주문가능금액
+    pub cfd_orr_pbl_qtyz18: Option<i64>,     //신용주문가능수량
+    pub ost_fee2z18: Option<i64>,            //수수료2
+    pub sdr_xps1z18: Option<i64>,            //제비용1
+    pub sdr_xpsz18: Option<i64>,             //제비용
+}
+
+pub const BLOCK_NAME_P8105_OUT: &str = "p8105OutBlock";
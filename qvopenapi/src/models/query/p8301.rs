@@ -0,0 +1,129 @@
+use std::ffi::c_char;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::s8301::{FO_ORD_TYPE_CODES, FO_SLBUY_TYPE_CODES};
+use crate::utils::{assert_code, parse_number, parse_string, to_fixed_decimal, to_fixed_string};
+use crate::{error::*, models::*, QvOpenApiRequest};
+use qvopenapi_bindings::{Tp8301InBlock, Tp8301OutBlock};
+
+pub const TR_CODE_P8301: &str = "p8301";
+
+/**
+ * 선물옵션 주문가능수량조회
+ */
+#[derive(Debug, Clone, Deserialize)]
+pub struct P8301Request {
+    pub account_index: i32,
+    pub issue_code: String, //종목코드
+    pub slbuy_type: char,   //매매구분 (1:매도 2:매수)
+    pub ord_type: char,     //주문유형
+    pub order_price: f64,   //주문단가
+}
+
+impl P8301Request {
+    pub fn new(
+        account_index: i32,
+        issue_code: &str,
+        slbuy_type: char,
+        ord_type: char,
+        order_price: f64,
+    ) -> P8301Request {
+        P8301Request {
+            account_index,
+            issue_code: issue_code.into(),
+            slbuy_type,
+            ord_type,
+            order_price,
+        }
+    }
+
+    pub fn into_raw(&self) -> Result<Arc<RawQueryRequest<Tp8301InBlock>>, QvOpenApiError> {
+        assert_code("slbuy_type", self.slbuy_type, FO_SLBUY_TYPE_CODES)?;
+        assert_code("ord_type", self.ord_type, FO_ORD_TYPE_CODES)?;
+
+        Ok(Arc::new(RawQueryRequest::new(
+            TR_CODE_P8301,
+            self.account_index,
+            Box::new(Tp8301InBlock {
+                issue_codez9: to_fixed_string(&self.issue_code)?,
+                _issue_codez9: ' ' as c_char,
+                slbuy_typez1: [self.slbuy_type as c_char],
+                _slbuy_typez1: ' ' as c_char,
+                ord_typez1: [self.ord_type as c_char],
+                _ord_typez1: ' ' as c_char,
+                order_pricez12: to_fixed_decimal(self.order_price, 2)?,
+                _order_pricez12: ' ' as c_char,
+                passwd_noz8: [' ' as c_char; 44],
+                _passwd_noz8: ' ' as c_char,
+            }),
+        )))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct P8301Output {
+    #[serde(rename = "p8301OutBlock")]
+    pub orderable: P8301Response, //가능수량
+}
+
+impl TypedQueryRequest for P8301Request {
+    type Output = P8301Output;
+
+    fn to_query(&self) -> Result<Arc<dyn QvOpenApiRequest>, QvOpenApiError> {
+        Ok(self.into_raw()?)
+    }
+}
+
+pub fn parse_p8301_response(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Tp8301OutBlock));
+        Ok(json!(P8301Response {
+            accnt_namez20: parse_string(&res.accnt_namez20)?,
+            kor_issue_namez40: parse_string(&res.kor_issue_namez40)?,
+            be_dpsit_ttamtz14: parse_number(&res.be_dpsit_ttamtz14)?,
+            be_dpsit_cash_amtz14: parse_number(&res.be_dpsit_cash_amtz14)?,
+            be_brkrg_mrgn_ttamz14: parse_number(&res.be_brkrg_mrgn_ttamz14)?,
+            be_brkrg_cash_mgamz14: parse_number(&res.be_brkrg_cash_mgamz14)?,
+            be_order_pos_ttamtz14: parse_number(&res.be_order_pos_ttamtz14)?,
+            be_order_pos_csamtz14: parse_number(&res.be_order_pos_csamtz14)?,
+            af_dpsit_ttamtz14: parse_number(&res.af_dpsit_ttamtz14)?,
+            af_dpsit_cash_amtz14: parse_number(&res.af_dpsit_cash_amtz14)?,
+            af_brkrg_mrgn_ttamz14: parse_number(&res.af_brkrg_mrgn_ttamz14)?,
+            af_brkrg_cash_mgamz14: parse_number(&res.af_brkrg_cash_mgamz14)?,
+            af_order_pos_ttamtz14: parse_number(&res.af_order_pos_ttamtz14)?,
+            af_order_pos_csamtz14: parse_number(&res.af_order_pos_csamtz14)?,
+            new_pos_qtyz14: parse_number(&res.new_pos_qtyz14)?,
+            bal_pos_qtyz14: parse_number(&res.bal_pos_qtyz14)?,
+            tot_pos_qtyz14: parse_number(&res.tot_pos_qtyz14)?,
+        }))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct P8301Response {
+    pub accnt_namez20: String,              //계좌명
+    pub kor_issue_namez40: String,          //종목명
+    pub be_dpsit_ttamtz14: Option<i64>,     //주문전 예탁총액
+    pub be_dpsit_cash_amtz14: Option<i64>,  //주문전 예탁현금
+    pub be_brkrg_mrgn_ttamz14: Option<i64>, //주문전 위탁증거금총액
+    pub be_brkrg_cash_mgamz14: Option<i64>, //주문전 위탁증거금현금
+    pub be_order_pos_ttamtz14: Option<i64>, //주문전 주문가능금액총액
+    pub be_order_pos_csamtz14: Option<i64>, //주문전 주문가능금액현금
+    pub af_dpsit_ttamtz14: Option<i64>,     //주문후 예탁총액
+    pub af_dpsit_cash_amtz14: Option<i64>,  //주문후 예탁현금
+    pub af_brkrg_mrgn_ttamz14: Option<i64>, //주문후 위탁증거금총액
+    pub af_brkrg_cash_mgamz14: Option<i64>, //주문후 위탁증거금현금
+    pub af_order_pos_ttamtz14: Option<i64>, //주문후 주문가능금액총액
+    pub af_order_pos_csamtz14: Option<i64>, //주문후 주문가능금액현금
+    pub new_pos_qtyz14: Option<i64>,        //신규주문 신규수량
+    pub bal_pos_qtyz14: Option<i64>,        //잔고주문 청산가능수량
+    pub tot_pos_qtyz14: Option<i64>,        //TOTAL 전체주문수량
+}
+
+pub const BLOCK_NAME_P8301_OUT: &str = "p8301OutBlock";
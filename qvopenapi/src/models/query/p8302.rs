@@ -0,0 +1,125 @@
+use std::ffi::c_char;
+use std::mem::size_of;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::utils::{assert_code, parse_decimal, parse_number, parse_string};
+use crate::{error::*, models::*, QvOpenApiRequest};
+use qvopenapi_bindings::{Tp8302InBlock, Tp8302OutBlock, Tp8302OutBlock1};
+
+pub const TR_CODE_P8302: &str = "p8302";
+
+// 1:코스피200선물 2:코스피200옵션 3:주식옵션 4:코스피200 5:스타지수선물 6:주식선물 9:전체 O:지수선옵/위클리
+const FO_PROC_GUBUN_CODES: &str = "1234569O";
+
+/**
+ * 선물옵션 계좌명 및 청산가능수량
+ */
+#[derive(Debug, Clone, Deserialize)]
+pub struct P8302Request {
+    pub account_index: i32,
+    pub proc_gubun: char, //처리구분 (9:전체)
+}
+
+impl P8302Request {
+    pub fn new(account_index: i32, proc_gubun: char) -> P8302Request {
+        P8302Request {
+            account_index,
+            proc_gubun,
+        }
+    }
+
+    pub fn into_raw(&self) -> Result<Arc<RawQueryRequest<Tp8302InBlock>>, QvOpenApiError> {
+        assert_code("proc_gubun", self.proc_gubun, FO_PROC_GUBUN_CODES)?;
+
+        Ok(Arc::new(RawQueryRequest::new(
+            TR_CODE_P8302,
+            self.account_index,
+            Box::new(Tp8302InBlock {
+                proc_gubunz1: [self.proc_gubun as c_char],
+                _proc_gubunz1: ' ' as c_char,
+                // 대상자산코드는 사용하지 않음
+                trobj_stock_codez12: [' ' as c_char; 12],
+                _trobj_stock_codez12: ' ' as c_char,
+            }),
+        )))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct P8302Output {
+    #[serde(rename = "p8302OutBlock")]
+    pub account: P8302Response, //계좌명
+    #[serde(rename = "p8302OutBlock1", default)]
+    pub positions: Vec<P8302Response1>, //청산가능수량
+}
+
+impl TypedQueryRequest for P8302Request {
+    type Output = P8302Output;
+
+    fn to_query(&self) -> Result<Arc<dyn QvOpenApiRequest>, QvOpenApiError> {
+        Ok(self.into_raw()?)
+    }
+}
+
+pub fn parse_p8302_response(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Tp8302OutBlock));
+        Ok(json!(P8302Response {
+            o_accnt_namez40: parse_string(&res.o_accnt_namez40)?,
+        }))
+    }
+}
+
+pub fn parse_p8302_response1_array(
+    block_data: *const c_char,
+    block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let block_count = block_len as usize / size_of::<Tp8302OutBlock1>();
+        let res: &[Tp8302OutBlock1] =
+            core::slice::from_raw_parts(block_data as *const Tp8302OutBlock1, block_count);
+
+        let ret: Result<Vec<P8302Response1>, QvOpenApiError> =
+            res.iter().map(parse_p8302_response1).collect();
+        Ok(json!(ret?))
+    }
+}
+
+fn parse_p8302_response1(res: &Tp8302OutBlock1) -> Result<P8302Response1, QvOpenApiError> {
+    Ok(P8302Response1 {
+        index_issue_codez9: parse_string(&res.index_issue_codez9)?,
+        index_issue_namez30: parse_string(&res.index_issue_namez30)?,
+        index_slbuy_typez2: parse_string(&res.index_slbuy_typez2)?,
+        index_slbuy_namez6: parse_string(&res.index_slbuy_namez6)?,
+        bal_qtyz12: parse_number(&res.bal_qtyz12)?,
+        today_revs_odqtyz12: parse_number(&res.today_revs_odqtyz12)?,
+        sell_posbl_qtyz12: parse_number(&res.sell_posbl_qtyz12)?,
+        avrgez14: parse_decimal(&res.avrgez14)?,
+    })
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct P8302Response {
+    pub o_accnt_namez40: String, //계좌명
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct P8302Response1 {
+    pub index_issue_codez9: String,       //종목
+    pub index_issue_namez30: String,      //종목명
+    pub index_slbuy_typez2: String,       //매매구분 (61:매수 62:매도)
+    pub index_slbuy_namez6: String,       //매매구분명
+    pub bal_qtyz12: Option<i64>,          //잔고수량
+    pub today_revs_odqtyz12: Option<i64>, //주문수량
+    pub sell_posbl_qtyz12: Option<i64>,   //청산가능수량
+    pub avrgez14: Option<f64>,            //평균가
+}
+
+pub const BLOCK_NAME_P8302_OUT: &str = "p8302OutBlock";
+pub const BLOCK_NAME_P8302_OUT1_ARRAY: &str = "p8302OutBlock1";
@@ -0,0 +1,596 @@
+use std::ffi::c_char;
+use std::mem::size_of;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::utils::{parse_decimal, parse_number, parse_sign, parse_string, to_fixed_string};
+use crate::{error::*, models::*, QvOpenApiRequest};
+use qvopenapi_bindings::{
+    Ts1701InBlock, Ts1701OutBlock, Ts1701OutBlock1, Ts1701OutBlock2, Ts1701OutBlock3,
+    Ts1701OutBlock4, Ts1701OutBlock5,
+};
+
+pub const TR_CODE_S1701: &str = "s1701";
+
+/**
+ * ELW 현재가 조회
+ */
+#[derive(Debug, Clone, Deserialize)]
+pub struct S1701Request {
+    pub code: String, //종목코드 (단축코드 6자리)
+}
+
+impl S1701Request {
+    pub fn new(code: &str) -> S1701Request {
+        S1701Request { code: code.into() }
+    }
+
+    pub fn into_raw(&self) -> Result<Arc<RawQueryRequest<Ts1701InBlock>>, QvOpenApiError> {
+        Ok(Arc::new(RawQueryRequest::new(
+            TR_CODE_S1701,
+            // 시세 조회는 계좌와 무관
+            0,
+            Box::new(Ts1701InBlock {
+                code: to_fixed_string(&self.code)?,
+                _code: ' ' as c_char,
+            }),
+        )))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S1701Output {
+    #[serde(rename = "s1701OutBlock")]
+    pub quote: S1701Response, //종목마스타기본자료
+    #[serde(rename = "s1701OutBlock1", default)]
+    pub underlyings: Vec<S1701Response1>, //기초자산정보
+    #[serde(rename = "s1701OutBlock2", default)]
+    pub traders: Option<S1701Response2>, //거래원정보
+    #[serde(rename = "s1701OutBlock3", default)]
+    pub trades: Vec<S1701Response3>, //ELW변동거래량자료
+    #[serde(rename = "s1701OutBlock4", default)]
+    pub kospi200: Option<S1701Response4>, //K200기초자산정보
+    #[serde(rename = "s1701OutBlock5", default)]
+    pub foreign_index: Option<S1701Response5>, //해외지수기초자산정보
+}
+
+impl TypedQueryRequest for S1701Request {
+    type Output = S1701Output;
+
+    fn to_query(&self) -> Result<Arc<dyn QvOpenApiRequest>, QvOpenApiError> {
+        Ok(self.into_raw()?)
+    }
+}
+
+pub fn parse_s1701_response(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Ts1701OutBlock));
+        Ok(json!(S1701Response {
+            code: parse_string(&res.code)?,
+            hname: parse_string(&res.hname)?,
+            price: parse_number(&res.price)?,
+            sign: parse_sign(&res.sign)?,
+            change: parse_number(&res.change)?,
+            chrate: parse_decimal(&res.chrate)?,
+            offer: parse_number(&res.offer)?,
+            bid: parse_number(&res.bid)?,
+            volume: parse_number(&res.volume)?,
+            volrate: parse_decimal(&res.volrate)?,
+            value: parse_number(&res.value)?,
+            open: parse_number(&res.open)?,
+            high: parse_number(&res.high)?,
+            low: parse_number(&res.low)?,
+            sale: parse_number(&res.sale)?,
+            dnlmtprice: parse_number(&res.dnlmtprice)?,
+            theoryprice: parse_number(&res.theoryprice)?,
+            grate: parse_number(&res.grate)?,
+            actprice: parse_decimal(&res.actprice)?,
+            listhprice: parse_number(&res.listhprice)?,
+            listhdate: parse_string(&res.listhdate)?,
+            listlprice: parse_number(&res.listlprice)?,
+            listldate: parse_string(&res.listldate)?,
+            preprice: parse_number(&res.preprice)?,
+            hotime: parse_string(&res.hotime)?,
+            offho1: parse_number(&res.offho1)?,
+            offho2: parse_number(&res.offho2)?,
+            offho3: parse_number(&res.offho3)?,
+            offho4: parse_number(&res.offho4)?,
+            offho5: parse_number(&res.offho5)?,
+            offho6: parse_number(&res.offho6)?,
+            offho7: parse_number(&res.offho7)?,
+            offho8: parse_number(&res.offho8)?,
+            offho9: parse_number(&res.offho9)?,
+            offho10: parse_number(&res.offho10)?,
+            bidho1: parse_number(&res.bidho1)?,
+            bidho2: parse_number(&res.bidho2)?,
+            bidho3: parse_number(&res.bidho3)?,
+            bidho4: parse_number(&res.bidho4)?,
+            bidho5: parse_number(&res.bidho5)?,
+            bidho6: parse_number(&res.bidho6)?,
+            bidho7: parse_number(&res.bidho7)?,
+            bidho8: parse_number(&res.bidho8)?,
+            bidho9: parse_number(&res.bidho9)?,
+            bidho10: parse_number(&res.bidho10)?,
+            offremain1: parse_number(&res.offremain1)?,
+            offremain2: parse_number(&res.offremain2)?,
+            offremain3: parse_number(&res.offremain3)?,
+            offremain4: parse_number(&res.offremain4)?,
+            offremain5: parse_number(&res.offremain5)?,
+            offremain6: parse_number(&res.offremain6)?,
+            offremain7: parse_number(&res.offremain7)?,
+            offremain8: parse_number(&res.offremain8)?,
+            offremain9: parse_number(&res.offremain9)?,
+            offremain10: parse_number(&res.offremain10)?,
+            bidremain1: parse_number(&res.bidremain1)?,
+            bidremain2: parse_number(&res.bidremain2)?,
+            bidremain3: parse_number(&res.bidremain3)?,
+            bidremain4: parse_number(&res.bidremain4)?,
+            bidremain5: parse_number(&res.bidremain5)?,
+            bidremain6: parse_number(&res.bidremain6)?,
+            bidremain7: parse_number(&res.bidremain7)?,
+            bidremain8: parse_number(&res.bidremain8)?,
+            bidremain9: parse_number(&res.bidremain9)?,
+            bidremain10: parse_number(&res.bidremain10)?,
+            lpoffremain1: parse_number(&res.lpoffremain1)?,
+            lpoffremain2: parse_number(&res.lpoffremain2)?,
+            lpoffremain3: parse_number(&res.lpoffremain3)?,
+            lpoffremain4: parse_number(&res.lpoffremain4)?,
+            lpoffremain5: parse_number(&res.lpoffremain5)?,
+            lpoffremain6: parse_number(&res.lpoffremain6)?,
+            lpoffremain7: parse_number(&res.lpoffremain7)?,
+            lpoffremain8: parse_number(&res.lpoffremain8)?,
+            lpoffremain9: parse_number(&res.lpoffremain9)?,
+            lpoffremain10: parse_number(&res.lpoffremain10)?,
+            lpbidremain1: parse_number(&res.lpbidremain1)?,
+            lpbidremain2: parse_number(&res.lpbidremain2)?,
+            lpbidremain3: parse_number(&res.lpbidremain3)?,
+            lpbidremain4: parse_number(&res.lpbidremain4)?,
+            lpbidremain5: parse_number(&res.lpbidremain5)?,
+            lpbidremain6: parse_number(&res.lpbidremain6)?,
+            lpbidremain7: parse_number(&res.lpbidremain7)?,
+            lpbidremain8: parse_number(&res.lpbidremain8)?,
+            lpbidremain9: parse_number(&res.lpbidremain9)?,
+            lpbidremain10: parse_number(&res.lpbidremain10)?,
+            offtot: parse_number(&res.offtot)?,
+            bidtot: parse_number(&res.bidtot)?,
+            impv: parse_decimal(&res.impv)?,
+            delta: parse_decimal(&res.delta)?,
+            gamma: parse_decimal(&res.gamma)?,
+            vega: parse_decimal(&res.vega)?,
+            theta: parse_decimal(&res.theta)?,
+            rho: parse_decimal(&res.rho)?,
+            cdratio: parse_decimal(&res.cdratio)?,
+            divideratio: parse_decimal(&res.divideratio)?,
+            jandatecnt: parse_number(&res.jandatecnt)?,
+            elwsdate: parse_string(&res.elwsdate)?,
+            elwedate: parse_string(&res.elwedate)?,
+            lastdate: parse_string(&res.lastdate)?,
+            balname: parse_string(&res.balname)?,
+            listing: parse_number(&res.listing)?,
+            rightgb: parse_string(&res.rightgb)?,
+            righttype: parse_string(&res.righttype)?,
+            settletype: parse_string(&res.settletype)?,
+            changerate: parse_decimal(&res.changerate)?,
+            rewardrate: parse_decimal(&res.rewardrate)?,
+            uppartrate: parse_decimal(&res.uppartrate)?,
+            paydate: parse_string(&res.paydate)?,
+            lpjumun: parse_string(&res.lpjumun)?,
+            parity: parse_decimal(&res.parity)?,
+            gearingrate: parse_decimal(&res.gearingrate)?,
+            profitrate: parse_decimal(&res.profitrate)?,
+            basepoint: parse_decimal(&res.basepoint)?,
+            lp_name1: parse_string(&res.lp_name1)?,
+            lp_name2: parse_string(&res.lp_name2)?,
+            lp_name3: parse_string(&res.lp_name3)?,
+            lp_name4: parse_string(&res.lp_name4)?,
+            lp_name5: parse_string(&res.lp_name5)?,
+            dongsi: parse_string(&res.dongsi)?,
+            eqprice: parse_number(&res.eqprice)?,
+            eqsign: parse_sign(&res.eqsign)?,
+            eqchange: parse_number(&res.eqchange)?,
+            eqchrate: parse_decimal(&res.eqchrate)?,
+            eqvol: parse_number(&res.eqvol)?,
+            lphold: parse_number(&res.lphold)?,
+            lprate: parse_decimal(&res.lprate)?,
+            egearing: parse_decimal(&res.egearing)?,
+            fixpay: parse_number(&res.fixpay)?,
+            listdate: parse_string(&res.listdate)?,
+            listhdatez8: parse_string(&res.listhdatez8)?,
+            listldatez8: parse_string(&res.listldatez8)?,
+            intval: parse_decimal(&res.intval)?,
+            leverage: parse_decimal(&res.leverage)?,
+            timeval: parse_decimal(&res.timeval)?,
+            gratio: parse_decimal(&res.gratio)?,
+            profitpt: parse_number(&res.profitpt)?,
+            payproxy: parse_string(&res.payproxy)?,
+            standardopt: parse_string(&res.standardopt)?,
+            koprice: parse_number(&res.koprice)?,
+            koappr: parse_decimal(&res.koappr)?,
+            expcode: parse_string(&res.expcode)?,
+            minpayment: parse_number(&res.minpayment)?,
+            stop: parse_string(&res.stop)?,
+            gratio2: parse_decimal(&res.gratio2)?,
+            lpstop: parse_string(&res.lpstop)?,
+            gonggb: parse_string(&res.gonggb)?,
+            lp_impv: parse_decimal(&res.lp_impv)?,
+            r_intval: parse_decimal(&res.r_intval)?,
+            jandatecnt2: parse_number(&res.jandatecnt2)?,
+            profitpt2: parse_decimal(&res.profitpt2)?,
+            alertgb: parse_string(&res.alertgb)?,
+        }))
+    }
+}
+
+pub fn parse_s1701_response1_array(
+    block_data: *const c_char,
+    block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let block_count = block_len as usize / size_of::<Ts1701OutBlock1>();
+        let res: &[Ts1701OutBlock1] =
+            core::slice::from_raw_parts(block_data as *const Ts1701OutBlock1, block_count);
+
+        let ret: Result<Vec<S1701Response1>, QvOpenApiError> =
+            res.iter().map(parse_s1701_response1).collect();
+        Ok(json!(ret?))
+    }
+}
+
+fn parse_s1701_response1(res: &Ts1701OutBlock1) -> Result<S1701Response1, QvOpenApiError> {
+    Ok(S1701Response1 {
+        code1: parse_string(&res.code1)?,
+        hname1: parse_string(&res.hname1)?,
+        price1: parse_number(&res.price1)?,
+        sign1: parse_sign(&res.sign1)?,
+        change1: parse_number(&res.change1)?,
+        chrate1: parse_decimal(&res.chrate1)?,
+        comrate1: parse_decimal(&res.comrate1)?,
+        pastv1: parse_decimal(&res.pastv1)?,
+        basegubun: parse_string(&res.basegubun)?,
+    })
+}
+
+pub fn parse_s1701_response2(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Ts1701OutBlock2));
+        Ok(json!(S1701Response2 {
+            tratimez5: parse_string(&res.tratimez5)?,
+            off_tra1: parse_string(&res.off_tra1)?,
+            bid_tra1: parse_string(&res.bid_tra1)?,
+            offvolume1: parse_number(&res.offvolume1)?,
+            bidvolume1: parse_number(&res.bidvolume1)?,
+            off_tra2: parse_string(&res.off_tra2)?,
+            bid_tra2: parse_string(&res.bid_tra2)?,
+            offvolume2: parse_number(&res.offvolume2)?,
+            bidvolume2: parse_number(&res.bidvolume2)?,
+            off_tra3: parse_string(&res.off_tra3)?,
+            bid_tra3: parse_string(&res.bid_tra3)?,
+            offvolume3: parse_number(&res.offvolume3)?,
+            bidvolume3: parse_number(&res.bidvolume3)?,
+            off_tra4: parse_string(&res.off_tra4)?,
+            bid_tra4: parse_string(&res.bid_tra4)?,
+            offvolume4: parse_number(&res.offvolume4)?,
+            bidvolume4: parse_number(&res.bidvolume4)?,
+            off_tra5: parse_string(&res.off_tra5)?,
+            bid_tra5: parse_string(&res.bid_tra5)?,
+            offvolume5: parse_number(&res.offvolume5)?,
+            bidvolume5: parse_number(&res.bidvolume5)?,
+            offvolall: parse_number(&res.offvolall)?,
+            bidvolall: parse_number(&res.bidvolall)?,
+            alloffvol: parse_number(&res.alloffvol)?,
+            allbidvol: parse_number(&res.allbidvol)?,
+        }))
+    }
+}
+
+pub fn parse_s1701_response3_array(
+    block_data: *const c_char,
+    block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let block_count = block_len as usize / size_of::<Ts1701OutBlock3>();
+        let res: &[Ts1701OutBlock3] =
+            core::slice::from_raw_parts(block_data as *const Ts1701OutBlock3, block_count);
+
+        let ret: Result<Vec<S1701Response3>, QvOpenApiError> =
+            res.iter().map(parse_s1701_response3).collect();
+        Ok(json!(ret?))
+    }
+}
+
+fn parse_s1701_response3(res: &Ts1701OutBlock3) -> Result<S1701Response3, QvOpenApiError> {
+    Ok(S1701Response3 {
+        chetime: parse_string(&res.chetime)?,
+        price: parse_number(&res.price)?,
+        sign: parse_sign(&res.sign)?,
+        change: parse_number(&res.change)?,
+        offer: parse_number(&res.offer)?,
+        bid: parse_number(&res.bid)?,
+        volume: parse_number(&res.volume)?,
+        movvol: parse_number(&res.movvol)?,
+    })
+}
+
+pub fn parse_s1701_response4(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Ts1701OutBlock4));
+        Ok(json!(S1701Response4 {
+            code6: parse_string(&res.code6)?,
+            hname6: parse_string(&res.hname6)?,
+            price6: parse_decimal(&res.price6)?,
+            sign6: parse_sign(&res.sign6)?,
+            change6: parse_decimal(&res.change6)?,
+            chrate6: parse_decimal(&res.chrate6)?,
+            comrate6: parse_decimal(&res.comrate6)?,
+            pastv6: parse_decimal(&res.pastv6)?,
+        }))
+    }
+}
+
+pub fn parse_s1701_response5(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Ts1701OutBlock5));
+        Ok(json!(S1701Response5 {
+            code7: parse_string(&res.code7)?,
+            hname7: parse_string(&res.hname7)?,
+            price7: parse_decimal(&res.price7)?,
+            sign7: parse_sign(&res.sign7)?,
+            change7: parse_decimal(&res.change7)?,
+            chrate7: parse_decimal(&res.chrate7)?,
+            time7: parse_string(&res.time7)?,
+        }))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S1701Response {
+    pub code: String,               //종목코드
+    pub hname: String,              //종목명
+    pub price: Option<i64>,         //현재가
+    pub sign: Option<PriceSign>,    //등락부호
+    pub change: Option<i64>,        //등락폭
+    pub chrate: Option<f64>,        //등락률
+    pub offer: Option<i64>,         //매도호가
+    pub bid: Option<i64>,           //매수호가
+    pub volume: Option<i64>,        //거래량
+    pub volrate: Option<f64>,       //거래비율
+    pub value: Option<i64>,         //거래대금
+    pub open: Option<i64>,          //시가
+    pub high: Option<i64>,          //고가
+    pub low: Option<i64>,           //저가
+    pub sale: Option<i64>,          //발행가
+    pub dnlmtprice: Option<i64>,    //하한가
+    pub theoryprice: Option<i64>,   //이론가
+    pub grate: Option<i64>,         //괴리도
+    pub actprice: Option<f64>,      //행사가
+    pub listhprice: Option<i64>,    //상장후최고가
+    pub listhdate: String,          //상장후최고가일
+    pub listlprice: Option<i64>,    //상장후최저가
+    pub listldate: String,          //상장후최저가일
+    pub preprice: Option<i64>,      //전일종가
+    pub hotime: String,             //호가시간
+    pub offho1: Option<i64>,        //매도최우선호가
+    pub offho2: Option<i64>,        //매도차선호가
+    pub offho3: Option<i64>,        //매도차차선호가
+    pub offho4: Option<i64>,        //매도4차선호가
+    pub offho5: Option<i64>,        //매도5차선호가
+    pub offho6: Option<i64>,        //매도6차선호가
+    pub offho7: Option<i64>,        //매도7차선호가
+    pub offho8: Option<i64>,        //매도8차선호가
+    pub offho9: Option<i64>,        //매도9차선호가
+    pub offho10: Option<i64>,       //매도10차선호가
+    pub bidho1: Option<i64>,        //매수최우선호가
+    pub bidho2: Option<i64>,        //매수차선호가
+    pub bidho3: Option<i64>,        //매수차차선호가
+    pub bidho4: Option<i64>,        //매수4차선호가
+    pub bidho5: Option<i64>,        //매수5차선호가
+    pub bidho6: Option<i64>,        //매수6차선호가
+    pub bidho7: Option<i64>,        //매수7차선호가
+    pub bidho8: Option<i64>,        //매수8차선호가
+    pub bidho9: Option<i64>,        //매수9차선호가
+    pub bidho10: Option<i64>,       //매수10차선호가
+    pub offremain1: Option<i64>,    //매도최우선잔량
+    pub offremain2: Option<i64>,    //매도차선잔량
+    pub offremain3: Option<i64>,    //매도차차선잔량
+    pub offremain4: Option<i64>,    //매도4차선잔량
+    pub offremain5: Option<i64>,    //매도5차선잔량
+    pub offremain6: Option<i64>,    //매도6차선잔량
+    pub offremain7: Option<i64>,    //매도7차선잔량
+    pub offremain8: Option<i64>,    //매도8차선잔량
+    pub offremain9: Option<i64>,    //매도9차선잔량
+    pub offremain10: Option<i64>,   //매도10차선잔량
+    pub bidremain1: Option<i64>,    //매수최우선잔량
+    pub bidremain2: Option<i64>,    //매수차선잔량
+    pub bidremain3: Option<i64>,    //매수차차선잔량
+    pub bidremain4: Option<i64>,    //매수4차선잔량
+    pub bidremain5: Option<i64>,    //매수5차선잔량
+    pub bidremain6: Option<i64>,    //매수6차선잔량
+    pub bidremain7: Option<i64>,    //매수7차선잔량
+    pub bidremain8: Option<i64>,    //매수8차선잔량
+    pub bidremain9: Option<i64>,    //매수9차선잔량
+    pub bidremain10: Option<i64>,   //매수10차선잔량
+    pub lpoffremain1: Option<i64>,  //LP매도최우선잔량
+    pub lpoffremain2: Option<i64>,  //LP매도차선잔량
+    pub lpoffremain3: Option<i64>,  //LP매도차차선잔량
+    pub lpoffremain4: Option<i64>,  //LP매도4차선잔량
+    pub lpoffremain5: Option<i64>,  //LP매도5차선잔량
+    pub lpoffremain6: Option<i64>,  //LP매도6차선잔량
+    pub lpoffremain7: Option<i64>,  //LP매도7차선잔량
+    pub lpoffremain8: Option<i64>,  //LP매도8차선잔량
+    pub lpoffremain9: Option<i64>,  //LP매도9차선잔량
+    pub lpoffremain10: Option<i64>, //LP매도10차선잔량
+    pub lpbidremain1: Option<i64>,  //LP매수최우선잔량
+    pub lpbidremain2: Option<i64>,  //LP매수차선잔량
+    pub lpbidremain3: Option<i64>,  //LP매수차차선잔량
+    pub lpbidremain4: Option<i64>,  //LP매수4차선잔량
+    pub lpbidremain5: Option<i64>,  //LP매수5차선잔량
+    pub lpbidremain6: Option<i64>,  //LP매수6차선잔량
+    pub lpbidremain7: Option<i64>,  //LP매수7차선잔량
+    pub lpbidremain8: Option<i64>,  //LP매수8차선잔량
+    pub lpbidremain9: Option<i64>,  //LP매수9차선잔량
+    pub lpbidremain10: Option<i64>, //LP매수10차선잔량
+    pub offtot: Option<i64>,        //총매도잔량
+    pub bidtot: Option<i64>,        //총매수잔량
+    pub impv: Option<f64>,          //내재변동성
+    pub delta: Option<f64>,         //델타지수
+    pub gamma: Option<f64>,         //감마지수
+    pub vega: Option<f64>,          //베가변동성
+    pub theta: Option<f64>,         //쎄타시간
+    pub rho: Option<f64>,           //로이자율
+    pub cdratio: Option<f64>,       //이자율
+    pub divideratio: Option<f64>,   //배당액지수
+    pub jandatecnt: Option<i64>,    //잔존일
+    pub elwsdate: String,           //행사기간개시일
+    pub elwedate: String,           //행사기간종료일
+    pub lastdate: String,           //최종거래일
+    pub balname: String,            //발행기관
+    pub listing: Option<i64>,       //발행수량
+    pub rightgb: String,            //권리유형 (콜,풋,기타)
+    pub righttype: String,          //권리행사방식 (유럽형,미국형,기타)
+    pub settletype: String,         //결제방법 (현금,실물,현금+실물)
+    pub changerate: Option<f64>,    //전환비율
+    pub rewardrate: Option<f64>,    //최소지급율
+    pub uppartrate: Option<f64>,    //가격상승참여율
+    pub paydate: String,            //최종지급일
+    pub lpjumun: String,            //LP주문가능여부 (불가,가능)
+    pub parity: Option<f64>,        //패리티
+    pub gearingrate: Option<f64>,   //기어링비율
+    pub profitrate: Option<f64>,    //손익분기율
+    pub basepoint: Option<f64>,     //자본지지점
+    pub lp_name1: String,           //LP회원사1
+    pub lp_name2: String,           //LP회원사2
+    pub lp_name3: String,           //LP회원사3
+    pub lp_name4: String,           //LP회원사4
+    pub lp_name5: String,           //LP회원사5
+    pub dongsi: String,             //동시호가구분
+    pub eqprice: Option<i64>,       //예상체결가
+    pub eqsign: Option<PriceSign>,  //예상체결부호
+    pub eqchange: Option<i64>,      //예상체결등락폭
+    pub eqchrate: Option<f64>,      //예상체결등락률
+    pub eqvol: Option<i64>,         //예상체결수량
+    pub lphold: Option<i64>,        //LP보유수량
+    pub lprate: Option<f64>,        //LP보유율
+    pub egearing: Option<f64>,      //E기어링
+    pub fixpay: Option<i64>,        //확정지급액
+    pub listdate: String,           //상장일
+    pub listhdatez8: String,        //상장후최고가일
+    pub listldatez8: String,        //상장후최저가일
+    pub intval: Option<f64>,        //내재가치
+    pub leverage: Option<f64>,      //레버리지
+    pub timeval: Option<f64>,       //시간가치
+    pub gratio: Option<f64>,        //괴리율
+    pub profitpt: Option<i64>,      //손익분기점(정수)
+    pub payproxy: String,           //지급대리인
+    pub standardopt: String,        //종목구분 (01:표준,03:조기종료)
+    pub koprice: Option<i64>,       //조기종료가
+    pub koappr: Option<f64>,        //KO접근도
+    pub expcode: String,            //확장코드
+    pub minpayment: Option<i64>,    //최소지급액
+    pub stop: String,               //거래정지구분
+    pub gratio2: Option<f64>,       //괴리율2
+    pub lpstop: String,             //LP종료일
+    pub gonggb: String,             //추가상장공시
+    pub lp_impv: Option<f64>,       //LP내재변동성
+    pub r_intval: Option<f64>,      //실시간용내재가치
+    pub jandatecnt2: Option<i64>,   //잔존일(영업일)
+    pub profitpt2: Option<f64>,     //손익분기점(소수점)
+    pub alertgb: String,            //투자주의구분
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S1701Response1 {
+    pub code1: String,            //기초자산코드1
+    pub hname1: String,           //기초자산명1
+    pub price1: Option<i64>,      //현재가1
+    pub sign1: Option<PriceSign>, //등락부호1
+    pub change1: Option<i64>,     //등락폭1
+    pub chrate1: Option<f64>,     //등락률1
+    pub comrate1: Option<f64>,    //구성비1
+    pub pastv1: Option<f64>,      //과거변동성1
+    pub basegubun: String,        //기초자산시장구분 (1:코스피,2:코스닥)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S1701Response2 {
+    pub tratimez5: String,       //시간
+    pub off_tra1: String,        //매도거래원1
+    pub bid_tra1: String,        //매수거래원1
+    pub offvolume1: Option<i64>, //매도거래량1
+    pub bidvolume1: Option<i64>, //매수거래량1
+    pub off_tra2: String,        //매도거래원2
+    pub bid_tra2: String,        //매수거래원2
+    pub offvolume2: Option<i64>, //매도거래량2
+    pub bidvolume2: Option<i64>, //매수거래량2
+    pub off_tra3: String,        //매도거래원3
+    pub bid_tra3: String,        //매수거래원3
+    pub offvolume3: Option<i64>, //매도거래량3
+    pub bidvolume3: Option<i64>, //매수거래량3
+    pub off_tra4: String,        //매도거래원4
+    pub bid_tra4: String,        //매수거래원4
+    pub offvolume4: Option<i64>, //매도거래량4
+    pub bidvolume4: Option<i64>, //매수거래량4
+    pub off_tra5: String,        //매도거래원5
+    pub bid_tra5: String,        //매수거래원5
+    pub offvolume5: Option<i64>, //매도거래량5
+    pub bidvolume5: Option<i64>, //매수거래량5
+    pub offvolall: Option<i64>,  //매도외국인거래량
+    pub bidvolall: Option<i64>,  //매수외국인거래량
+    pub alloffvol: Option<i64>,  //전체거래원매도합
+    pub allbidvol: Option<i64>,  //전체거래원매수합
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S1701Response3 {
+    pub chetime: String,         //시간
+    pub price: Option<i64>,      //현재가
+    pub sign: Option<PriceSign>, //등락부호
+    pub change: Option<i64>,     //등락폭
+    pub offer: Option<i64>,      //매도호가
+    pub bid: Option<i64>,        //매수호가
+    pub volume: Option<i64>,     //거래량
+    pub movvol: Option<i64>,     //변동량
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S1701Response4 {
+    pub code6: String,            //기초자산코드6
+    pub hname6: String,           //기초자산명6
+    pub price6: Option<f64>,      //현재가6
+    pub sign6: Option<PriceSign>, //등락부호6
+    pub change6: Option<f64>,     //등락폭6
+    pub chrate6: Option<f64>,     //등락률6
+    pub comrate6: Option<f64>,    //구성비6
+    pub pastv6: Option<f64>,      //과거변동성6
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S1701Response5 {
+    pub code7: String,            //기초자산코드7
+    pub hname7: String,           //기초자산명7
+    pub price7: Option<f64>,      //현재가7
+    pub sign7: Option<PriceSign>, //등락부호7
+    pub change7: Option<f64>,     //등락폭7
+    pub chrate7: Option<f64>,     //등락률7
+    pub time7: String,            //데이타시간
+}
+
+pub const BLOCK_NAME_S1701_OUT: &str = "s1701OutBlock";
+pub const BLOCK_NAME_S1701_OUT1_ARRAY: &str = "s1701OutBlock1";
+pub const BLOCK_NAME_S1701_OUT2: &str = "s1701OutBlock2";
+pub const BLOCK_NAME_S1701_OUT3_ARRAY: &str = "s1701OutBlock3";
+pub const BLOCK_NAME_S1701_OUT4: &str = "s1701OutBlock4";
+pub const BLOCK_NAME_S1701_OUT5: &str = "s1701OutBlock5";
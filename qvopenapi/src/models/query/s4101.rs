@@ -0,0 +1,462 @@
+use std::ffi::c_char;
+use std::mem::size_of;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::utils::{
+    parse_decimal, parse_number, parse_scaled, parse_sign, parse_string, to_fixed_string,
+};
+use crate::{error::*, models::*, QvOpenApiRequest};
+use qvopenapi_bindings::{
+    Ts4101InBlock, Ts4101OutBlock, Ts4101OutBlock1, Ts4101OutBlock2, Ts4101OutBlock3,
+    Ts4101OutBlock4, Ts4101OutBlock5, Ts4101OutBlock6,
+};
+
+pub const TR_CODE_S4101: &str = "s4101";
+
+/**
+ * 선물 현재가 조회
+ */
+#[derive(Debug, Clone, Deserialize)]
+pub struct S4101Request {
+    pub formlang: char, //한영구분 (k:한글, 기타:영문)
+    pub code: String,   //종목코드 (거래소 단축코드 8자리)
+}
+
+impl S4101Request {
+    pub fn new(formlang: char, code: &str) -> S4101Request {
+        S4101Request {
+            formlang,
+            code: code.into(),
+        }
+    }
+
+    pub fn into_raw(&self) -> Result<Arc<RawQueryRequest<Ts4101InBlock>>, QvOpenApiError> {
+        Ok(Arc::new(RawQueryRequest::new(
+            TR_CODE_S4101,
+            // 시세 조회는 계좌와 무관
+            0,
+            Box::new(Ts4101InBlock {
+                formlang: [self.formlang as c_char],
+                _formlang: ' ' as c_char,
+                fuitemz9: to_fixed_string(&self.code)?,
+                _fuitemz9: ' ' as c_char,
+            }),
+        )))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S4101Output {
+    #[serde(rename = "s4101OutBlock")]
+    pub quote: S4101Response, //종목마스타기본자료
+    #[serde(rename = "s4101OutBlock1", default)]
+    pub kospi200: Option<S4101Response1>, //코스피200지수
+    #[serde(rename = "s4101OutBlock2", default)]
+    pub trades: Vec<S4101Response2>, //변동거래량자료
+    #[serde(rename = "s4101OutBlock3", default)]
+    pub investors_recent: Vec<S4101Response3>, //시간대별투자자현황최근메모리
+    #[serde(rename = "s4101OutBlock4", default)]
+    pub investors_by_time: Vec<S4101Response4>, //시간대별투자자현황시간별
+    #[serde(rename = "s4101OutBlock5", default)]
+    pub kospi200_top10: Vec<S4101Response5>, //KOSPI200시가총액상위10종목
+    #[serde(rename = "s4101OutBlock6", default)]
+    pub expected: Option<S4101Response6>, //예상체결
+}
+
+impl TypedQueryRequest for S4101Request {
+    type Output = S4101Output;
+
+    fn to_query(&self) -> Result<Arc<dyn QvOpenApiRequest>, QvOpenApiError> {
+        Ok(self.into_raw()?)
+    }
+}
+
+pub fn parse_s4101_response(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Ts4101OutBlock));
+        Ok(json!(S4101Response {
+            fuitem: parse_string(&res.fuitem)?,
+            fuhname: parse_string(&res.fuhname)?,
+            fucurr: parse_scaled(&res.fucurr, 2)?,
+            fusign: parse_sign(&res.fusign)?,
+            fuchange: parse_scaled(&res.fuchange, 2)?,
+            fuchrate: parse_scaled(&res.fuchrate, 2)?,
+            fubasis: parse_scaled(&res.fubasis, 2)?,
+            futheoryprice: parse_scaled(&res.futheoryprice, 2)?,
+            fugrate: parse_scaled(&res.fugrate, 2)?,
+            fugratio: parse_scaled(&res.fugratio, 2)?,
+            fuvolall: parse_number(&res.fuvolall)?,
+            fuvalall: parse_number(&res.fuvalall)?,
+            fuopenyak: parse_number(&res.fuopenyak)?,
+            fupreopenyak: parse_number(&res.fupreopenyak)?,
+            fuhprice: parse_scaled(&res.fuhprice, 2)?,
+            fuhigh: parse_scaled(&res.fuhigh, 2)?,
+            fuopen: parse_scaled(&res.fuopen, 2)?,
+            fuopensign: parse_sign(&res.fuopensign)?,
+            fuopenchange: parse_scaled(&res.fuopenchange, 2)?,
+            fulow: parse_scaled(&res.fulow, 2)?,
+            fulprice: parse_scaled(&res.fulprice, 2)?,
+            fucbhprice: parse_scaled(&res.fucbhprice, 2)?,
+            fucblprice: parse_scaled(&res.fucblprice, 2)?,
+            fudehprice: parse_scaled(&res.fudehprice, 2)?,
+            fudelprice: parse_scaled(&res.fudelprice, 2)?,
+            fulisthprice: parse_scaled(&res.fulisthprice, 2)?,
+            fulisthdate: parse_string(&res.fulisthdate)?,
+            fulistlprice: parse_scaled(&res.fulistlprice, 2)?,
+            fulistldate: parse_string(&res.fulistldate)?,
+            fulastdate: parse_string(&res.fulastdate)?,
+            fujandatecnt: parse_number(&res.fujandatecnt)?,
+            fucdratio: parse_decimal(&res.fucdratio)?,
+            fuchetime: parse_string(&res.fuchetime)?,
+            fuoffer: parse_scaled(&res.fuoffer, 2)?,
+            fujoffer: parse_scaled(&res.fujoffer, 2)?,
+            fujjoffer: parse_scaled(&res.fujjoffer, 2)?,
+            fuj4offer: parse_scaled(&res.fuj4offer, 2)?,
+            fuj5offer: parse_scaled(&res.fuj5offer, 2)?,
+            fubid: parse_scaled(&res.fubid, 2)?,
+            fujbid: parse_scaled(&res.fujbid, 2)?,
+            fujjbid: parse_scaled(&res.fujjbid, 2)?,
+            fuj4bid: parse_scaled(&res.fuj4bid, 2)?,
+            fuj5bid: parse_scaled(&res.fuj5bid, 2)?,
+            fuofferjan: parse_number(&res.fuofferjan)?,
+            fujofferjan: parse_number(&res.fujofferjan)?,
+            fujjofferjan: parse_number(&res.fujjofferjan)?,
+            fuj4offerjan: parse_number(&res.fuj4offerjan)?,
+            fuj5offerjan: parse_number(&res.fuj5offerjan)?,
+            fubidjan: parse_number(&res.fubidjan)?,
+            fujbidjan: parse_number(&res.fujbidjan)?,
+            fujjbidjan: parse_number(&res.fujjbidjan)?,
+            fuj4bidjan: parse_number(&res.fuj4bidjan)?,
+            fuj5bidjan: parse_number(&res.fuj5bidjan)?,
+            futofferjan: parse_number(&res.futofferjan)?,
+            futbidjan: parse_number(&res.futbidjan)?,
+            fuoffersu: parse_number(&res.fuoffersu)?,
+            fujoffersu: parse_number(&res.fujoffersu)?,
+            fujjoffersu: parse_number(&res.fujjoffersu)?,
+            fuj4offersu: parse_number(&res.fuj4offersu)?,
+            fuj5offersu: parse_number(&res.fuj5offersu)?,
+            fubidsu: parse_number(&res.fubidsu)?,
+            fujbidsu: parse_number(&res.fujbidsu)?,
+            fujjbidsu: parse_number(&res.fujjbidsu)?,
+            fuj4bidsu: parse_number(&res.fuj4bidsu)?,
+            fuj5bidsu: parse_number(&res.fuj5bidsu)?,
+            futoffersu: parse_number(&res.futoffersu)?,
+            futbidsu: parse_number(&res.futbidsu)?,
+            fupivot2upz5: parse_scaled(&res.fupivot2upz5, 2)?,
+            fupivot1upz5: parse_scaled(&res.fupivot1upz5, 2)?,
+            fupivotz5: parse_scaled(&res.fupivotz5, 2)?,
+            fupivot1dnz5: parse_scaled(&res.fupivot1dnz5, 2)?,
+            fupivot2dnz5: parse_scaled(&res.fupivot2dnz5, 2)?,
+            fujgubun: parse_string(&res.fujgubun)?,
+            fuspvolall: parse_number(&res.fuspvolall)?,
+            fudivideratio: parse_decimal(&res.fudivideratio)?,
+            preclose: parse_scaled(&res.preclose, 2)?,
+            fudynhprice: parse_scaled(&res.fudynhprice, 2)?,
+            fudynlprice: parse_scaled(&res.fudynlprice, 2)?,
+            fudynpriceflag: parse_string(&res.fudynpriceflag)?,
+            fubulkvol: parse_number(&res.fubulkvol)?,
+            exlmtgb: parse_string(&res.exlmtgb)?,
+            uplmtgb: parse_string(&res.uplmtgb)?,
+            dnlmtgb: parse_string(&res.dnlmtgb)?,
+            baseprice: parse_scaled(&res.baseprice, 2)?,
+            theory_basis: parse_scaled(&res.theory_basis, 2)?,
+        }))
+    }
+}
+
+pub fn parse_s4101_response1(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Ts4101OutBlock1));
+        Ok(json!(S4101Response1 {
+            fuitem: parse_string(&res.fuitem)?,
+            fucurr: parse_scaled(&res.fucurr, 2)?,
+            fusign: parse_sign(&res.fusign)?,
+            fuchange: parse_scaled(&res.fuchange, 2)?,
+            fuchrate: parse_scaled(&res.fuchrate, 2)?,
+        }))
+    }
+}
+
+pub fn parse_s4101_response2_array(
+    block_data: *const c_char,
+    block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let block_count = block_len as usize / size_of::<Ts4101OutBlock2>();
+        let res: &[Ts4101OutBlock2] =
+            core::slice::from_raw_parts(block_data as *const Ts4101OutBlock2, block_count);
+
+        let ret: Result<Vec<S4101Response2>, QvOpenApiError> =
+            res.iter().map(parse_s4101_response2).collect();
+        Ok(json!(ret?))
+    }
+}
+
+fn parse_s4101_response2(res: &Ts4101OutBlock2) -> Result<S4101Response2, QvOpenApiError> {
+    Ok(S4101Response2 {
+        fuchetime: parse_string(&res.fuchetime)?,
+        fucurr: parse_scaled(&res.fucurr, 2)?,
+        fusign: parse_sign(&res.fusign)?,
+        fuchange: parse_scaled(&res.fuchange, 2)?,
+        fuoffer: parse_scaled(&res.fuoffer, 2)?,
+        fubid: parse_scaled(&res.fubid, 2)?,
+        fuvol: parse_number(&res.fuvol)?,
+        fuvolall: parse_number(&res.fuvolall)?,
+        fuopenyak: parse_number(&res.fuopenyak)?,
+    })
+}
+
+pub fn parse_s4101_response3_array(
+    block_data: *const c_char,
+    block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let block_count = block_len as usize / size_of::<Ts4101OutBlock3>();
+        let res: &[Ts4101OutBlock3] =
+            core::slice::from_raw_parts(block_data as *const Ts4101OutBlock3, block_count);
+
+        let ret: Result<Vec<S4101Response3>, QvOpenApiError> =
+            res.iter().map(parse_s4101_response3).collect();
+        Ok(json!(ret?))
+    }
+}
+
+fn parse_s4101_response3(res: &Ts4101OutBlock3) -> Result<S4101Response3, QvOpenApiError> {
+    Ok(S4101Response3 {
+        titlez6: parse_string(&res.titlez6)?,
+        amesuvalpure: parse_number(&res.amesuvalpure)?,
+        cmesuvalpure: parse_number(&res.cmesuvalpure)?,
+        imesuvalpure: parse_number(&res.imesuvalpure)?,
+    })
+}
+
+pub fn parse_s4101_response4_array(
+    block_data: *const c_char,
+    block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let block_count = block_len as usize / size_of::<Ts4101OutBlock4>();
+        let res: &[Ts4101OutBlock4] =
+            core::slice::from_raw_parts(block_data as *const Ts4101OutBlock4, block_count);
+
+        let ret: Result<Vec<S4101Response4>, QvOpenApiError> =
+            res.iter().map(parse_s4101_response4).collect();
+        Ok(json!(ret?))
+    }
+}
+
+fn parse_s4101_response4(res: &Ts4101OutBlock4) -> Result<S4101Response4, QvOpenApiError> {
+    Ok(S4101Response4 {
+        timez8: parse_string(&res.timez8)?,
+        amesuvalpure: parse_number(&res.amesuvalpure)?,
+        cmesuvalpure: parse_number(&res.cmesuvalpure)?,
+        imesuvalpure: parse_number(&res.imesuvalpure)?,
+    })
+}
+
+pub fn parse_s4101_response5_array(
+    block_data: *const c_char,
+    block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let block_count = block_len as usize / size_of::<Ts4101OutBlock5>();
+        let res: &[Ts4101OutBlock5] =
+            core::slice::from_raw_parts(block_data as *const Ts4101OutBlock5, block_count);
+
+        let ret: Result<Vec<S4101Response5>, QvOpenApiError> =
+            res.iter().map(parse_s4101_response5).collect();
+        Ok(json!(ret?))
+    }
+}
+
+fn parse_s4101_response5(res: &Ts4101OutBlock5) -> Result<S4101Response5, QvOpenApiError> {
+    Ok(S4101Response5 {
+        code: parse_string(&res.code)?,
+        hname: parse_string(&res.hname)?,
+        parvalue: parse_string(&res.parvalue)?,
+        price: parse_number(&res.price)?,
+        sign: parse_sign(&res.sign)?,
+        change: parse_number(&res.change)?,
+        chrate: parse_decimal(&res.chrate)?,
+    })
+}
+
+pub fn parse_s4101_response6(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Ts4101OutBlock6));
+        Ok(json!(S4101Response6 {
+            dongsi: parse_string(&res.dongsi)?,
+            jeqprice: parse_scaled(&res.jeqprice, 2)?,
+            jeqsign: parse_sign(&res.jeqsign)?,
+            jeqchange: parse_scaled(&res.jeqchange, 2)?,
+            jeqchrate: parse_scaled(&res.jeqchrate, 2)?,
+        }))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S4101Response {
+    pub fuitem: String,                //종목코드
+    pub fuhname: String,               //종목명
+    pub fucurr: Option<f64>,           //현재가
+    pub fusign: Option<PriceSign>,     //등락부호
+    pub fuchange: Option<f64>,         //등락폭
+    pub fuchrate: Option<f64>,         //등락률
+    pub fubasis: Option<f64>,          //베이시스
+    pub futheoryprice: Option<f64>,    //이론가
+    pub fugrate: Option<f64>,          //괴리도
+    pub fugratio: Option<f64>,         //괴리율
+    pub fuvolall: Option<i64>,         //거래량
+    pub fuvalall: Option<i64>,         //누적거래대금(백만)
+    pub fuopenyak: Option<i64>,        //미결제약정수량
+    pub fupreopenyak: Option<i64>,     //미결제약정전일
+    pub fuhprice: Option<f64>,         //상한가
+    pub fuhigh: Option<f64>,           //고가
+    pub fuopen: Option<f64>,           //시가
+    pub fuopensign: Option<PriceSign>, //시가대비부호
+    pub fuopenchange: Option<f64>,     //시가대비등락
+    pub fulow: Option<f64>,            //저가
+    pub fulprice: Option<f64>,         //하한가
+    pub fucbhprice: Option<f64>,       //CB발동상한
+    pub fucblprice: Option<f64>,       //CB발동하한
+    pub fudehprice: Option<f64>,       //DEMARK저항
+    pub fudelprice: Option<f64>,       //DEMARK지지
+    pub fulisthprice: Option<f64>,     //상장후최고가
+    pub fulisthdate: String,           //상장후최고일
+    pub fulistlprice: Option<f64>,     //상장후최저가
+    pub fulistldate: String,           //상장후최저일
+    pub fulastdate: String,            //최종거래일
+    pub fujandatecnt: Option<i64>,     //잔존일
+    pub fucdratio: Option<f64>,        //무위험이자율
+    pub fuchetime: String,             //호가시간
+    pub fuoffer: Option<f64>,          //매도최우선호가
+    pub fujoffer: Option<f64>,         //매도차선호가
+    pub fujjoffer: Option<f64>,        //매도차차선호가
+    pub fuj4offer: Option<f64>,        //매도4차선호가
+    pub fuj5offer: Option<f64>,        //매도5차선호가
+    pub fubid: Option<f64>,            //매수최우선호가
+    pub fujbid: Option<f64>,           //매수차선호가
+    pub fujjbid: Option<f64>,          //매수차차선호가
+    pub fuj4bid: Option<f64>,          //매수4차선호가
+    pub fuj5bid: Option<f64>,          //매수5차선호가
+    pub fuofferjan: Option<i64>,       //매도최우선잔량
+    pub fujofferjan: Option<i64>,      //매도차선잔량
+    pub fujjofferjan: Option<i64>,     //매도차차선잔량
+    pub fuj4offerjan: Option<i64>,     //매도4차선잔량
+    pub fuj5offerjan: Option<i64>,     //매도5차선잔량
+    pub fubidjan: Option<i64>,         //매수최우선잔량
+    pub fujbidjan: Option<i64>,        //매수차선잔량
+    pub fujjbidjan: Option<i64>,       //매수차차선잔량
+    pub fuj4bidjan: Option<i64>,       //매수4차선잔량
+    pub fuj5bidjan: Option<i64>,       //매수5차선잔량
+    pub futofferjan: Option<i64>,      //총매도잔량
+    pub futbidjan: Option<i64>,        //총매수잔량
+    pub fuoffersu: Option<i64>,        //매도최우선건수
+    pub fujoffersu: Option<i64>,       //매도차선건수
+    pub fujjoffersu: Option<i64>,      //매도차차선건수
+    pub fuj4offersu: Option<i64>,      //매도4차선건수
+    pub fuj5offersu: Option<i64>,      //매도5차선건수
+    pub fubidsu: Option<i64>,          //매수최우선건수
+    pub fujbidsu: Option<i64>,         //매수차선건수
+    pub fujjbidsu: Option<i64>,        //매수차차선건수
+    pub fuj4bidsu: Option<i64>,        //매수4차선건수
+    pub fuj5bidsu: Option<i64>,        //매수5차선건수
+    pub futoffersu: Option<i64>,       //총매도건수
+    pub futbidsu: Option<i64>,         //총매수건수
+    pub fupivot2upz5: Option<f64>,     //피봇2차저항
+    pub fupivot1upz5: Option<f64>,     //피봇1차저항
+    pub fupivotz5: Option<f64>,        //피봇가
+    pub fupivot1dnz5: Option<f64>,     //피봇1차지지
+    pub fupivot2dnz5: Option<f64>,     //피봇2차지지
+    pub fujgubun: String,              //CB발동여부
+    pub fuspvolall: Option<i64>,       //스프레드거래량
+    pub fudivideratio: Option<f64>,    //배당액지수
+    pub preclose: Option<f64>,         //전일종가
+    pub fudynhprice: Option<f64>,      //실시간상한가
+    pub fudynlprice: Option<f64>,      //실시간하한가
+    pub fudynpriceflag: String,        //동적가격제한여부
+    pub fubulkvol: Option<i64>,        //협의거래량
+    pub exlmtgb: String,               //가격확대예정구분
+    pub uplmtgb: String,               //가격제한확대상한단계
+    pub dnlmtgb: String,               //가격제한확대하한단계
+    pub baseprice: Option<f64>,        //기준가
+    pub theory_basis: Option<f64>,     //이론베이시스
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S4101Response1 {
+    pub fuitem: String,            //코스피200코드
+    pub fucurr: Option<f64>,       //코스피200지수
+    pub fusign: Option<PriceSign>, //코스피200등락부호
+    pub fuchange: Option<f64>,     //코스피200등락폭
+    pub fuchrate: Option<f64>,     //코스피200등락률
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S4101Response2 {
+    pub fuchetime: String,         //시간
+    pub fucurr: Option<f64>,       //현재가
+    pub fusign: Option<PriceSign>, //등락부호
+    pub fuchange: Option<f64>,     //등락폭
+    pub fuoffer: Option<f64>,      //매도호가
+    pub fubid: Option<f64>,        //매수호가
+    pub fuvol: Option<i64>,        //거래량
+    pub fuvolall: Option<i64>,     //누적거래량
+    pub fuopenyak: Option<i64>,    //미결제약정
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S4101Response3 {
+    pub titlez6: String,           //TITLE
+    pub amesuvalpure: Option<i64>, //순매수
+    pub cmesuvalpure: Option<i64>, //매도
+    pub imesuvalpure: Option<i64>, //매수
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S4101Response4 {
+    pub timez8: String,            //시간별
+    pub amesuvalpure: Option<i64>, //외국인순매수
+    pub cmesuvalpure: Option<i64>, //증권순매수
+    pub imesuvalpure: Option<i64>, //개인순매수
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S4101Response5 {
+    pub code: String,            //종목코드
+    pub hname: String,           //종목명
+    pub parvalue: String,        //액면가
+    pub price: Option<i64>,      //현재가
+    pub sign: Option<PriceSign>, //등락부호
+    pub change: Option<i64>,     //등락폭
+    pub chrate: Option<f64>,     //등락률
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S4101Response6 {
+    pub dongsi: String,             //동시호가구분
+    pub jeqprice: Option<f64>,      //예상체결가
+    pub jeqsign: Option<PriceSign>, //예상체결부호
+    pub jeqchange: Option<f64>,     //예상체결등락폭
+    pub jeqchrate: Option<f64>,     //예상체결등락률
+}
+
+pub const BLOCK_NAME_S4101_OUT: &str = "s4101OutBlock";
+pub const BLOCK_NAME_S4101_OUT1: &str = "s4101OutBlock1";
+pub const BLOCK_NAME_S4101_OUT2_ARRAY: &str = "s4101OutBlock2";
+pub const BLOCK_NAME_S4101_OUT3_ARRAY: &str = "s4101OutBlock3";
+pub const BLOCK_NAME_S4101_OUT4_ARRAY: &str = "s4101OutBlock4";
+pub const BLOCK_NAME_S4101_OUT5_ARRAY: &str = "s4101OutBlock5";
+pub const BLOCK_NAME_S4101_OUT6: &str = "s4101OutBlock6";
@@ -0,0 +1,546 @@
+use std::ffi::c_char;
+use std::mem::size_of;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::utils::{
+    parse_decimal, parse_number, parse_scaled, parse_sign, parse_string, to_fixed_string,
+};
+use crate::{error::*, models::*, QvOpenApiRequest};
+use qvopenapi_bindings::{
+    Ts4201InBlock, Ts4201OutBlock, Ts4201OutBlock1, Ts4201OutBlock2, Ts4201OutBlock3,
+    Ts4201OutBlock4, Ts4201OutBlock5, Ts4201OutBlock6,
+};
+
+pub const TR_CODE_S4201: &str = "s4201";
+
+/**
+ * 옵션 현재가 조회
+ */
+#[derive(Debug, Clone, Deserialize)]
+pub struct S4201Request {
+    pub formlang: char, //한영구분 (k:한글, 기타:영문)
+    pub code: String,   //종목코드 (거래소 단축코드 8자리)
+}
+
+impl S4201Request {
+    pub fn new(formlang: char, code: &str) -> S4201Request {
+        S4201Request {
+            formlang,
+            code: code.into(),
+        }
+    }
+
+    pub fn into_raw(&self) -> Result<Arc<RawQueryRequest<Ts4201InBlock>>, QvOpenApiError> {
+        Ok(Arc::new(RawQueryRequest::new(
+            TR_CODE_S4201,
+            // 시세 조회는 계좌와 무관
+            0,
+            Box::new(Ts4201InBlock {
+                formlang: [self.formlang as c_char],
+                _formlang: ' ' as c_char,
+                opitemz9: to_fixed_string(&self.code)?,
+                _opitemz9: ' ' as c_char,
+            }),
+        )))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S4201Output {
+    #[serde(rename = "s4201OutBlock")]
+    pub quote: S4201Response, //종목마스타기본자료
+    #[serde(rename = "s4201OutBlock1", default)]
+    pub kospi200: Option<S4201Response1>, //코스피200지수
+    #[serde(rename = "s4201OutBlock2", default)]
+    pub trades: Vec<S4201Response2>, //옵션변동거래량자료
+    #[serde(rename = "s4201OutBlock3", default)]
+    pub front_futures: Option<S4201Response3>, //선물최근월물
+    #[serde(rename = "s4201OutBlock4", default)]
+    pub futures_trades: Vec<S4201Response4>, //선물변동거래량자료
+    #[serde(rename = "s4201OutBlock5", default)]
+    pub expected: Option<S4201Response5>, //옵션예상체결
+    #[serde(rename = "s4201OutBlock6", default)]
+    pub futures_expected: Option<S4201Response6>, //선물예상체결
+}
+
+impl TypedQueryRequest for S4201Request {
+    type Output = S4201Output;
+
+    fn to_query(&self) -> Result<Arc<dyn QvOpenApiRequest>, QvOpenApiError> {
+        Ok(self.into_raw()?)
+    }
+}
+
+pub fn parse_s4201_response(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Ts4201OutBlock));
+        Ok(json!(S4201Response {
+            opitem: parse_string(&res.opitem)?,
+            ophname: parse_string(&res.ophname)?,
+            opcurr: parse_scaled(&res.opcurr, 2)?,
+            opsign: parse_sign(&res.opsign)?,
+            opchange: parse_scaled(&res.opchange, 2)?,
+            opchrate: parse_scaled(&res.opchrate, 2)?,
+            opopen: parse_scaled(&res.opopen, 2)?,
+            ophigh: parse_scaled(&res.ophigh, 2)?,
+            oplow: parse_scaled(&res.oplow, 2)?,
+            optheoryprice: parse_scaled(&res.optheoryprice, 2)?,
+            opvolallz8: parse_number(&res.opvolallz8)?,
+            opvalall: parse_number(&res.opvalall)?,
+            opopenyak: parse_number(&res.opopenyak)?,
+            oppreopenyak: parse_number(&res.oppreopenyak)?,
+            oplisthdatez11: parse_string(&res.oplisthdatez11)?,
+            oplistldatez11: parse_string(&res.oplistldatez11)?,
+            oplistdate: parse_string(&res.oplistdate)?,
+            oplastdate: parse_string(&res.oplastdate)?,
+            opjandatecnt: parse_number(&res.opjandatecnt)?,
+            ophprice: parse_scaled(&res.ophprice, 2)?,
+            oplprice: parse_scaled(&res.oplprice, 2)?,
+            opgrate: parse_scaled(&res.opgrate, 2)?,
+            opimpv: parse_scaled(&res.opimpv, 2)?,
+            oppastv90: parse_scaled(&res.oppastv90, 2)?,
+            opdelta: parse_decimal(&res.opdelta)?,
+            opgmma: parse_decimal(&res.opgmma)?,
+            opvega: parse_decimal(&res.opvega)?,
+            optheta: parse_decimal(&res.optheta)?,
+            oprho: parse_decimal(&res.oprho)?,
+            opcdratio: parse_decimal(&res.opcdratio)?,
+            opdivideratio: parse_decimal(&res.opdivideratio)?,
+            opchetime: parse_string(&res.opchetime)?,
+            opoffer: parse_scaled(&res.opoffer, 2)?,
+            opjoffer: parse_scaled(&res.opjoffer, 2)?,
+            opjjoffer: parse_scaled(&res.opjjoffer, 2)?,
+            opj4offer: parse_scaled(&res.opj4offer, 2)?,
+            opj5offer: parse_scaled(&res.opj5offer, 2)?,
+            opbid: parse_scaled(&res.opbid, 2)?,
+            opjbid: parse_scaled(&res.opjbid, 2)?,
+            opjjbid: parse_scaled(&res.opjjbid, 2)?,
+            opj4bid: parse_scaled(&res.opj4bid, 2)?,
+            opj5bid: parse_scaled(&res.opj5bid, 2)?,
+            opofferjan: parse_number(&res.opofferjan)?,
+            opjofferjan: parse_number(&res.opjofferjan)?,
+            opjjofferjan: parse_number(&res.opjjofferjan)?,
+            opj4offerjan: parse_number(&res.opj4offerjan)?,
+            opj5offerjan: parse_number(&res.opj5offerjan)?,
+            opbidjan: parse_number(&res.opbidjan)?,
+            opjbidjan: parse_number(&res.opjbidjan)?,
+            opjjbidjan: parse_number(&res.opjjbidjan)?,
+            opj4bidjan: parse_number(&res.opj4bidjan)?,
+            opj5bidjan: parse_number(&res.opj5bidjan)?,
+            optofferjan: parse_number(&res.optofferjan)?,
+            optbidjan: parse_number(&res.optbidjan)?,
+            opoffersu: parse_number(&res.opoffersu)?,
+            opjoffersu: parse_number(&res.opjoffersu)?,
+            opjjoffersu: parse_number(&res.opjjoffersu)?,
+            opj4offersu: parse_number(&res.opj4offersu)?,
+            opj5offersu: parse_number(&res.opj5offersu)?,
+            opbidsu: parse_number(&res.opbidsu)?,
+            opjbidsu: parse_number(&res.opjbidsu)?,
+            opjjbidsu: parse_number(&res.opjjbidsu)?,
+            opj4bidsu: parse_number(&res.opj4bidsu)?,
+            opj5bidsu: parse_number(&res.opj5bidsu)?,
+            optoffersu: parse_number(&res.optoffersu)?,
+            optbidsu: parse_number(&res.optbidsu)?,
+            opjgubun: parse_string(&res.opjgubun)?,
+            opopensign: parse_sign(&res.opopensign)?,
+            opopenchange: parse_scaled(&res.opopenchange, 2)?,
+            opgratio: parse_scaled(&res.opgratio, 2)?,
+            preclose: parse_scaled(&res.preclose, 2)?,
+            fudynhprice: parse_scaled(&res.fudynhprice, 2)?,
+            fudynlprice: parse_scaled(&res.fudynlprice, 2)?,
+            fudynpriceflag: parse_string(&res.fudynpriceflag)?,
+            opbulkvol: parse_number(&res.opbulkvol)?,
+            uplmtgb: parse_string(&res.uplmtgb)?,
+            dnlmtgb: parse_string(&res.dnlmtgb)?,
+            baseprice: parse_scaled(&res.baseprice, 2)?,
+        }))
+    }
+}
+
+pub fn parse_s4201_response1(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Ts4201OutBlock1));
+        Ok(json!(S4201Response1 {
+            fuitem: parse_string(&res.fuitem)?,
+            fucurr: parse_scaled(&res.fucurr, 2)?,
+            fusign: parse_sign(&res.fusign)?,
+            fuchange: parse_scaled(&res.fuchange, 2)?,
+            fuchrate: parse_scaled(&res.fuchrate, 2)?,
+        }))
+    }
+}
+
+pub fn parse_s4201_response2_array(
+    block_data: *const c_char,
+    block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let block_count = block_len as usize / size_of::<Ts4201OutBlock2>();
+        let res: &[Ts4201OutBlock2] =
+            core::slice::from_raw_parts(block_data as *const Ts4201OutBlock2, block_count);
+
+        let ret: Result<Vec<S4201Response2>, QvOpenApiError> =
+            res.iter().map(parse_s4201_response2).collect();
+        Ok(json!(ret?))
+    }
+}
+
+fn parse_s4201_response2(res: &Ts4201OutBlock2) -> Result<S4201Response2, QvOpenApiError> {
+    Ok(S4201Response2 {
+        opchetime: parse_string(&res.opchetime)?,
+        opcurr: parse_scaled(&res.opcurr, 2)?,
+        opsign: parse_sign(&res.opsign)?,
+        opchange: parse_scaled(&res.opchange, 2)?,
+        opoffer: parse_scaled(&res.opoffer, 2)?,
+        opbid: parse_scaled(&res.opbid, 2)?,
+        opvol: parse_number(&res.opvol)?,
+        opvolallz8: parse_number(&res.opvolallz8)?,
+        opopenyak: parse_number(&res.opopenyak)?,
+    })
+}
+
+pub fn parse_s4201_response3(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Ts4201OutBlock3));
+        Ok(json!(S4201Response3 {
+            fuitem: parse_string(&res.fuitem)?,
+            fuitemz9: parse_string(&res.fuitemz9)?,
+            fuhname: parse_string(&res.fuhname)?,
+            fucurr: parse_scaled(&res.fucurr, 2)?,
+            fusign: parse_sign(&res.fusign)?,
+            fuchange: parse_scaled(&res.fuchange, 2)?,
+            fuchrate: parse_scaled(&res.fuchrate, 2)?,
+            fuvolall: parse_number(&res.fuvolall)?,
+            fuvalall: parse_number(&res.fuvalall)?,
+            fuchetime: parse_string(&res.fuchetime)?,
+            fuoffer: parse_scaled(&res.fuoffer, 2)?,
+            fujoffer: parse_scaled(&res.fujoffer, 2)?,
+            fujjoffer: parse_scaled(&res.fujjoffer, 2)?,
+            fuj4offer: parse_scaled(&res.fuj4offer, 2)?,
+            fuj5offer: parse_scaled(&res.fuj5offer, 2)?,
+            fubid: parse_scaled(&res.fubid, 2)?,
+            fujbid: parse_scaled(&res.fujbid, 2)?,
+            fujjbid: parse_scaled(&res.fujjbid, 2)?,
+            fuj4bid: parse_scaled(&res.fuj4bid, 2)?,
+            fuj5bid: parse_scaled(&res.fuj5bid, 2)?,
+            fuofferjan: parse_number(&res.fuofferjan)?,
+            fujofferjan: parse_number(&res.fujofferjan)?,
+            fujjofferjan: parse_number(&res.fujjofferjan)?,
+            fuj4offerjan: parse_number(&res.fuj4offerjan)?,
+            fuj5offerjan: parse_number(&res.fuj5offerjan)?,
+            fubidjan: parse_number(&res.fubidjan)?,
+            fujbidjan: parse_number(&res.fujbidjan)?,
+            fujjbidjan: parse_number(&res.fujjbidjan)?,
+            fuj4bidjan: parse_number(&res.fuj4bidjan)?,
+            fuj5bidjan: parse_number(&res.fuj5bidjan)?,
+            futofferjan: parse_number(&res.futofferjan)?,
+            futbidjan: parse_number(&res.futbidjan)?,
+            fuoffersu: parse_number(&res.fuoffersu)?,
+            fujoffersu: parse_number(&res.fujoffersu)?,
+            fujjoffersu: parse_number(&res.fujjoffersu)?,
+            fuj4offersu: parse_number(&res.fuj4offersu)?,
+            fuj5offersu: parse_number(&res.fuj5offersu)?,
+            fubidsu: parse_number(&res.fubidsu)?,
+            fujbidsu: parse_number(&res.fujbidsu)?,
+            fujjbidsu: parse_number(&res.fujjbidsu)?,
+            fuj4bidsu: parse_number(&res.fuj4bidsu)?,
+            fuj5bidsu: parse_number(&res.fuj5bidsu)?,
+            futoffersu: parse_number(&res.futoffersu)?,
+            futbidsu: parse_number(&res.futbidsu)?,
+            fuhprice: parse_scaled(&res.fuhprice, 2)?,
+            fuhigh: parse_scaled(&res.fuhigh, 2)?,
+            fuopen: parse_scaled(&res.fuopen, 2)?,
+            fuopensign: parse_sign(&res.fuopensign)?,
+            fuopenchange: parse_scaled(&res.fuopenchange, 2)?,
+            fulow: parse_scaled(&res.fulow, 2)?,
+            fulprice: parse_scaled(&res.fulprice, 2)?,
+            fupivot2upz5: parse_scaled(&res.fupivot2upz5, 2)?,
+            fupivot1upz5: parse_scaled(&res.fupivot1upz5, 2)?,
+            fupivotz5: parse_scaled(&res.fupivotz5, 2)?,
+            fupivot1dnz5: parse_scaled(&res.fupivot1dnz5, 2)?,
+            fupivot2dnz5: parse_scaled(&res.fupivot2dnz5, 2)?,
+            fudynhprice: parse_scaled(&res.fudynhprice, 2)?,
+            fudynlprice: parse_scaled(&res.fudynlprice, 2)?,
+            fudynpriceflag: parse_string(&res.fudynpriceflag)?,
+        }))
+    }
+}
+
+pub fn parse_s4201_response4_array(
+    block_data: *const c_char,
+    block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let block_count = block_len as usize / size_of::<Ts4201OutBlock4>();
+        let res: &[Ts4201OutBlock4] =
+            core::slice::from_raw_parts(block_data as *const Ts4201OutBlock4, block_count);
+
+        let ret: Result<Vec<S4201Response4>, QvOpenApiError> =
+            res.iter().map(parse_s4201_response4).collect();
+        Ok(json!(ret?))
+    }
+}
+
+fn parse_s4201_response4(res: &Ts4201OutBlock4) -> Result<S4201Response4, QvOpenApiError> {
+    Ok(S4201Response4 {
+        fuchetime: parse_string(&res.fuchetime)?,
+        fucurr: parse_scaled(&res.fucurr, 2)?,
+        fusign: parse_sign(&res.fusign)?,
+        fuchange: parse_scaled(&res.fuchange, 2)?,
+        fuoffer: parse_scaled(&res.fuoffer, 2)?,
+        fubid: parse_scaled(&res.fubid, 2)?,
+        fuvol: parse_number(&res.fuvol)?,
+        fuvolall: parse_number(&res.fuvolall)?,
+        fuopenyak: parse_number(&res.fuopenyak)?,
+    })
+}
+
+pub fn parse_s4201_response5(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Ts4201OutBlock5));
+        Ok(json!(S4201Response5 {
+            dongsi: parse_string(&res.dongsi)?,
+            jeqprice: parse_scaled(&res.jeqprice, 2)?,
+            jeqsign: parse_sign(&res.jeqsign)?,
+            jeqchange: parse_scaled(&res.jeqchange, 2)?,
+            jeqchrate: parse_scaled(&res.jeqchrate, 2)?,
+        }))
+    }
+}
+
+pub fn parse_s4201_response6(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Ts4201OutBlock6));
+        Ok(json!(S4201Response6 {
+            dongsi: parse_string(&res.dongsi)?,
+            jeqprice: parse_scaled(&res.jeqprice, 2)?,
+            jeqsign: parse_sign(&res.jeqsign)?,
+            jeqchange: parse_scaled(&res.jeqchange, 2)?,
+            jeqchrate: parse_scaled(&res.jeqchrate, 2)?,
+        }))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S4201Response {
+    pub opitem: String,                //종목코드
+    pub ophname: String,               //종목명
+    pub opcurr: Option<f64>,           //현재가
+    pub opsign: Option<PriceSign>,     //등락부호
+    pub opchange: Option<f64>,         //등락폭
+    pub opchrate: Option<f64>,         //등락률
+    pub opopen: Option<f64>,           //시가
+    pub ophigh: Option<f64>,           //고가
+    pub oplow: Option<f64>,            //저가
+    pub optheoryprice: Option<f64>,    //이론가
+    pub opvolallz8: Option<i64>,       //거래량
+    pub opvalall: Option<i64>,         //누적거래대금(백만)
+    pub opopenyak: Option<i64>,        //미결제약정수량
+    pub oppreopenyak: Option<i64>,     //미결제약정전일
+    pub oplisthdatez11: String,        //상장후최고일
+    pub oplistldatez11: String,        //상장후최저일
+    pub oplistdate: String,            //거래개시일
+    pub oplastdate: String,            //최종거래일
+    pub opjandatecnt: Option<i64>,     //잔존일
+    pub ophprice: Option<f64>,         //상한가
+    pub oplprice: Option<f64>,         //하한가
+    pub opgrate: Option<f64>,          //괴리도
+    pub opimpv: Option<f64>,           //내재변동성
+    pub oppastv90: Option<f64>,        //과거변동성90
+    pub opdelta: Option<f64>,          //델타지수
+    pub opgmma: Option<f64>,           //감마지수
+    pub opvega: Option<f64>,           //베가변동성
+    pub optheta: Option<f64>,          //쎄타시간
+    pub oprho: Option<f64>,            //로이자율
+    pub opcdratio: Option<f64>,        //이자율
+    pub opdivideratio: Option<f64>,    //배당액지수
+    pub opchetime: String,             //호가시간
+    pub opoffer: Option<f64>,          //매도최우선호가
+    pub opjoffer: Option<f64>,         //매도차선호가
+    pub opjjoffer: Option<f64>,        //매도차차선호가
+    pub opj4offer: Option<f64>,        //매도4차선호가
+    pub opj5offer: Option<f64>,        //매도5차선호가
+    pub opbid: Option<f64>,            //매수최우선호가
+    pub opjbid: Option<f64>,           //매수차선호가
+    pub opjjbid: Option<f64>,          //매수차차선호가
+    pub opj4bid: Option<f64>,          //매수4차선호가
+    pub opj5bid: Option<f64>,          //매수5차선호가
+    pub opofferjan: Option<i64>,       //매도최우선잔량
+    pub opjofferjan: Option<i64>,      //매도차선잔량
+    pub opjjofferjan: Option<i64>,     //매도차차선잔량
+    pub opj4offerjan: Option<i64>,     //매도4차선잔량
+    pub opj5offerjan: Option<i64>,     //매도5차선잔량
+    pub opbidjan: Option<i64>,         //매수최우선잔량
+    pub opjbidjan: Option<i64>,        //매수차선잔량
+    pub opjjbidjan: Option<i64>,       //매수차차선잔량
+    pub opj4bidjan: Option<i64>,       //매수4차선잔량
+    pub opj5bidjan: Option<i64>,       //매수5차선잔량
+    pub optofferjan: Option<i64>,      //총매도잔량
+    pub optbidjan: Option<i64>,        //총매수잔량
+    pub opoffersu: Option<i64>,        //매도최우선건수
+    pub opjoffersu: Option<i64>,       //매도차선건수
+    pub opjjoffersu: Option<i64>,      //매도차차선건수
+    pub opj4offersu: Option<i64>,      //매도4차선건수
+    pub opj5offersu: Option<i64>,      //매도5차선건수
+    pub opbidsu: Option<i64>,          //매수최우선건수
+    pub opjbidsu: Option<i64>,         //매수차선건수
+    pub opjjbidsu: Option<i64>,        //매수차차선건수
+    pub opj4bidsu: Option<i64>,        //매수4차선건수
+    pub opj5bidsu: Option<i64>,        //매수5차선건수
+    pub optoffersu: Option<i64>,       //총매도건수
+    pub optbidsu: Option<i64>,         //총매수건수
+    pub opjgubun: String,              //CB발동여부
+    pub opopensign: Option<PriceSign>, //시가대비부호
+    pub opopenchange: Option<f64>,     //시가대비등락
+    pub opgratio: Option<f64>,         //괴리율
+    pub preclose: Option<f64>,         //전일종가
+    pub fudynhprice: Option<f64>,      //실시간상한가
+    pub fudynlprice: Option<f64>,      //실시간하한가
+    pub fudynpriceflag: String,        //동적가격제한여부
+    pub opbulkvol: Option<i64>,        //협의거래량
+    pub uplmtgb: String,               //가격제한확대상한단계
+    pub dnlmtgb: String,               //가격제한확대하한단계
+    pub baseprice: Option<f64>,        //기준가
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S4201Response1 {
+    pub fuitem: String,            //코스피200코드
+    pub fucurr: Option<f64>,       //코스피200지수
+    pub fusign: Option<PriceSign>, //코스피200등락부호
+    pub fuchange: Option<f64>,     //코스피200등락폭
+    pub fuchrate: Option<f64>,     //코스피200등락률
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S4201Response2 {
+    pub opchetime: String,         //시간
+    pub opcurr: Option<f64>,       //현재가
+    pub opsign: Option<PriceSign>, //등락부호
+    pub opchange: Option<f64>,     //등락폭
+    pub opoffer: Option<f64>,      //매도호가
+    pub opbid: Option<f64>,        //매수호가
+    pub opvol: Option<i64>,        //거래량
+    pub opvolallz8: Option<i64>,   //누적거래량
+    pub opopenyak: Option<i64>,    //미결제약정
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S4201Response3 {
+    pub fuitem: String,                //선물최근월물코드
+    pub fuitemz9: String,              //선물최근월물확장코드
+    pub fuhname: String,               //선물최근월물명
+    pub fucurr: Option<f64>,           //선물최근월물지수
+    pub fusign: Option<PriceSign>,     //선물최근월물등락부호
+    pub fuchange: Option<f64>,         //선물최근월물등락폭
+    pub fuchrate: Option<f64>,         //선물최근월물등락률
+    pub fuvolall: Option<i64>,         //선물최근월물거래량
+    pub fuvalall: Option<i64>,         //선물최근월물누적거래대금(백만)
+    pub fuchetime: String,             //선물최근월물호가시간
+    pub fuoffer: Option<f64>,          //선물최근월물매도최우선호가
+    pub fujoffer: Option<f64>,         //선물최근월물매도차선호가
+    pub fujjoffer: Option<f64>,        //선물최근월물매도차차선호가
+    pub fuj4offer: Option<f64>,        //선물최근월물매도4차선호가
+    pub fuj5offer: Option<f64>,        //선물최근월물매도5차선호가
+    pub fubid: Option<f64>,            //선물최근월물매수최우선호가
+    pub fujbid: Option<f64>,           //선물최근월물매수차선호가
+    pub fujjbid: Option<f64>,          //선물최근월물매수차차선호가
+    pub fuj4bid: Option<f64>,          //선물최근월물매수4차선호가
+    pub fuj5bid: Option<f64>,          //선물최근월물매수5차선호가
+    pub fuofferjan: Option<i64>,       //선물최근월물매도최우선잔량
+    pub fujofferjan: Option<i64>,      //선물최근월물매도차선잔량
+    pub fujjofferjan: Option<i64>,     //선물최근월물매도차차선잔량
+    pub fuj4offerjan: Option<i64>,     //선물최근월물매도4차선잔량
+    pub fuj5offerjan: Option<i64>,     //선물최근월물매도5차선잔량
+    pub fubidjan: Option<i64>,         //선물최근월물매수최우선잔량
+    pub fujbidjan: Option<i64>,        //선물최근월물매수차선잔량
+    pub fujjbidjan: Option<i64>,       //선물최근월물매수차차선잔량
+    pub fuj4bidjan: Option<i64>,       //선물최근월물매수4차선잔량
+    pub fuj5bidjan: Option<i64>,       //선물최근월물매수5차선잔량
+    pub futofferjan: Option<i64>,      //선물최근월물총매도잔량
+    pub futbidjan: Option<i64>,        //선물최근월물총매수잔량
+    pub fuoffersu: Option<i64>,        //선물최근월물매도최우선건수
+    pub fujoffersu: Option<i64>,       //선물최근월물매도차선건수
+    pub fujjoffersu: Option<i64>,      //선물최근월물매도차차선건수
+    pub fuj4offersu: Option<i64>,      //선물최근월물매도4차선건수
+    pub fuj5offersu: Option<i64>,      //선물최근월물매도5차선건수
+    pub fubidsu: Option<i64>,          //선물최근월물매수최우선건수
+    pub fujbidsu: Option<i64>,         //선물최근월물매수차선건수
+    pub fujjbidsu: Option<i64>,        //선물최근월물매수차차선건수
+    pub fuj4bidsu: Option<i64>,        //선물최근월물매수4차선건수
+    pub fuj5bidsu: Option<i64>,        //선물최근월물매수5차선건수
+    pub futoffersu: Option<i64>,       //선물최근월물총매도건수
+    pub futbidsu: Option<i64>,         //선물최근월물총매수건수
+    pub fuhprice: Option<f64>,         //상한가
+    pub fuhigh: Option<f64>,           //고가
+    pub fuopen: Option<f64>,           //시가
+    pub fuopensign: Option<PriceSign>, //시가대비부호
+    pub fuopenchange: Option<f64>,     //시가대비등락
+    pub fulow: Option<f64>,            //저가
+    pub fulprice: Option<f64>,         //하한가
+    pub fupivot2upz5: Option<f64>,     //피봇2차저항
+    pub fupivot1upz5: Option<f64>,     //피봇1차저항
+    pub fupivotz5: Option<f64>,        //피봇가
+    pub fupivot1dnz5: Option<f64>,     //피봇1차지지
+    pub fupivot2dnz5: Option<f64>,     //피봇2차지지
+    pub fudynhprice: Option<f64>,      //실시간상한가
+    pub fudynlprice: Option<f64>,      //실시간하한가
+    pub fudynpriceflag: String,        //동적가격제한여부
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S4201Response4 {
+    pub fuchetime: String,         //시간
+    pub fucurr: Option<f64>,       //현재가
+    pub fusign: Option<PriceSign>, //등락부호
+    pub fuchange: Option<f64>,     //등락폭
+    pub fuoffer: Option<f64>,      //매도호가
+    pub fubid: Option<f64>,        //매수호가
+    pub fuvol: Option<i64>,        //거래량
+    pub fuvolall: Option<i64>,     //누적거래량
+    pub fuopenyak: Option<i64>,    //미결제약정
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S4201Response5 {
+    pub dongsi: String,             //동시호가구분
+    pub jeqprice: Option<f64>,      //예상체결가
+    pub jeqsign: Option<PriceSign>, //예상체결부호
+    pub jeqchange: Option<f64>,     //예상체결등락폭
+    pub jeqchrate: Option<f64>,     //예상체결등락률
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S4201Response6 {
+    pub dongsi: String,             //동시호가구분
+    pub jeqprice: Option<f64>,      //예상체결가
+    pub jeqsign: Option<PriceSign>, //예상체결부호
+    pub jeqchange: Option<f64>,     //예상체결등락폭
+    pub jeqchrate: Option<f64>,     //예상체결등락률
+}
+
+pub const BLOCK_NAME_S4201_OUT: &str = "s4201OutBlock";
+pub const BLOCK_NAME_S4201_OUT1: &str = "s4201OutBlock1";
+pub const BLOCK_NAME_S4201_OUT2_ARRAY: &str = "s4201OutBlock2";
+pub const BLOCK_NAME_S4201_OUT3: &str = "s4201OutBlock3";
+pub const BLOCK_NAME_S4201_OUT4_ARRAY: &str = "s4201OutBlock4";
+pub const BLOCK_NAME_S4201_OUT5: &str = "s4201OutBlock5";
+pub const BLOCK_NAME_S4201_OUT6: &str = "s4201OutBlock6";
@@ -0,0 +1,265 @@
+use std::ffi::c_char;
+use std::mem::size_of;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::utils::{from_cp949, parse_decimal, parse_number, parse_string, to_fixed_string};
+use crate::{error::*, models::*, QvOpenApiRequest};
+use qvopenapi_bindings::{Ts8120InBlock, Ts8120OutBlock, Ts8120OutBlock1, Ts8120OutBlock_IN};
+
+pub const TR_CODE_S8120: &str = "s8120";
+
+/**
+ * 주문/체결 내역 조회
+ * 다음 페이지는 s8120OutBlock_IN의 CTS를 넣어 with_next()로 다시 조회
+ */
+#[derive(Debug, Clone, Deserialize)]
+pub struct S8120Request {
+    pub account_index: i32,
+    pub inq_gubun: char,         //조회주체구분 (3:계좌별조회)
+    pub group_no: String,        //그룹번호
+    pub mkt_slct: char,          //시장구분 (0:전체 4:선물옵션 7:주식옵션현물 등)
+    pub order_date: String,      //주문일자 (YYYYMMDD)
+    pub issue_code: String,      //종목번호
+    pub comm_order_type: String, //매체구분 (CC:전체 AA:영업 BB:온라인)
+    pub conc_gubun: char,        //체결구분 (0:전체 1:미체결 2:체결)
+    pub inq_seq_gubun: char,     //조회순서 (0:번호 1:모주문번호)
+    pub sort_gubun: char,        //정렬구분 (0:주문번호순 1:주문번호 역순)
+    pub sell_buy_type: char,     //매수도구분 (1:매도 2:매수 3:전매 4:환매)
+    pub mrgn_type: char,         //신용구분 (0:보통 1:신용 2:대출)
+    pub accnt_admin_type: char,  //계좌구분 (0:전체)
+    pub order_no: String,        //주문번호
+    pub cts: String,             //CTS (연속조회 키)
+    pub is_page_up: bool,        //ISPAGEUP (다음 페이지 조회)
+}
+
+impl S8120Request {
+    pub fn new(
+        account_index: i32,
+        inq_gubun: char,
+        group_no: &str,
+        mkt_slct: char,
+        order_date: &str,
+    ) -> S8120Request {
+        S8120Request {
+            account_index,
+            inq_gubun,
+            group_no: group_no.into(),
+            mkt_slct,
+            order_date: order_date.into(),
+            issue_code: "".into(),
+            comm_order_type: "CC".into(),
+            conc_gubun: '0',
+            inq_seq_gubun: '0',
+            sort_gubun: '0',
+            sell_buy_type: ' ',
+            mrgn_type: ' ',
+            accnt_admin_type: '0',
+            order_no: "".into(),
+            cts: "".into(),
+            is_page_up: false,
+        }
+    }
+
+    pub fn with_next(&self, cts: &str) -> S8120Request {
+        S8120Request {
+            cts: cts.into(),
+            is_page_up: true,
+            ..self.clone()
+        }
+    }
+
+    pub fn into_raw(&self) -> Result<Arc<RawQueryRequest<Ts8120InBlock>>, QvOpenApiError> {
+        Ok(Arc::new(RawQueryRequest::new(
+            TR_CODE_S8120,
+            self.account_index,
+            Box::new(Ts8120InBlock {
+                inq_gubunz1: [self.inq_gubun as c_char],
+                _inq_gubunz1: ' ' as c_char,
+                pswd_noz8: [' ' as c_char; 44],
+                _pswd_noz8: ' ' as c_char,
+                group_noz4: to_fixed_string(&self.group_no)?,
+                _group_noz4: ' ' as c_char,
+                mkt_slctz1: [self.mkt_slct as c_char],
+                _mkt_slctz1: ' ' as c_char,
+                order_datez8: to_fixed_string(&self.order_date)?,
+                _order_datez8: ' ' as c_char,
+                issue_codez12: to_fixed_string(&self.issue_code)?,
+                _issue_codez12: ' ' as c_char,
+                comm_order_typez2: to_fixed_string(&self.comm_order_type)?,
+                _comm_order_typez2: ' ' as c_char,
+                conc_gubunz1: [self.conc_gubun as c_char],
+                _conc_gubunz1: ' ' as c_char,
+                inq_seq_gubunz1: [self.inq_seq_gubun as c_char],
+                _inq_seq_gubunz1: ' ' as c_char,
+                sort_gubunz1: [self.sort_gubun as c_char],
+                _sort_gubunz1: ' ' as c_char,
+                sell_buy_typez1: [self.sell_buy_type as c_char],
+                _sell_buy_typez1: ' ' as c_char,
+                mrgn_typez1: [self.mrgn_type as c_char],
+                _mrgn_typez1: ' ' as c_char,
+                accnt_admin_typez1: [self.accnt_admin_type as c_char],
+                _accnt_admin_typez1: ' ' as c_char,
+                order_noz10: to_fixed_string(&self.order_no)?,
+                _order_noz10: ' ' as c_char,
+                ctsz56: to_fixed_string(&self.cts)?,
+                _ctsz56: ' ' as c_char,
+                trad_pswd1z8: [' ' as c_char; 44],
+                _trad_pswd1z8: ' ' as c_char,
+                trad_pswd2z8: [' ' as c_char; 44],
+                _trad_pswd2z8: ' ' as c_char,
+                IsPageUp: [if self.is_page_up { NEXT_PAGE_FLAG } else { ' ' } as c_char],
+                _IsPageUp: ' ' as c_char,
+            }),
+        )))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S8120Output {
+    #[serde(rename = "s8120OutBlock")]
+    pub summary: S8120Response, //화면출력
+    #[serde(rename = "s8120OutBlock1", default)]
+    pub orders: Vec<S8120Response1>, //주문/체결 내역
+    #[serde(rename = "s8120OutBlock_IN", default)]
+    pub next: Option<S8120ResponseIn>, //다음 페이지 정보
+}
+
+impl TypedQueryRequest for S8120Request {
+    type Output = S8120Output;
+
+    fn to_query(&self) -> Result<Arc<dyn QvOpenApiRequest>, QvOpenApiError> {
+        Ok(self.into_raw()?)
+    }
+}
+
+pub fn parse_s8120_response(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Ts8120OutBlock));
+        Ok(json!(S8120Response {
+            emp_kor_namez20: parse_string(&res.emp_kor_namez20)?,
+            brch_namez30: parse_string(&res.brch_namez30)?,
+            buy_conc_qtyz14: parse_number(&res.buy_conc_qtyz14)?,
+            buy_conc_amtz19: parse_number(&res.buy_conc_amtz19)?,
+            sell_conc_qtyz14: parse_number(&res.sell_conc_qtyz14)?,
+            sell_conc_amtz19: parse_number(&res.sell_conc_amtz19)?,
+        }))
+    }
+}
+
+pub fn parse_s8120_response1_array(
+    block_data: *const c_char,
+    block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let block_count = block_len as usize / size_of::<Ts8120OutBlock1>();
+        let res: &[Ts8120OutBlock1] =
+            core::slice::from_raw_parts(block_data as *const Ts8120OutBlock1, block_count);
+
+        let ret: Result<Vec<S8120Response1>, QvOpenApiError> =
+            res.iter().map(parse_s8120_response1).collect();
+        Ok(json!(ret?))
+    }
+}
+
+fn parse_s8120_response1(res: &Ts8120OutBlock1) -> Result<S8120Response1, QvOpenApiError> {
+    Ok(S8120Response1 {
+        order_datez8: parse_string(&res.order_datez8)?,
+        order_noz10: parse_number(&res.order_noz10)?,
+        orgnl_order_noz10: parse_number(&res.orgnl_order_noz10)?,
+        accnt_noz11: parse_string(&res.accnt_noz11)?,
+        accnt_namez20: parse_string(&res.accnt_namez20)?,
+        order_kindz20: parse_string(&res.order_kindz20)?,
+        trd_gubun_noz1: parse_string(&res.trd_gubun_noz1)?,
+        trd_gubunz20: parse_string(&res.trd_gubunz20)?,
+        trade_type_noz1: parse_string(&res.trade_type_noz1)?,
+        trade_type1z20: parse_string(&res.trade_type1z20)?,
+        issue_codez12: parse_string(&res.issue_codez12)?,
+        issue_namez40: parse_string(&res.issue_namez40)?,
+        order_qtyz10: parse_number(&res.order_qtyz10)?,
+        conc_qtyz10: parse_number(&res.conc_qtyz10)?,
+        order_unit_pricez12: parse_decimal(&res.order_unit_pricez12)?,
+        conc_unit_pricez12: parse_decimal(&res.conc_unit_pricez12)?,
+        crctn_canc_qtyz10: parse_number(&res.crctn_canc_qtyz10)?,
+        cfirm_qtyz10: parse_number(&res.cfirm_qtyz10)?,
+        media_namez12: parse_string(&res.media_namez12)?,
+        proc_emp_noz5: parse_string(&res.proc_emp_noz5)?,
+        proc_timez8: parse_string(&res.proc_timez8)?,
+        proc_termz8: parse_string(&res.proc_termz8)?,
+        proc_typez12: parse_string(&res.proc_typez12)?,
+        rejec_codez5: parse_string(&res.rejec_codez5)?,
+        avail_qtyz10: parse_number(&res.avail_qtyz10)?,
+        mkt_typez1: parse_string(&res.mkt_typez1)?,
+        shsll_typez20: parse_string(&res.shsll_typez20)?,
+    })
+}
+
+pub fn parse_s8120_response_in(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Ts8120OutBlock_IN));
+        Ok(json!(S8120ResponseIn {
+            // CTS는 그대로 다시 입력해야 하므로 trim하지 않음
+            ctsz56: from_cp949(&res.ctsz56),
+            nextbutton: parse_string(&res.nextbutton)?,
+        }))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S8120Response {
+    pub emp_kor_namez20: String,       //한글사원성명
+    pub brch_namez30: String,          //한글지점명
+    pub buy_conc_qtyz14: Option<i64>,  //매수체결수량
+    pub buy_conc_amtz19: Option<i64>,  //매수체결금액
+    pub sell_conc_qtyz14: Option<i64>, //매도체결수량
+    pub sell_conc_amtz19: Option<i64>, //매도체결금액
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S8120Response1 {
+    pub order_datez8: String,             //주문일자
+    pub order_noz10: Option<i64>,         //주문번호
+    pub orgnl_order_noz10: Option<i64>,   //원주문번호
+    pub accnt_noz11: String,              //계좌번호
+    pub accnt_namez20: String,            //계좌명
+    pub order_kindz20: String,            //주문구분
+    pub trd_gubun_noz1: String,           //매매구분번호
+    pub trd_gubunz20: String,             //매매구분
+    pub trade_type_noz1: String,          //거래구분번호
+    pub trade_type1z20: String,           //거래구분
+    pub issue_codez12: String,            //종목번호
+    pub issue_namez40: String,            //종목명
+    pub order_qtyz10: Option<i64>,        //주문수량
+    pub conc_qtyz10: Option<i64>,         //체결수량
+    pub order_unit_pricez12: Option<f64>, //주문단가
+    pub conc_unit_pricez12: Option<f64>,  //체결평균단가
+    pub crctn_canc_qtyz10: Option<i64>,   //정정취소수량
+    pub cfirm_qtyz10: Option<i64>,        //확인수량
+    pub media_namez12: String,            //매체구분
+    pub proc_emp_noz5: String,            //처리사번
+    pub proc_timez8: String,              //처리시간
+    pub proc_termz8: String,              //처리단말
+    pub proc_typez12: String,             //처리구분
+    pub rejec_codez5: String,             //거부코드
+    pub avail_qtyz10: Option<i64>,        //정취가능수량
+    pub mkt_typez1: String,               //시장구분
+    pub shsll_typez20: String,            //공매도구분
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S8120ResponseIn {
+    pub ctsz56: String,     //CTS
+    pub nextbutton: String, //NEXTBUTTON
+}
+
+pub const BLOCK_NAME_S8120_OUT: &str = "s8120OutBlock";
+pub const BLOCK_NAME_S8120_OUT1_ARRAY: &str = "s8120OutBlock1";
+pub const BLOCK_NAME_S8120_OUT_IN: &str = "s8120OutBlock_IN";
@@ -0,0 +1,158 @@
+use std::ffi::c_char;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::utils::{
+    assert_code, assert_non_negative, assert_positive, parse_decimal, parse_number,
+    to_fixed_decimal, to_fixed_number, to_fixed_string,
+};
+use crate::{error::*, models::*, QvOpenApiRequest};
+use qvopenapi_bindings::{Ts8301InBlock, Ts8301OutBlock};
+
+pub const TR_CODE_S8301: &str = "s8301";
+
+// 1:매도 2:매수
+pub(crate) const FO_SLBUY_TYPE_CODES: &str = "12";
+// 0:지정가 3:시장가 5:조건부 6:최유리 A:IOC B:FOK
+pub(crate) const FO_ORD_TYPE_CODES: &str = "0356AB";
+// 주문가격을 지정하는 주문유형. 3:시장가 6:최유리는 주문가격 0으로 주문
+const FO_PRICED_ORD_TYPE_CODES: &str = "05AB";
+// 0:보통
+pub(crate) const FO_TRADE_TYPE_CODES: &str = "0";
+
+/**
+ * 선물/옵션 매도, 매수 주문
+ */
+#[derive(Debug, Clone, Deserialize)]
+pub struct S8301Request {
+    pub account_index: i32,
+    pub slbuy_type: char,   //매수매도유형
+    pub issue_code: String, //종목코드
+    pub ord_type: char,     //주문유형
+    pub trade_type: char,   //거래유형
+    pub order_qty: i64,     //주문수량
+    pub order_price: f64,   //주문가격
+}
+
+impl S8301Request {
+    pub fn new(
+        account_index: i32,
+        slbuy_type: char,
+        issue_code: &str,
+        ord_type: char,
+        trade_type: char,
+        order_qty: i64,
+        order_price: f64,
+    ) -> S8301Request {
+        S8301Request {
+            account_index,
+            slbuy_type,
+            issue_code: issue_code.into(),
+            ord_type,
+            trade_type,
+            order_qty,
+            order_price,
+        }
+    }
+
+    pub fn into_raw(&self) -> Result<Arc<RawQueryRequest<Ts8301InBlock>>, QvOpenApiError> {
+        assert_code("slbuy_type", self.slbuy_type, FO_SLBUY_TYPE_CODES)?;
+        assert_code("ord_type", self.ord_type, FO_ORD_TYPE_CODES)?;
+        assert_code("trade_type", self.trade_type, FO_TRADE_TYPE_CODES)?;
+        assert_positive("order_qty", self.order_qty)?;
+        assert_fo_order_price(&self.issue_code, self.ord_type, self.order_price)?;
+
+        Ok(Arc::new(RawQueryRequest::new(
+            TR_CODE_S8301,
+            self.account_index,
+            Box::new(Ts8301InBlock {
+                slbuy_typez1: [self.slbuy_type as c_char],
+                _slbuy_typez1: ' ' as c_char,
+                passwd_noz8: [' ' as c_char; 44],
+                _passwd_noz8: ' ' as c_char,
+                issue_codez9: to_fixed_string(&self.issue_code)?,
+                _issue_codez9: ' ' as c_char,
+                ord_typez1: [self.ord_type as c_char],
+                _ord_typez1: ' ' as c_char,
+                trade_typez1: [self.trade_type as c_char],
+                _trade_typez1: ' ' as c_char,
+                order_qtyz8: to_fixed_number(self.order_qty)?,
+                _order_qtyz8: ' ' as c_char,
+                order_pricez12: to_fixed_decimal(self.order_price, 2)?,
+                _order_pricez12: ' ' as c_char,
+                trad_pswd_no_1z8: [' ' as c_char; 44],
+                _trad_pswd_no_1z8: ' ' as c_char,
+                trad_pswd_no_2z8: [' ' as c_char; 44],
+                _trad_pswd_no_2z8: ' ' as c_char,
+            }),
+        )))
+    }
+}
+
+/**
+ * 주문가격이 주문유형에 맞는지 확인
+ * 지정가 계열은 0보다 커야 하고, 시장가 계열은 0을 허용
+ * 스프레드(종목코드 첫 자리 4)는 가격차로 주문하므로 0이나 음수도 허용
+ */
+pub(crate) fn assert_fo_order_price(
+    issue_code: &str,
+    ord_type: char,
+    order_price: f64,
+) -> Result<(), QvOpenApiError> {
+    if !FO_PRICED_ORD_TYPE_CODES.contains(ord_type) {
+        assert_non_negative("order_price", order_price)
+    } else if issue_code.trim_start().starts_with('4') {
+        Ok(())
+    } else {
+        assert_positive("order_price", order_price)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S8301Output {
+    #[serde(rename = "s8301OutBlock")]
+    pub order: S8301Response, //주문 결과
+}
+
+impl TypedQueryRequest for S8301Request {
+    type Output = S8301Output;
+
+    fn to_query(&self) -> Result<Arc<dyn QvOpenApiRequest>, QvOpenApiError> {
+        Ok(self.into_raw()?)
+    }
+}
+
+pub fn parse_s8301_response(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Ts8301OutBlock));
+        Ok(json!(S8301Response {
+            order_qtyz8: parse_number(&res.order_qtyz8)?,
+            order_pricez12: parse_decimal(&res.order_pricez12)?,
+            order_noz10: parse_number(&res.order_noz10)?,
+            ord_pos_csamtz16: parse_number(&res.ord_pos_csamtz16)?,
+            ord_pos_ttamtz16: parse_number(&res.ord_pos_ttamtz16)?,
+            ord_insuf_csamtz16: parse_number(&res.ord_insuf_csamtz16)?,
+            ord_insuf_ttamtz16: parse_number(&res.ord_insuf_ttamtz16)?,
+            ord_pos_qtyz16: parse_number(&res.ord_pos_qtyz16)?,
+        }))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S8301Response {
+    pub order_qtyz8: Option<i64>,        //주문수량
+    pub order_pricez12: Option<f64>,     //주문가격
+    pub order_noz10: Option<i64>,        //주문번호
+    pub ord_pos_csamtz16: Option<i64>,   //주문가능현금
+    pub ord_pos_ttamtz16: Option<i64>,   //주문가능총액
+    pub ord_insuf_csamtz16: Option<i64>, //주문부족현금
+    pub ord_insuf_ttamtz16: Option<i64>, //주문부족총액
+    pub ord_pos_qtyz16: Option<i64>,     //주문가능수량
+}
+
+pub const BLOCK_NAME_S8301_OUT: &str = "s8301OutBlock";
@@ -0,0 +1,156 @@
+use std::ffi::c_char;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::s8301::{assert_fo_order_price, FO_ORD_TYPE_CODES, FO_SLBUY_TYPE_CODES};
+use crate::utils::{
+    assert_code, assert_non_negative, assert_positive, parse_decimal, parse_number,
+    to_fixed_decimal, to_fixed_number, to_fixed_string,
+};
+use crate::{error::*, models::*, QvOpenApiRequest};
+use qvopenapi_bindings::{Ts8302InBlock, Ts8302OutBlock};
+
+pub const TR_CODE_S8302: &str = "s8302";
+
+// 1:정정 2:취소
+const FO_CRCTN_CANC_TYPE_CODES: &str = "12";
+
+/**
+ * 선물/옵션 정정, 취소 주문
+ */
+#[derive(Debug, Clone, Deserialize)]
+pub struct S8302Request {
+    pub account_index: i32,
+    pub crctn_canc_type: char, //정정/취소구분
+    pub slbuy_type: char,      //매수매도유형
+    pub orgnl_order_no: i64,   //원주문번호
+    pub issue_code: String,    //종목코드
+    pub orgnl_ord_type: char,  //원주문유형
+    pub crctn_ord_type: char,  //정정주문유형
+    pub order_qty: i64,        //주문수량
+    pub order_price: f64,      //주문가격
+}
+
+impl S8302Request {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        account_index: i32,
+        crctn_canc_type: char,
+        slbuy_type: char,
+        orgnl_order_no: i64,
+        issue_code: &str,
+        orgnl_ord_type: char,
+        crctn_ord_type: char,
+        order_qty: i64,
+        order_price: f64,
+    ) -> S8302Request {
+        S8302Request {
+            account_index,
+            crctn_canc_type,
+            slbuy_type,
+            orgnl_order_no,
+            issue_code: issue_code.into(),
+            orgnl_ord_type,
+            crctn_ord_type,
+            order_qty,
+            order_price,
+        }
+    }
+
+    pub fn into_raw(&self) -> Result<Arc<RawQueryRequest<Ts8302InBlock>>, QvOpenApiError> {
+        assert_code(
+            "crctn_canc_type",
+            self.crctn_canc_type,
+            FO_CRCTN_CANC_TYPE_CODES,
+        )?;
+        assert_code("slbuy_type", self.slbuy_type, FO_SLBUY_TYPE_CODES)?;
+        assert_code("orgnl_ord_type", self.orgnl_ord_type, FO_ORD_TYPE_CODES)?;
+        assert_code("crctn_ord_type", self.crctn_ord_type, FO_ORD_TYPE_CODES)?;
+        assert_positive("orgnl_order_no", self.orgnl_order_no)?;
+        assert_positive("order_qty", self.order_qty)?;
+        // 취소는 가격을 쓰지 않으므로 0 허용
+        if self.crctn_canc_type == '1' {
+            assert_fo_order_price(&self.issue_code, self.crctn_ord_type, self.order_price)?;
+        } else {
+            assert_non_negative("order_price", self.order_price)?;
+        }
+
+        Ok(Arc::new(RawQueryRequest::new(
+            TR_CODE_S8302,
+            self.account_index,
+            Box::new(Ts8302InBlock {
+                gubunz1: [self.crctn_canc_type as c_char],
+                _gubunz1: ' ' as c_char,
+                slbuy_typez1: [self.slbuy_type as c_char],
+                _slbuy_typez1: ' ' as c_char,
+                pswd_noz8: [' ' as c_char; 44],
+                _pswd_noz8: ' ' as c_char,
+                orgnl_order_noz10: to_fixed_number(self.orgnl_order_no)?,
+                _orgnl_order_noz10: ' ' as c_char,
+                issue_codez9: to_fixed_string(&self.issue_code)?,
+                _issue_codez9: ' ' as c_char,
+                orgnl_ord_typez1: [self.orgnl_ord_type as c_char],
+                _orgnl_ord_typez1: ' ' as c_char,
+                crctn_ord_typez1: [self.crctn_ord_type as c_char],
+                _crctn_ord_typez1: ' ' as c_char,
+                order_qtyz8: to_fixed_number(self.order_qty)?,
+                _order_qtyz8: ' ' as c_char,
+                order_pricez12: to_fixed_decimal(self.order_price, 2)?,
+                _order_pricez12: ' ' as c_char,
+                trad_pswd_no_1z8: [' ' as c_char; 44],
+                _trad_pswd_no_1z8: ' ' as c_char,
+                trad_pswd_no_2z8: [' ' as c_char; 44],
+                _trad_pswd_no_2z8: ' ' as c_char,
+            }),
+        )))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S8302Output {
+    #[serde(rename = "s8302OutBlock")]
+    pub order: S8302Response, //주문 결과
+}
+
+impl TypedQueryRequest for S8302Request {
+    type Output = S8302Output;
+
+    fn to_query(&self) -> Result<Arc<dyn QvOpenApiRequest>, QvOpenApiError> {
+        Ok(self.into_raw()?)
+    }
+}
+
+pub fn parse_s8302_response(
+    block_data: *const c_char,
+    _block_len: i32,
+) -> Result<Value, QvOpenApiError> {
+    unsafe {
+        let res = &(*(block_data as *const Ts8302OutBlock));
+        Ok(json!(S8302Response {
+            order_noz10: parse_number(&res.order_noz10)?,
+            ord_pos_csamtz16: parse_number(&res.ord_pos_csamtz16)?,
+            ord_pos_ttamtz16: parse_number(&res.ord_pos_ttamtz16)?,
+            ord_insuf_csamtz16: parse_number(&res.ord_insuf_csamtz16)?,
+            ord_insuf_ttamtz16: parse_number(&res.ord_insuf_ttamtz16)?,
+            ord_pos_qtyz16: parse_number(&res.ord_pos_qtyz16)?,
+            order_qtyz8: parse_number(&res.order_qtyz8)?,
+            order_pricez12: parse_decimal(&res.order_pricez12)?,
+        }))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S8302Response {
+    pub order_noz10: Option<i64>,        //주문번호
+    pub ord_pos_csamtz16: Option<i64>,   //주문가능현금
+    pub ord_pos_ttamtz16: Option<i64>,   //주문가능총액
+    pub ord_insuf_csamtz16: Option<i64>, //주문부족현금
+    pub ord_insuf_ttamtz16: Option<i64>, //주문부족총액
+    pub ord_pos_qtyz16: Option<i64>,     //주문가능수량
+    pub order_qtyz8: Option<i64>,        //주문수량
+    pub order_pricez12: Option<f64>,     //주문가격
+}
+
+pub const BLOCK_NAME_S8302_OUT: &str = "s8302OutBlock";
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/**
+ * 등락부호
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PriceSign {
+    UpperLimit, //상한
+    Up,         //상승
+    Unchanged,  //보합
+    LowerLimit, //하한
+    Down,       //하락
+}
@@ -0,0 +1,674 @@
+use std::ffi::c_char;
+
+use serde::Serialize;
+
+use crate::utils::{parse_decimal, parse_number, parse_scaled, parse_sign, parse_string};
+use crate::{error::*, models::*};
+use qvopenapi_bindings::{
+    Tf1OutBlock, Tf3OutBlock, Tf4OutBlock, Tf7OutBlock, Tf8OutBlock, TfEOutBlock, To1OutBlock,
+    To2OutBlock, To3OutBlock, To4OutBlock, To7OutBlock, ToEOutBlock, Tq1OutBlock, Tq2OutBlock,
+};
+
+use super::cast_packet;
+
+/**
+ * 선물 호가 (f1)
+ */
+pub fn parse_f1(body: &[c_char]) -> Result<DerivativesHoga, QvOpenApiError> {
+    let res: &Tf1OutBlock = cast_packet(SiseKind::F1, body)?;
+    Ok(DerivativesHoga {
+        code: parse_string(&res.fuitem)?,
+        hotime: parse_string(&res.fuhotime)?,
+        levels: vec![
+            parse_derivatives_hoga_level(
+                &res.fuoffer,
+                &res.fubid,
+                &res.fuofferjan,
+                &res.fubidjan,
+                &res.fuoffersu,
+                &res.fubidsu,
+            )?,
+            parse_derivatives_hoga_level(
+                &res.fujoffer,
+                &res.fujbid,
+                &res.fujofferjan,
+                &res.fujbidjan,
+                &res.fujoffersu,
+                &res.fujbidsu,
+            )?,
+            parse_derivatives_hoga_level(
+                &res.fujjoffer,
+                &res.fujjbid,
+                &res.fujjofferjan,
+                &res.fujjbidjan,
+                &res.fujjoffersu,
+                &res.fujjbidsu,
+            )?,
+            parse_derivatives_hoga_level(
+                &res.fuj4offer,
+                &res.fuj4bid,
+                &res.fuj4offerjan,
+                &res.fuj4bidjan,
+                &res.fuj4offersu,
+                &res.fuj4bidsu,
+            )?,
+            parse_derivatives_hoga_level(
+                &res.fuj5offer,
+                &res.fuj5bid,
+                &res.fuj5offerjan,
+                &res.fuj5bidjan,
+                &res.fuj5offersu,
+                &res.fuj5bidsu,
+            )?,
+        ],
+        tofferjan: parse_number(&res.futofferjan)?,
+        tbidjan: parse_number(&res.futbidjan)?,
+        toffersu: parse_number(&res.futoffersu)?,
+        tbidsu: parse_number(&res.futbidsu)?,
+    })
+}
+
+/**
+ * 선물 이론가 (f3)
+ */
+pub fn parse_f3(body: &[c_char]) -> Result<FuturesTheory, QvOpenApiError> {
+    let res: &Tf3OutBlock = cast_packet(SiseKind::F3, body)?;
+    Ok(FuturesTheory {
+        fuitem: parse_string(&res.fuitem)?,
+        futheoryprice: parse_scaled(&res.futheoryprice, 2)?,
+        futheorytime: parse_string(&res.futheorytime)?,
+        fugrate: parse_scaled(&res.fugrate, 2)?,
+        fugratio: parse_scaled(&res.fugratio, 2)?,
+    })
+}
+
+/**
+ * 선물 미결제약정 (f4)
+ */
+pub fn parse_f4(body: &[c_char]) -> Result<OpenInterest, QvOpenApiError> {
+    let res: &Tf4OutBlock = cast_packet(SiseKind::F4, body)?;
+    Ok(OpenInterest {
+        code: parse_string(&res.fuitem)?,
+        chetime: parse_string(&res.fuchetime)?,
+        openyak: parse_number(&res.fuopenyak)?,
+        preopenyak: parse_number(&res.fupreopenyak)?,
+    })
+}
+
+/**
+ * 선물 체결 (f8)
+ */
+pub fn parse_f8(body: &[c_char]) -> Result<FuturesTick, QvOpenApiError> {
+    let res: &Tf8OutBlock = cast_packet(SiseKind::F8, body)?;
+    Ok(FuturesTick {
+        fuitem: parse_string(&res.fuitem)?,
+        fuchetime: parse_string(&res.fuchetime)?,
+        fusign: parse_sign(&res.fusign)?,
+        fuchange: parse_scaled(&res.fuchange, 2)?,
+        fucurr: parse_scaled(&res.fucurr, 2)?,
+        fuhigh: parse_scaled(&res.fuhigh, 2)?,
+        fulow: parse_scaled(&res.fulow, 2)?,
+        fuvol: parse_number(&res.fuvol)?,
+        fuvolall: parse_number(&res.fuvolall)?,
+        fuvalall: parse_number(&res.fuvalall)?,
+        fuopenyak: parse_number(&res.fuopenyak)?,
+        fuoffer: parse_scaled(&res.fuoffer, 2)?,
+        fubid: parse_scaled(&res.fubid, 2)?,
+        fuofferjan: parse_number(&res.fuofferjan)?,
+        fubidjan: parse_number(&res.fubidjan)?,
+        futofferjan: parse_number(&res.futofferjan)?,
+        futbidjan: parse_number(&res.futbidjan)?,
+        fuoffersu: parse_number(&res.fuoffersu)?,
+        fubidsu: parse_number(&res.fubidsu)?,
+        futoffersu: parse_number(&res.futoffersu)?,
+        futbidsu: parse_number(&res.futbidsu)?,
+        fuchrate: parse_scaled(&res.fuchrate, 2)?,
+        fubasis: parse_scaled(&res.fubasis, 2)?,
+        fugrate: parse_scaled(&res.fugrate, 2)?,
+        fugratio: parse_scaled(&res.fugratio, 2)?,
+        fupreopenyak: parse_number(&res.fupreopenyak)?,
+        fuspvolall: parse_number(&res.fuspvolall)?,
+        fuopen: parse_scaled(&res.fuopen, 2)?,
+        bulkvol: parse_number(&res.bulkvol)?,
+    })
+}
+
+/**
+ * 옵션 호가 (o1)
+ */
+pub fn parse_o1(body: &[c_char]) -> Result<DerivativesHoga, QvOpenApiError> {
+    let res: &To1OutBlock = cast_packet(SiseKind::O1, body)?;
+    Ok(DerivativesHoga {
+        code: parse_string(&res.opitem)?,
+        hotime: parse_string(&res.ophotime)?,
+        levels: vec![
+            parse_derivatives_hoga_level(
+                &res.opoffer,
+                &res.opbid,
+                &res.opofferjan,
+                &res.opbidjan,
+                &res.opoffersu,
+                &res.opbidsu,
+            )?,
+            parse_derivatives_hoga_level(
+                &res.opjoffer,
+                &res.opjbid,
+                &res.opjofferjan,
+                &res.opjbidjan,
+                &res.opjoffersu,
+                &res.opjbidsu,
+            )?,
+            parse_derivatives_hoga_level(
+                &res.opjjoffer,
+                &res.opjjbid,
+                &res.opjjofferjan,
+                &res.opjjbidjan,
+                &res.opjjoffersu,
+                &res.opjjbidsu,
+            )?,
+            parse_derivatives_hoga_level(
+                &res.opj4offer,
+                &res.opj4bid,
+                &res.opj4offerjan,
+                &res.opj4bidjan,
+                &res.opj4offersu,
+                &res.opj4bidsu,
+            )?,
+            parse_derivatives_hoga_level(
+                &res.opj5offer,
+                &res.opj5bid,
+                &res.opj5offerjan,
+                &res.opj5bidjan,
+                &res.opj5offersu,
+                &res.opj5bidsu,
+            )?,
+        ],
+        tofferjan: parse_number(&res.optofferjan)?,
+        tbidjan: parse_number(&res.optbidjan)?,
+        toffersu: parse_number(&res.optoffersu)?,
+        tbidsu: parse_number(&res.optbidsu)?,
+    })
+}
+
+/**
+ * 옵션 체결 (o2)
+ */
+pub fn parse_o2(body: &[c_char]) -> Result<OptionTick, QvOpenApiError> {
+    let res: &To2OutBlock = cast_packet(SiseKind::O2, body)?;
+    Ok(OptionTick {
+        opitem: parse_string(&res.opitem)?,
+        opchetime: parse_string(&res.opchetime)?,
+        opjgubun: parse_string(&res.opjgubun)?,
+        opsign: parse_sign(&res.opsign)?,
+        opchange: parse_scaled(&res.opchange, 2)?,
+        opcurr: parse_scaled(&res.opcurr, 2)?,
+        opopen: parse_scaled(&res.opopen, 2)?,
+        ophigh: parse_scaled(&res.ophigh, 2)?,
+        oplow: parse_scaled(&res.oplow, 2)?,
+        opvol: parse_number(&res.opvol)?,
+        opvolallz8: parse_number(&res.opvolallz8)?,
+        opvalall: parse_number(&res.opvalall)?,
+        opopenyak: parse_number(&res.opopenyak)?,
+        opoffer: parse_scaled(&res.opoffer, 2)?,
+        opbid: parse_scaled(&res.opbid, 2)?,
+        opofferjan: parse_number(&res.opofferjan)?,
+        opbidjan: parse_number(&res.opbidjan)?,
+        opjoffer: parse_scaled(&res.opjoffer, 2)?,
+        opjbid: parse_scaled(&res.opjbid, 2)?,
+        opjofferjan: parse_number(&res.opjofferjan)?,
+        opjbidjan: parse_number(&res.opjbidjan)?,
+        opjjoffer: parse_scaled(&res.opjjoffer, 2)?,
+        opjjbid: parse_scaled(&res.opjjbid, 2)?,
+        opjjofferjan: parse_number(&res.opjjofferjan)?,
+        opjjbidjan: parse_number(&res.opjjbidjan)?,
+        optofferjan: parse_number(&res.optofferjan)?,
+        optbidjan: parse_number(&res.optbidjan)?,
+        opj4offer: parse_scaled(&res.opj4offer, 2)?,
+        opj4bid: parse_scaled(&res.opj4bid, 2)?,
+        opj4offerjan: parse_number(&res.opj4offerjan)?,
+        opj4bidjan: parse_number(&res.opj4bidjan)?,
+        opj5offer: parse_scaled(&res.opj5offer, 2)?,
+        opj5bid: parse_scaled(&res.opj5bid, 2)?,
+        opj5offerjan: parse_number(&res.opj5offerjan)?,
+        opj5bidjan: parse_number(&res.opj5bidjan)?,
+        opoffersu: parse_number(&res.opoffersu)?,
+        opjoffersu: parse_number(&res.opjoffersu)?,
+        opjjoffersu: parse_number(&res.opjjoffersu)?,
+        opj4offersu: parse_number(&res.opj4offersu)?,
+        opj5offersu: parse_number(&res.opj5offersu)?,
+        optoffersu: parse_number(&res.optoffersu)?,
+        opbidsu: parse_number(&res.opbidsu)?,
+        opjbidsu: parse_number(&res.opjbidsu)?,
+        opjjbidsu: parse_number(&res.opjjbidsu)?,
+        opj4bidsu: parse_number(&res.opj4bidsu)?,
+        opj5bidsu: parse_number(&res.opj5bidsu)?,
+        optbidsu: parse_number(&res.optbidsu)?,
+        opchrate: parse_scaled(&res.opchrate, 2)?,
+        opgrate: parse_scaled(&res.opgrate, 2)?,
+        opgratio: parse_scaled(&res.opgratio, 2)?,
+        oppreopenyak: parse_number(&res.oppreopenyak)?,
+        opbp_jgubun: parse_string(&res.opbp_jgubun)?,
+        bulkvolz8: parse_number(&res.bulkvolz8)?,
+    })
+}
+
+/**
+ * 옵션 이론가 (o3)
+ */
+pub fn parse_o3(body: &[c_char]) -> Result<OptionTheory, QvOpenApiError> {
+    let res: &To3OutBlock = cast_packet(SiseKind::O3, body)?;
+    Ok(OptionTheory {
+        opitem: parse_string(&res.opitem)?,
+        optheorytime: parse_string(&res.optheorytime)?,
+        optheoryprice: parse_scaled(&res.optheoryprice, 2)?,
+        opimpv: parse_scaled(&res.opimpv, 2)?,
+        opdelta: parse_decimal(&res.opdelta)?,
+        opgmma: parse_decimal(&res.opgmma)?,
+        opvega: parse_decimal(&res.opvega)?,
+        optheta: parse_decimal(&res.optheta)?,
+        oprho: parse_decimal(&res.oprho)?,
+        opgrate: parse_scaled(&res.opgrate, 2)?,
+        opgratio: parse_scaled(&res.opgratio, 2)?,
+    })
+}
+
+/**
+ * 옵션 미결제약정 (o4)
+ */
+pub fn parse_o4(body: &[c_char]) -> Result<OpenInterest, QvOpenApiError> {
+    let res: &To4OutBlock = cast_packet(SiseKind::O4, body)?;
+    Ok(OpenInterest {
+        code: parse_string(&res.opitem)?,
+        chetime: parse_string(&res.opchetime)?,
+        openyak: parse_number(&res.opopenyak)?,
+        preopenyak: parse_number(&res.oppreopenyak)?,
+    })
+}
+
+/**
+ * 선물스프레드 호가 (q1)
+ */
+pub fn parse_q1(body: &[c_char]) -> Result<DerivativesHoga, QvOpenApiError> {
+    let res: &Tq1OutBlock = cast_packet(SiseKind::Q1, body)?;
+    Ok(DerivativesHoga {
+        code: parse_string(&res.fuspcode)?,
+        hotime: parse_string(&res.fusphotime)?,
+        levels: vec![
+            parse_derivatives_hoga_level(
+                &res.fuspoffer,
+                &res.fuspbid,
+                &res.fuspofferjan,
+                &res.fuspbidjan,
+                &res.fuspoffersu,
+                &res.fuspbidsu,
+            )?,
+            parse_derivatives_hoga_level(
+                &res.fuspjoffer,
+                &res.fuspjbid,
+                &res.fuspjofferjan,
+                &res.fuspjbidjan,
+                &res.fuspjoffersu,
+                &res.fuspjbidsu,
+            )?,
+            parse_derivatives_hoga_level(
+                &res.fuspjjoffer,
+                &res.fuspjjbid,
+                &res.fuspjjofferjan,
+                &res.fuspjjbidjan,
+                &res.fuspjjoffersu,
+                &res.fuspjjbidsu,
+            )?,
+            parse_derivatives_hoga_level(
+                &res.fuspj4offer,
+                &res.fuspj4bid,
+                &res.fuspj4offerjan,
+                &res.fuspj4bidjan,
+                &res.fuspj4offersu,
+                &res.fuspj4bidsu,
+            )?,
+            parse_derivatives_hoga_level(
+                &res.fuspj5offer,
+                &res.fuspj5bid,
+                &res.fuspj5offerjan,
+                &res.fuspj5bidjan,
+                &res.fuspj5offersu,
+                &res.fuspj5bidsu,
+            )?,
+        ],
+        tofferjan: parse_number(&res.fusptofferjan)?,
+        tbidjan: parse_number(&res.fusptbidjan)?,
+        toffersu: parse_number(&res.fusptoffersu)?,
+        tbidsu: parse_number(&res.fusptbidsu)?,
+    })
+}
+
+/**
+ * 선물스프레드 체결 (q2)
+ */
+pub fn parse_q2(body: &[c_char]) -> Result<SpreadTick, QvOpenApiError> {
+    let res: &Tq2OutBlock = cast_packet(SiseKind::Q2, body)?;
+    Ok(SpreadTick {
+        fuspcode: parse_string(&res.fuspcode)?,
+        fusphotime: parse_string(&res.fusphotime)?,
+        fuspjgubun: parse_string(&res.fuspjgubun)?,
+        fuspsign: parse_sign(&res.fuspsign)?,
+        fuspchange: parse_scaled(&res.fuspchange, 2)?,
+        fuspcurr: parse_scaled(&res.fuspcurr, 2)?,
+        fuspcurr1: parse_scaled(&res.fuspcurr1, 2)?,
+        fuspcurr2: parse_scaled(&res.fuspcurr2, 2)?,
+        fuspopen: parse_scaled(&res.fuspopen, 2)?,
+        fusphigh: parse_scaled(&res.fusphigh, 2)?,
+        fusplow: parse_scaled(&res.fusplow, 2)?,
+        fuspvol: parse_number(&res.fuspvol)?,
+        fuspvolall: parse_number(&res.fuspvolall)?,
+        fuspvalall: parse_number(&res.fuspvalall)?,
+        fuspchrate: parse_scaled(&res.fuspchrate, 2)?,
+        fuspbp_jgubun: parse_string(&res.fuspbp_jgubun)?,
+        fuspoffer: parse_scaled(&res.fuspoffer, 2)?,
+        fuspbid: parse_scaled(&res.fuspbid, 2)?,
+    })
+}
+
+/**
+ * 선물 예상체결 (fE)
+ */
+pub fn parse_fe(body: &[c_char]) -> Result<ExpectedPrice, QvOpenApiError> {
+    let res: &TfEOutBlock = cast_packet(SiseKind::FE, body)?;
+    Ok(ExpectedPrice {
+        code: parse_string(&res.fuitem)?,
+        time: parse_string(&res.time)?,
+        dongsi: parse_string(&res.dongsi)?,
+        eqsign: parse_sign(&res.eqsign)?,
+        eqprice: parse_scaled(&res.eqprice, 2)?,
+        eqchange: parse_scaled(&res.eqchange, 2)?,
+        eqchrate: parse_scaled(&res.eqchrate, 2)?,
+    })
+}
+
+/**
+ * 옵션 예상체결 (oE)
+ */
+pub fn parse_oe(body: &[c_char]) -> Result<ExpectedPrice, QvOpenApiError> {
+    let res: &ToEOutBlock = cast_packet(SiseKind::OE, body)?;
+    Ok(ExpectedPrice {
+        code: parse_string(&res.opitem)?,
+        time: parse_string(&res.time)?,
+        dongsi: parse_string(&res.dongsi)?,
+        eqsign: parse_sign(&res.eqsign)?,
+        eqprice: parse_scaled(&res.eqprice, 2)?,
+        eqchange: parse_scaled(&res.eqchange, 2)?,
+        eqchrate: parse_scaled(&res.eqchrate, 2)?,
+    })
+}
+
+/**
+ * 선물 단계별 상하한가 (f7)
+ */
+pub fn parse_f7(body: &[c_char]) -> Result<PriceLimit, QvOpenApiError> {
+    let res: &Tf7OutBlock = cast_packet(SiseKind::F7, body)?;
+    Ok(PriceLimit {
+        code: parse_string(&res.fuitem)?,
+        time: parse_string(&res.futime)?,
+        exlmtstep: parse_string(&res.exlmtstep)?,
+        exlmtgb: parse_string(&res.exlmtgb)?,
+        uplmtgb: parse_string(&res.uplmtgb)?,
+        dnlmtgb: parse_string(&res.dnlmtgb)?,
+        uplmtprice: parse_scaled(&res.uplmtprice, 2)?,
+        dnlmtprice: parse_scaled(&res.dnlmtprice, 2)?,
+    })
+}
+
+/**
+ * 옵션 단계별 상하한가 (o7)
+ * 가격확대예정 필드가 없으므로 빈 문자열
+ */
+pub fn parse_o7(body: &[c_char]) -> Result<PriceLimit, QvOpenApiError> {
+    let res: &To7OutBlock = cast_packet(SiseKind::O7, body)?;
+    Ok(PriceLimit {
+        code: parse_string(&res.opitem)?,
+        time: parse_string(&res.optime)?,
+        exlmtstep: String::new(),
+        exlmtgb: String::new(),
+        uplmtgb: parse_string(&res.uplmtgb)?,
+        dnlmtgb: parse_string(&res.dnlmtgb)?,
+        uplmtprice: parse_scaled(&res.uplmtprice, 2)?,
+        dnlmtprice: parse_scaled(&res.dnlmtprice, 2)?,
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+fn parse_derivatives_hoga_level(
+    offer: &[c_char],
+    bid: &[c_char],
+    offerjan: &[c_char],
+    bidjan: &[c_char],
+    offersu: &[c_char],
+    bidsu: &[c_char],
+) -> Result<DerivativesHogaLevel, QvOpenApiError> {
+    Ok(DerivativesHogaLevel {
+        offer: parse_scaled(offer, 2)?,
+        bid: parse_scaled(bid, 2)?,
+        offerjan: parse_number(offerjan)?,
+        bidjan: parse_number(bidjan)?,
+        offersu: parse_number(offersu)?,
+        bidsu: parse_number(bidsu)?,
+    })
+}
+
+/**
+ * 선물/옵션/선물스프레드 5단계 호가 (f1, o1, q1)
+ * levels[0]이 우선호가, levels[4]가 5차선호가
+ */
+#[derive(Debug, Clone, Serialize)]
+pub struct DerivativesHoga {
+    pub code: String,                      //종목코드
+    pub hotime: String,                    //시간
+    pub levels: Vec<DerivativesHogaLevel>, //1~5차 호가
+    pub tofferjan: Option<i64>,            //총매도잔량
+    pub tbidjan: Option<i64>,              //총매수잔량
+    pub toffersu: Option<i64>,             //총매도건수
+    pub tbidsu: Option<i64>,               //총매수건수
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DerivativesHogaLevel {
+    pub offer: Option<f64>,    //매도호가
+    pub bid: Option<f64>,      //매수호가
+    pub offerjan: Option<i64>, //매도잔량
+    pub bidjan: Option<i64>,   //매수잔량
+    pub offersu: Option<i64>,  //매도건수
+    pub bidsu: Option<i64>,    //매수건수
+}
+
+/**
+ * 동시호가 예상체결 (fE, oE, vE)
+ * 주식선물(vE)은 원 단위 가격
+ */
+#[derive(Debug, Clone, Serialize)]
+pub struct ExpectedPrice {
+    pub code: String,              //종목코드
+    pub time: String,              //시간
+    pub dongsi: String,            //동시호가구분
+    pub eqsign: Option<PriceSign>, //예상등락부호
+    pub eqprice: Option<f64>,      //예상체결가
+    pub eqchange: Option<f64>,     //예상등락폭
+    pub eqchrate: Option<f64>,     //예상등락률
+}
+
+/**
+ * 단계별 가격제한 확대 (f7, o7, vI)
+ */
+#[derive(Debug, Clone, Serialize)]
+pub struct PriceLimit {
+    pub code: String,            //종목코드
+    pub time: String,            //시간
+    pub exlmtstep: String,       //가격확대예정단계
+    pub exlmtgb: String,         //가격확대예정구분
+    pub uplmtgb: String,         //가격제한확대상한단계
+    pub dnlmtgb: String,         //가격제한확대하한단계
+    pub uplmtprice: Option<f64>, //적용된 단계 상한가
+    pub dnlmtprice: Option<f64>, //적용된 단계 하한가
+}
+
+/**
+ * 미결제약정 (f4, o4)
+ */
+#[derive(Debug, Clone, Serialize)]
+pub struct OpenInterest {
+    pub code: String,            //종목코드
+    pub chetime: String,         //체결시간
+    pub openyak: Option<i64>,    //미결제약정수량
+    pub preopenyak: Option<i64>, //전일미결제약정수량
+}
+
+/**
+ * 선물 이론가와 괴리
+ */
+#[derive(Debug, Clone, Serialize)]
+pub struct FuturesTheory {
+    pub fuitem: String,             //종목코드
+    pub futheoryprice: Option<f64>, //선물이론가
+    pub futheorytime: String,       //이론가시간
+    pub fugrate: Option<f64>,       //괴리도
+    pub fugratio: Option<f64>,      //괴리율
+}
+
+/**
+ * 선물 체결
+ * 가격은 소수점 2자리로 변환 (35025 -> 350.25)
+ */
+#[derive(Debug, Clone, Serialize)]
+pub struct FuturesTick {
+    pub fuitem: String,            //종목코드
+    pub fuchetime: String,         //시간
+    pub fusign: Option<PriceSign>, //등락부호
+    pub fuchange: Option<f64>,     //등락폭
+    pub fucurr: Option<f64>,       //현재가
+    pub fuhigh: Option<f64>,       //고가
+    pub fulow: Option<f64>,        //저가
+    pub fuvol: Option<i64>,        //체결수량
+    pub fuvolall: Option<i64>,     //누적체결수량
+    pub fuvalall: Option<i64>,     //누적거래대금
+    pub fuopenyak: Option<i64>,    //미결제약정수량
+    pub fuoffer: Option<f64>,      //우선매도호가
+    pub fubid: Option<f64>,        //우선매수호가
+    pub fuofferjan: Option<i64>,   //우선매도잔량
+    pub fubidjan: Option<i64>,     //우선매수잔량
+    pub futofferjan: Option<i64>,  //총매도잔량
+    pub futbidjan: Option<i64>,    //총매수잔량
+    pub fuoffersu: Option<i64>,    //우선매도건수
+    pub fubidsu: Option<i64>,      //우선매수건수
+    pub futoffersu: Option<i64>,   //총매도건수
+    pub futbidsu: Option<i64>,     //총매수건수
+    pub fuchrate: Option<f64>,     //등락률
+    pub fubasis: Option<f64>,      //베이시스
+    pub fugrate: Option<f64>,      //괴리도
+    pub fugratio: Option<f64>,     //괴리율
+    pub fupreopenyak: Option<i64>, //미결제약정전일
+    pub fuspvolall: Option<i64>,   //스프레드수량
+    pub fuopen: Option<f64>,       //시가
+    pub bulkvol: Option<i64>,      //협의대량누적체결수량
+}
+
+/**
+ * 옵션 체결 (5단계 호가 포함)
+ */
+#[derive(Debug, Clone, Serialize)]
+pub struct OptionTick {
+    pub opitem: String,            //종목코드
+    pub opchetime: String,         //시간
+    pub opjgubun: String,          //장운용
+    pub opsign: Option<PriceSign>, //등락부호
+    pub opchange: Option<f64>,     //등락폭
+    pub opcurr: Option<f64>,       //현재가
+    pub opopen: Option<f64>,       //시가
+    pub ophigh: Option<f64>,       //고가
+    pub oplow: Option<f64>,        //저가
+    pub opvol: Option<i64>,        //체결수량
+    pub opvolallz8: Option<i64>,   //누적체결수량
+    pub opvalall: Option<i64>,     //누적거래대금
+    pub opopenyak: Option<i64>,    //미결제약정수량
+    pub opoffer: Option<f64>,      //우선매도호가
+    pub opbid: Option<f64>,        //우선매수호가
+    pub opofferjan: Option<i64>,   //우선매도잔량
+    pub opbidjan: Option<i64>,     //우선매수잔량
+    pub opjoffer: Option<f64>,     //차선매도호가
+    pub opjbid: Option<f64>,       //차선매수호가
+    pub opjofferjan: Option<i64>,  //차선매도잔량
+    pub opjbidjan: Option<i64>,    //차선매수잔량
+    pub opjjoffer: Option<f64>,    //차차선매도호가
+    pub opjjbid: Option<f64>,      //차차선매수호가
+    pub opjjofferjan: Option<i64>, //차차선매도잔량
+    pub opjjbidjan: Option<i64>,   //차차선매수잔량
+    pub optofferjan: Option<i64>,  //총매도잔량
+    pub optbidjan: Option<i64>,    //총매수잔량
+    pub opj4offer: Option<f64>,    //4차선매도호가
+    pub opj4bid: Option<f64>,      //4차선매수호가
+    pub opj4offerjan: Option<i64>, //4차선매도잔량
+    pub opj4bidjan: Option<i64>,   //4차선매수잔량
+    pub opj5offer: Option<f64>,    //5차선매도호가
+    pub opj5bid: Option<f64>,      //5차선매수호가
+    pub opj5offerjan: Option<i64>, //5차선매도잔량
+    pub opj5bidjan: Option<i64>,   //5차선매수잔량
+    pub opoffersu: Option<i64>,    //우선매도건수
+    pub opjoffersu: Option<i64>,   //차선매도건수
+    pub opjjoffersu: Option<i64>,  //차차선매도건수
+    pub opj4offersu: Option<i64>,  //4차선매도건수
+    pub opj5offersu: Option<i64>,  //5차선매도건수
+    pub optoffersu: Option<i64>,   //총매도건수
+    pub opbidsu: Option<i64>,      //우선매수건수
+    pub opjbidsu: Option<i64>,     //차선매수건수
+    pub opjjbidsu: Option<i64>,    //차차선매수건수
+    pub opj4bidsu: Option<i64>,    //4차선매수건수
+    pub opj5bidsu: Option<i64>,    //5차선매수건수
+    pub optbidsu: Option<i64>,     //총매수건수
+    pub opchrate: Option<f64>,     //등락률
+    pub opgrate: Option<f64>,      //괴리도
+    pub opgratio: Option<f64>,     //괴리율
+    pub oppreopenyak: Option<i64>, //미결제약정전일
+    pub opbp_jgubun: String,       //BP용장구분
+    pub bulkvolz8: Option<i64>,    //혐의대량누적체결수량
+}
+
+/**
+ * 옵션 이론가, 내재변동성, 그릭스
+ */
+#[derive(Debug, Clone, Serialize)]
+pub struct OptionTheory {
+    pub opitem: String,             //종목코드
+    pub optheorytime: String,       //이론가시간
+    pub optheoryprice: Option<f64>, //옵션이론가
+    pub opimpv: Option<f64>,        //내재변동성
+    pub opdelta: Option<f64>,       //부호+델타
+    pub opgmma: Option<f64>,        //부호+감마
+    pub opvega: Option<f64>,        //부호+베가
+    pub optheta: Option<f64>,       //부호+세타
+    pub oprho: Option<f64>,         //부호+로
+    pub opgrate: Option<f64>,       //괴리도
+    pub opgratio: Option<f64>,      //괴리율
+}
+
+/**
+ * 선물스프레드 체결
+ */
+#[derive(Debug, Clone, Serialize)]
+pub struct SpreadTick {
+    pub fuspcode: String,            //종목코드
+    pub fusphotime: String,          //시간
+    pub fuspjgubun: String,          //장운용
+    pub fuspsign: Option<PriceSign>, //전일부호
+    pub fuspchange: Option<f64>,     //전일대비
+    pub fuspcurr: Option<f64>,       //현재가
+    pub fuspcurr1: Option<f64>,      //의제약정가(근월)
+    pub fuspcurr2: Option<f64>,      //의제약정가(원월)
+    pub fuspopen: Option<f64>,       //시가
+    pub fusphigh: Option<f64>,       //고가
+    pub fusplow: Option<f64>,        //저가
+    pub fuspvol: Option<i64>,        //체결수량
+    pub fuspvolall: Option<i64>,     //누적체결수량
+    pub fuspvalall: Option<i64>,     //누적거래대금
+    pub fuspchrate: Option<f64>,     //등락율
+    pub fuspbp_jgubun: String,       //BP용장구분
+    pub fuspoffer: Option<f64>,      //우선매도호가
+    pub fuspbid: Option<f64>,        //우선매수호가
+}
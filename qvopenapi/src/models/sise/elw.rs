@@ -0,0 +1,443 @@
+use std::ffi::c_char;
+
+use serde::Serialize;
+
+use crate::utils::{parse_decimal, parse_number, parse_scaled, parse_sign, parse_string};
+use crate::{error::*, models::*};
+use qvopenapi_bindings::{TeCOutBlock, TeHOutBlock, TeLOutBlock, TeTOutBlock, TeVOutBlock};
+
+use super::cast_packet;
+
+/**
+ * ELW 체결 (eC)
+ */
+pub fn parse_ec(body: &[c_char]) -> Result<ElwTick, QvOpenApiError> {
+    let res: &TeCOutBlock = cast_packet(SiseKind::EC, body)?;
+    Ok(ElwTick {
+        code: parse_string(&res.code)?,
+        time: parse_string(&res.time)?,
+        price: parse_number(&res.price)?,
+        sign: parse_sign(&res.sign)?,
+        change: parse_number(&res.change)?,
+        chrate: parse_decimal(&res.chrate)?,
+        open: parse_number(&res.open)?,
+        high: parse_number(&res.high)?,
+        low: parse_number(&res.low)?,
+        offer: parse_number(&res.offer)?,
+        bid: parse_number(&res.bid)?,
+        volume: parse_number(&res.volume)?,
+        volrate: parse_decimal(&res.volrate)?,
+        movolume: parse_number(&res.movolume)?,
+        value: parse_number(&res.value)?,
+        janggubun: parse_string(&res.janggubun)?,
+        cbgubun: parse_string(&res.cbgubun)?,
+        stop: parse_string(&res.stop)?,
+        grate: parse_number(&res.grate)?,
+        gratio: parse_scaled(&res.gratio, 2)?,
+        lphold: parse_number(&res.lphold)?,
+        lprate: parse_scaled(&res.lprate, 2)?,
+    })
+}
+
+/**
+ * ELW 호가 (eH)
+ */
+pub fn parse_eh(body: &[c_char]) -> Result<ElwHoga, QvOpenApiError> {
+    let res: &TeHOutBlock = cast_packet(SiseKind::EH, body)?;
+    Ok(ElwHoga {
+        code: parse_string(&res.code)?,
+        time: parse_string(&res.time)?,
+        levels: vec![
+            parse_elw_hoga_level(
+                &res.S1_off,
+                &res.S1_bid,
+                &res.S1_offrem,
+                &res.S1_bidrem,
+                &res.S1_lpoffrem,
+                &res.S1_lpbidrem,
+            )?,
+            parse_elw_hoga_level(
+                &res.S2_off,
+                &res.S2_bid,
+                &res.S2_offrem,
+                &res.S2_bidrem,
+                &res.S2_lpoffrem,
+                &res.S2_lpbidrem,
+            )?,
+            parse_elw_hoga_level(
+                &res.S3_off,
+                &res.S3_bid,
+                &res.S3_offrem,
+                &res.S3_bidrem,
+                &res.S3_lpoffrem,
+                &res.S3_lpbidrem,
+            )?,
+            parse_elw_hoga_level(
+                &res.S4_off,
+                &res.S4_bid,
+                &res.S4_offrem,
+                &res.S4_bidrem,
+                &res.S4_lpoffrem,
+                &res.S4_lpbidrem,
+            )?,
+            parse_elw_hoga_level(
+                &res.S5_off,
+                &res.S5_bid,
+                &res.S5_offrem,
+                &res.S5_bidrem,
+                &res.S5_lpoffrem,
+                &res.S5_lpbidrem,
+            )?,
+            parse_elw_hoga_level(
+                &res.S6_off,
+                &res.S6_bid,
+                &res.S6_offrem,
+                &res.S6_bidrem,
+                &res.S6_lpoffrem,
+                &res.S6_lpbidrem,
+            )?,
+            parse_elw_hoga_level(
+                &res.S7_off,
+                &res.S7_bid,
+                &res.S7_offrem,
+                &res.S7_bidrem,
+                &res.S7_lpoffrem,
+                &res.S7_lpbidrem,
+            )?,
+            parse_elw_hoga_level(
+                &res.S8_off,
+                &res.S8_bid,
+                &res.S8_offrem,
+                &res.S8_bidrem,
+                &res.S8_lpoffrem,
+                &res.S8_lpbidrem,
+            )?,
+            parse_elw_hoga_level(
+                &res.S9_off,
+                &res.S9_bid,
+                &res.S9_offrem,
+                &res.S9_bidrem,
+                &res.S9_lpoffrem,
+                &res.S9_lpbidrem,
+            )?,
+            parse_elw_hoga_level(
+                &res.S10_off,
+                &res.S10_bid,
+                &res.S10_offrem,
+                &res.S10_bidrem,
+                &res.S10_lpoffrem,
+                &res.S10_lpbidrem,
+            )?,
+        ],
+        T_offrem: parse_number(&res.T_offrem)?,
+        T_bidrem: parse_number(&res.T_bidrem)?,
+        dongsi: parse_string(&res.dongsi)?,
+        eqprice: parse_number(&res.eqprice)?,
+        sign: parse_sign(&res.sign)?,
+        change: parse_number(&res.change)?,
+        chrate: parse_decimal(&res.chrate)?,
+        eqvol: parse_number(&res.eqvol)?,
+    })
+}
+
+/**
+ * ELW 이론가 (eV)
+ */
+pub fn parse_ev(body: &[c_char]) -> Result<ElwTheory, QvOpenApiError> {
+    let res: &TeVOutBlock = cast_packet(SiseKind::EV, body)?;
+    Ok(ElwTheory {
+        code: parse_string(&res.code)?,
+        theorytime: parse_string(&res.theorytime)?,
+        theoryprice: parse_number(&res.theoryprice)?,
+        impv: parse_scaled(&res.impv, 2)?,
+        delta: parse_decimal(&res.delta)?,
+        gmma: parse_decimal(&res.gmma)?,
+        vega: parse_decimal(&res.vega)?,
+        theta: parse_decimal(&res.theta)?,
+        rho: parse_decimal(&res.rho)?,
+        grate: parse_number(&res.grate)?,
+        gratio: parse_scaled(&res.gratio, 2)?,
+    })
+}
+
+/**
+ * ELW 투자지표 (eL)
+ */
+pub fn parse_el(body: &[c_char]) -> Result<ElwIndicator, QvOpenApiError> {
+    let res: &TeLOutBlock = cast_packet(SiseKind::EL, body)?;
+    Ok(ElwIndicator {
+        code: parse_string(&res.code)?,
+        jipyotime: parse_string(&res.jipyotime)?,
+        parity: parse_scaled(&res.parity, 2)?,
+        egearing: parse_scaled(&res.egearing, 2)?,
+        gearingrate: parse_scaled(&res.gearingrate, 2)?,
+        profitrate: parse_scaled(&res.profitrate, 2)?,
+        basepoint: parse_scaled(&res.basepoint, 2)?,
+    })
+}
+
+/**
+ * ELW 실시간 거래원 (eT)
+ */
+pub fn parse_et(body: &[c_char]) -> Result<ElwTrader, QvOpenApiError> {
+    let res: &TeTOutBlock = cast_packet(SiseKind::ET, body)?;
+    Ok(ElwTrader {
+        code: parse_string(&res.code)?,
+        time: parse_string(&res.time)?,
+        offer_traders: vec![
+            parse_elw_trader(
+                &res.off_trano1,
+                &res.off_tra1,
+                &res.N_off_tra1,
+                &res.N_otraflag1,
+                &res.N_offvolume1,
+                &res.N_offvolcha1,
+            )?,
+            parse_elw_trader(
+                &res.off_trano2,
+                &res.off_tra2,
+                &res.N_off_tra2,
+                &res.N_otraflag2,
+                &res.N_offvolume2,
+                &res.N_offvolcha2,
+            )?,
+            parse_elw_trader(
+                &res.off_trano3,
+                &res.off_tra3,
+                &res.N_off_tra3,
+                &res.N_otraflag3,
+                &res.N_offvolume3,
+                &res.N_offvolcha3,
+            )?,
+            parse_elw_trader(
+                &res.off_trano4,
+                &res.off_tra4,
+                &res.N_off_tra4,
+                &res.N_otraflag4,
+                &res.N_offvolume4,
+                &res.N_offvolcha4,
+            )?,
+            parse_elw_trader(
+                &res.off_trano5,
+                &res.off_tra5,
+                &res.N_off_tra5,
+                &res.N_otraflag5,
+                &res.N_offvolume5,
+                &res.N_offvolcha5,
+            )?,
+        ],
+        bid_traders: vec![
+            parse_elw_trader(
+                &res.bid_trano1,
+                &res.bid_tra1,
+                &res.N_bid_tra1,
+                &res.N_btraflag1,
+                &res.N_bidvolume1,
+                &res.N_bidvolcha1,
+            )?,
+            parse_elw_trader(
+                &res.bid_trano2,
+                &res.bid_tra2,
+                &res.N_bid_tra2,
+                &res.N_btraflag2,
+                &res.N_bidvolume2,
+                &res.N_bidvolcha2,
+            )?,
+            parse_elw_trader(
+                &res.bid_trano3,
+                &res.bid_tra3,
+                &res.N_bid_tra3,
+                &res.N_btraflag3,
+                &res.N_bidvolume3,
+                &res.N_bidvolcha3,
+            )?,
+            parse_elw_trader(
+                &res.bid_trano4,
+                &res.bid_tra4,
+                &res.N_bid_tra4,
+                &res.N_btraflag4,
+                &res.N_bidvolume4,
+                &res.N_bidvolcha4,
+            )?,
+            parse_elw_trader(
+                &res.bid_trano5,
+                &res.bid_tra5,
+                &res.N_bid_tra5,
+                &res.N_btraflag5,
+                &res.N_bidvolume5,
+                &res.N_bidvolcha5,
+            )?,
+        ],
+        N_offvolall: parse_number(&res.N_offvolall)?,
+        N_offvolcha: parse_number(&res.N_offvolcha)?,
+        N_bidvolall: parse_number(&res.N_bidvolall)?,
+        N_bidvolcha: parse_number(&res.N_bidvolcha)?,
+        N_soonmaesu: parse_number(&res.N_soonmaesu)?,
+        N_soonmaecha: parse_number(&res.N_soonmaecha)?,
+        N_alloffvol: parse_number(&res.N_alloffvol)?,
+        N_allbidvol: parse_number(&res.N_allbidvol)?,
+        hname: parse_string(&res.hname)?,
+        kpgubun: parse_string(&res.kpgubun)?,
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+fn parse_elw_hoga_level(
+    offer: &[c_char],
+    bid: &[c_char],
+    offerrem: &[c_char],
+    bidrem: &[c_char],
+    lpofferrem: &[c_char],
+    lpbidrem: &[c_char],
+) -> Result<ElwHogaLevel, QvOpenApiError> {
+    Ok(ElwHogaLevel {
+        offer: parse_number(offer)?,
+        bid: parse_number(bid)?,
+        offerrem: parse_number(offerrem)?,
+        bidrem: parse_number(bidrem)?,
+        lpofferrem: parse_number(lpofferrem)?,
+        lpbidrem: parse_number(lpbidrem)?,
+    })
+}
+
+fn parse_elw_trader(
+    trano: &[c_char],
+    tra: &[c_char],
+    name: &[c_char],
+    traflag: &[c_char],
+    volume: &[c_char],
+    volcha: &[c_char],
+) -> Result<ElwTraderEntry, QvOpenApiError> {
+    Ok(ElwTraderEntry {
+        trano: parse_string(trano)?,
+        tra: parse_string(tra)?,
+        name: parse_string(name)?,
+        traflag: parse_string(traflag)?,
+        volume: parse_number(volume)?,
+        volcha: parse_number(volcha)?,
+    })
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ElwHogaLevel {
+    pub offer: Option<i64>,      //매도호가
+    pub bid: Option<i64>,        //매수호가
+    pub offerrem: Option<i64>,   //매도호가잔량
+    pub bidrem: Option<i64>,     //매수호가잔량
+    pub lpofferrem: Option<i64>, //LP매도호가잔량
+    pub lpbidrem: Option<i64>,   //LP매수호가잔량
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ElwTraderEntry {
+    pub trano: String,       //회원사코드
+    pub tra: String,         //회원사단이름
+    pub name: String,        //회원사장이름
+    pub traflag: String,     //회원사외구분
+    pub volume: Option<i64>, //거래량
+    pub volcha: Option<i64>, //직전대비
+}
+
+/**
+ * ELW 체결
+ * volume은 누적거래량, movolume은 이번 체결의 변동거래량
+ */
+#[derive(Debug, Clone, Serialize)]
+pub struct ElwTick {
+    pub code: String,            //단축종목코드
+    pub time: String,            //시간
+    pub price: Option<i64>,      //현재가
+    pub sign: Option<PriceSign>, //등락부호
+    pub change: Option<i64>,     //등락폭
+    pub chrate: Option<f64>,     //등락률
+    pub open: Option<i64>,       //시가
+    pub high: Option<i64>,       //고가
+    pub low: Option<i64>,        //저가
+    pub offer: Option<i64>,      //매도호가
+    pub bid: Option<i64>,        //매수호가
+    pub volume: Option<i64>,     //누적거래량
+    pub volrate: Option<f64>,    //거래량 전일비
+    pub movolume: Option<i64>,   //변동거래량
+    pub value: Option<i64>,      //거래대금 백만원
+    pub janggubun: String,       //장구분
+    pub cbgubun: String,         //CB구분
+    pub stop: String,            //STOP
+    pub grate: Option<i64>,      //괴리도
+    pub gratio: Option<f64>,     //괴리율
+    pub lphold: Option<i64>,     //LP보유수량
+    pub lprate: Option<f64>,     //LP보유률
+}
+
+/**
+ * ELW 10단계 호가 (LP 잔량 포함)
+ * levels[0]이 최우선호가, levels[9]가 10차호가
+ */
+#[derive(Debug, Clone, Serialize)]
+#[allow(non_snake_case)]
+pub struct ElwHoga {
+    pub code: String,              //단축코드
+    pub time: String,              //시간
+    pub levels: Vec<ElwHogaLevel>, //1~10차 호가
+    pub T_offrem: Option<i64>,     //총매도호가 잔량
+    pub T_bidrem: Option<i64>,     //총매수호가 잔량
+    pub dongsi: String,            //동시구분
+    pub eqprice: Option<i64>,      //동시호가시예상체결가
+    pub sign: Option<PriceSign>,   //등락부호
+    pub change: Option<i64>,       //등락폭
+    pub chrate: Option<f64>,       //등락률
+    pub eqvol: Option<i64>,        //동시호가시예상체결수량
+}
+
+/**
+ * ELW 이론가, 내재변동성, 그릭스
+ */
+#[derive(Debug, Clone, Serialize)]
+pub struct ElwTheory {
+    pub code: String,             //종목코드
+    pub theorytime: String,       //이론가시간
+    pub theoryprice: Option<i64>, //이론가
+    pub impv: Option<f64>,        //내재변동성
+    pub delta: Option<f64>,       //부호+델타
+    pub gmma: Option<f64>,        //부호+감마
+    pub vega: Option<f64>,        //부호+베가
+    pub theta: Option<f64>,       //부호+세타
+    pub rho: Option<f64>,         //부호+로
+    pub grate: Option<i64>,       //괴리도
+    pub gratio: Option<f64>,      //괴리율
+}
+
+/**
+ * ELW 투자지표
+ */
+#[derive(Debug, Clone, Serialize)]
+pub struct ElwIndicator {
+    pub code: String,             //종목코드
+    pub jipyotime: String,        //투자지표시간
+    pub parity: Option<f64>,      //패리티
+    pub egearing: Option<f64>,    //E기어링
+    pub gearingrate: Option<f64>, //기어링비율
+    pub profitrate: Option<f64>,  //손익분기율
+    pub basepoint: Option<f64>,   //자본지지점
+}
+
+/**
+ * ELW 거래원 상위 5개
+ */
+#[derive(Debug, Clone, Serialize)]
+#[allow(non_snake_case)]
+pub struct ElwTrader {
+    pub code: String,                       //종목코드
+    pub time: String,                       //시간
+    pub offer_traders: Vec<ElwTraderEntry>, //매도회원사 1~5
+    pub bid_traders: Vec<ElwTraderEntry>,   //매수회원사 1~5
+    pub N_offvolall: Option<i64>,           //외국계회원사매도합
+    pub N_offvolcha: Option<i64>,           //외국계직전매도차
+    pub N_bidvolall: Option<i64>,           //외국계회원사매수합
+    pub N_bidvolcha: Option<i64>,           //외국계직전매수차
+    pub N_soonmaesu: Option<i64>,           //외국계회원순매수
+    pub N_soonmaecha: Option<i64>,          //외국계직전순매수차
+    pub N_alloffvol: Option<i64>,           //매도전체합
+    pub N_allbidvol: Option<i64>,           //매수전체합
+    pub hname: String,                      //종목명
+    pub kpgubun: String,                    //시장구분
+}
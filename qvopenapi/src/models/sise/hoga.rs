@@ -0,0 +1,209 @@
+use std::ffi::c_char;
+
+use serde::Serialize;
+
+use crate::utils::{parse_decimal, parse_number, parse_sign, parse_string};
+use crate::{error::*, models::*};
+use qvopenapi_bindings::{
+    Th1OutBlock, Th2OutBlock, Th3OutBlock, Tk3OutBlock, Tk4OutBlock, Tk5OutBlock,
+};
+
+use super::cast_packet;
+
+/**
+ * 거래소 호가잔량 (h1)
+ */
+pub fn parse_h1(body: &[c_char]) -> Result<Hoga, QvOpenApiError> {
+    let res: &Th1OutBlock = cast_packet(SiseKind::H1, body)?;
+    Ok(Hoga {
+        code: parse_string(&res.code)?,
+        hotime: parse_string(&res.hotime)?,
+        levels: vec![
+            parse_hoga_level(&res.offer, &res.bid, &res.offerrem, &res.bidrem)?,
+            parse_hoga_level(&res.P_offer, &res.P_bid, &res.P_offerrem, &res.P_bidrem)?,
+            parse_hoga_level(&res.S_offer, &res.S_bid, &res.S_offerrem, &res.S_bidrem)?,
+            parse_hoga_level(&res.S4_offer, &res.S4_bid, &res.S4_offerrem, &res.S4_bidrem)?,
+            parse_hoga_level(&res.S5_offer, &res.S5_bid, &res.S5_offerrem, &res.S5_bidrem)?,
+            parse_hoga_level(&res.S6_offer, &res.S6_bid, &res.S6_offerrem, &res.S6_bidrem)?,
+            parse_hoga_level(&res.S7_offer, &res.S7_bid, &res.S7_offerrem, &res.S7_bidrem)?,
+            parse_hoga_level(&res.S8_offer, &res.S8_bid, &res.S8_offerrem, &res.S8_bidrem)?,
+            parse_hoga_level(&res.S9_offer, &res.S9_bid, &res.S9_offerrem, &res.S9_bidrem)?,
+            parse_hoga_level(
+                &res.S10_offer,
+                &res.S10_bid,
+                &res.S10_offerrem,
+                &res.S10_bidrem,
+            )?,
+        ],
+        T_offerrem: parse_number(&res.T_offerrem)?,
+        T_bidrem: parse_number(&res.T_bidrem)?,
+        volume: parse_number(&res.volume)?,
+    })
+}
+
+/**
+ * 코스닥 호가잔량 (k3)
+ */
+pub fn parse_k3(body: &[c_char]) -> Result<Hoga, QvOpenApiError> {
+    let res: &Tk3OutBlock = cast_packet(SiseKind::K3, body)?;
+    Ok(Hoga {
+        code: parse_string(&res.code)?,
+        hotime: parse_string(&res.time)?,
+        levels: vec![
+            parse_hoga_level(&res.offer, &res.bid, &res.offerrem, &res.bidrem)?,
+            parse_hoga_level(&res.P_offer, &res.P_bid, &res.P_offerrem, &res.P_bidrem)?,
+            parse_hoga_level(&res.S_offer, &res.S_bid, &res.S_offerrem, &res.S_bidrem)?,
+            parse_hoga_level(&res.S4_offer, &res.S4_bid, &res.S4_offerrem, &res.S4_bidrem)?,
+            parse_hoga_level(&res.S5_offer, &res.S5_bid, &res.S5_offerrem, &res.S5_bidrem)?,
+            parse_hoga_level(&res.S6_offer, &res.S6_bid, &res.S6_offerrem, &res.S6_bidrem)?,
+            parse_hoga_level(&res.S7_offer, &res.S7_bid, &res.S7_offerrem, &res.S7_bidrem)?,
+            parse_hoga_level(&res.S8_offer, &res.S8_bid, &res.S8_offerrem, &res.S8_bidrem)?,
+            parse_hoga_level(&res.S9_offer, &res.S9_bid, &res.S9_offerrem, &res.S9_bidrem)?,
+            parse_hoga_level(
+                &res.S10_offer,
+                &res.S10_bid,
+                &res.S10_offerrem,
+                &res.S10_bidrem,
+            )?,
+        ],
+        T_offerrem: parse_number(&res.T_offerrem)?,
+        T_bidrem: parse_number(&res.T_bidrem)?,
+        volume: parse_number(&res.volume)?,
+    })
+}
+
+/**
+ * 거래소 시간외 호가잔량 (h2)
+ */
+pub fn parse_h2(body: &[c_char]) -> Result<HogaTotal, QvOpenApiError> {
+    let res: &Th2OutBlock = cast_packet(SiseKind::H2, body)?;
+    Ok(HogaTotal {
+        code: parse_string(&res.code)?,
+        hotime: parse_string(&res.hotime)?,
+        O_offerrem: parse_number(&res.O_offerrem)?,
+        O_bidrem: parse_number(&res.O_bidrem)?,
+    })
+}
+
+/**
+ * 코스닥 시간외 호가잔량 (k4)
+ */
+pub fn parse_k4(body: &[c_char]) -> Result<HogaTotal, QvOpenApiError> {
+    let res: &Tk4OutBlock = cast_packet(SiseKind::K4, body)?;
+    Ok(HogaTotal {
+        code: parse_string(&res.code)?,
+        hotime: parse_string(&res.hotime)?,
+        O_offerrem: parse_number(&res.O_offerrem)?,
+        O_bidrem: parse_number(&res.O_bidrem)?,
+    })
+}
+
+/**
+ * 거래소 예상호가잔량 (h3)
+ */
+pub fn parse_h3(body: &[c_char]) -> Result<ExpectedHoga, QvOpenApiError> {
+    let res: &Th3OutBlock = cast_packet(SiseKind::H3, body)?;
+    Ok(ExpectedHoga {
+        code: parse_string(&res.code)?,
+        hotime: parse_string(&res.hotime)?,
+        dongsi: parse_string(&res.dongsi)?,
+        jeqprice: parse_number(&res.jeqprice)?,
+        jeqsign: parse_sign(&res.jeqsign)?,
+        jeqchange: parse_number(&res.jeqchange)?,
+        jeqchrate: parse_decimal(&res.jeqchrate)?,
+        jeqvol: parse_number(&res.jeqvol)?,
+        offer: parse_number(&res.offer)?,
+        bid: parse_number(&res.bid)?,
+        offerrem: parse_number(&res.offerrem)?,
+        bidrem: parse_number(&res.bidrem)?,
+    })
+}
+
+/**
+ * 코스닥 예상호가잔량 (k5)
+ */
+pub fn parse_k5(body: &[c_char]) -> Result<ExpectedHoga, QvOpenApiError> {
+    let res: &Tk5OutBlock = cast_packet(SiseKind::K5, body)?;
+    Ok(ExpectedHoga {
+        code: parse_string(&res.code)?,
+        hotime: parse_string(&res.hotime)?,
+        dongsi: parse_string(&res.dongsi)?,
+        jeqprice: parse_number(&res.jeqprice)?,
+        jeqsign: parse_sign(&res.jeqsign)?,
+        jeqchange: parse_number(&res.jeqchange)?,
+        jeqchrate: parse_decimal(&res.jeqchrate)?,
+        jeqvol: parse_number(&res.jeqvol)?,
+        offer: parse_number(&res.offer)?,
+        bid: parse_number(&res.bid)?,
+        offerrem: parse_number(&res.offerrem)?,
+        bidrem: parse_number(&res.bidrem)?,
+    })
+}
+
+fn parse_hoga_level(
+    offer: &[c_char],
+    bid: &[c_char],
+    offerrem: &[c_char],
+    bidrem: &[c_char],
+) -> Result<HogaLevel, QvOpenApiError> {
+    Ok(HogaLevel {
+        offer: parse_number(offer)?,
+        bid: parse_number(bid)?,
+        offerrem: parse_number(offerrem)?,
+        bidrem: parse_number(bidrem)?,
+    })
+}
+
+/**
+ * 10단계 호가
+ * levels[0]이 최우선호가, levels[9]가 10차호가
+ */
+#[derive(Debug, Clone, Serialize)]
+#[allow(non_snake_case)]
+pub struct Hoga {
+    pub code: String,            //종목코드
+    pub hotime: String,          //시간
+    pub levels: Vec<HogaLevel>,  //1~10차 호가
+    pub T_offerrem: Option<i64>, //총매도호가잔량
+    pub T_bidrem: Option<i64>,   //총매수호가잔량
+    pub volume: Option<i64>,     //누적거래량
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct HogaLevel {
+    pub offer: Option<i64>,    //매도호가
+    pub bid: Option<i64>,      //매수호가
+    pub offerrem: Option<i64>, //매도호가잔량
+    pub bidrem: Option<i64>,   //매수호가잔량
+}
+
+/**
+ * 시간외 총호가잔량
+ */
+#[derive(Debug, Clone, Serialize)]
+#[allow(non_snake_case)]
+pub struct HogaTotal {
+    pub code: String,            //종목코드
+    pub hotime: String,          //시간
+    pub O_offerrem: Option<i64>, //총매도호가잔량
+    pub O_bidrem: Option<i64>,   //총매수호가잔량
+}
+
+/**
+ * 동시호가 시간의 예상체결과 최우선호가
+ */
+#[derive(Debug, Clone, Serialize)]
+pub struct ExpectedHoga {
+    pub code: String,               //종목코드
+    pub hotime: String,             //시간
+    pub dongsi: String,             //동시구분
+    pub jeqprice: Option<i64>,      //예상체결가
+    pub jeqsign: Option<PriceSign>, //예상등락부호
+    pub jeqchange: Option<i64>,     //예상등락폭
+    pub jeqchrate: Option<f64>,     //예상등락률
+    pub jeqvol: Option<i64>,        //예상체결수량
+    pub offer: Option<i64>,         //매도호가
+    pub bid: Option<i64>,           //매수호가
+    pub offerrem: Option<i64>,      //매도호가잔량
+    pub bidrem: Option<i64>,        //매수호가잔량
+}
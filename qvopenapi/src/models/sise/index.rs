@@ -0,0 +1,76 @@
+use std::ffi::c_char;
+
+use serde::Serialize;
+
+use crate::utils::{parse_number, parse_scaled, parse_sign, parse_string};
+use crate::{error::*, models::*};
+use qvopenapi_bindings::{Tk1OutBlock, Tu1OutBlock};
+
+use super::cast_packet;
+
+/**
+ * 코스피 업종지수 (u1)
+ */
+pub fn parse_u1(body: &[c_char]) -> Result<IndexTick, QvOpenApiError> {
+    let res: &Tu1OutBlock = cast_packet(SiseKind::U1, body)?;
+    Ok(IndexTick {
+        code: parse_string(&res.jisucode)?,
+        time: parse_string(&res.jisutime)?,
+        jisu: parse_scaled(&res.jisu, 2)?,
+        sign: parse_sign(&res.jisusign)?,
+        change: parse_scaled(&res.jisuchange, 2)?,
+        volume: parse_number(&res.jisuvolume)?,
+        value: parse_number(&res.jisuvalue)?,
+        open: parse_scaled(&res.jisuopen, 2)?,
+        high: parse_scaled(&res.jisuhigh, 2)?,
+        hightime: parse_string(&res.jisuhightime)?,
+        low: parse_scaled(&res.jisulow, 2)?,
+        lowtime: parse_string(&res.jisulowtime)?,
+        chrate: parse_scaled(&res.jisuchrate, 2)?,
+        brkvol: parse_scaled(&res.jisubrkvol, 2)?,
+    })
+}
+
+/**
+ * 코스닥 업종지수 (k1)
+ */
+pub fn parse_k1(body: &[c_char]) -> Result<IndexTick, QvOpenApiError> {
+    let res: &Tk1OutBlock = cast_packet(SiseKind::K1, body)?;
+    Ok(IndexTick {
+        code: parse_string(&res.jisukcode)?,
+        time: parse_string(&res.jisuktime)?,
+        jisu: parse_scaled(&res.jisuk, 2)?,
+        sign: parse_sign(&res.jisuksign)?,
+        change: parse_scaled(&res.jisukchange, 2)?,
+        volume: parse_number(&res.jisukvolume)?,
+        value: parse_number(&res.jisukvalue)?,
+        open: parse_scaled(&res.jisukopen, 2)?,
+        high: parse_scaled(&res.jisukhigh, 2)?,
+        hightime: parse_string(&res.jisukhightime)?,
+        low: parse_scaled(&res.jisuklow, 2)?,
+        lowtime: parse_string(&res.jisuklowtime)?,
+        chrate: parse_scaled(&res.jisukchrate, 2)?,
+        brkvol: parse_scaled(&res.jisukbrkvol, 2)?,
+    })
+}
+
+/**
+ * 업종지수 (u1, k1)
+ */
+#[derive(Debug, Clone, Serialize)]
+pub struct IndexTick {
+    pub code: String,            //업종코드
+    pub time: String,            //시간
+    pub jisu: Option<f64>,       //지수
+    pub sign: Option<PriceSign>, //등락부호
+    pub change: Option<f64>,     //등락폭
+    pub volume: Option<i64>,     //거래량
+    pub value: Option<i64>,      //거래대금
+    pub open: Option<f64>,       //시가지수
+    pub high: Option<f64>,       //고가지수
+    pub hightime: String,        //고가시간
+    pub low: Option<f64>,        //저가지수
+    pub lowtime: String,         //저가시간
+    pub chrate: Option<f64>,     //지수등락률
+    pub brkvol: Option<f64>,     //거래비중
+}
@@ -0,0 +1,562 @@
+use std::collections::HashMap;
+use std::ffi::c_char;
+use std::mem::size_of;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+
+use qvopenapi_bindings::OutDataBlock;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    client::{QvOpenApiClientMessageHandler, QvOpenApiRequest},
+    error::*,
+    subscription::SubscriptionId,
+    utils::{from_cp949, from_cp949_ptr},
+    wmca_lib,
+};
+
+mod derivatives;
+mod elw;
+mod hoga;
+mod index;
+mod order;
+mod stock_futures;
+mod tick;
+pub use derivatives::*;
+pub use elw::*;
+pub use hoga::*;
+pub use index::*;
+pub use order::*;
+pub use stock_futures::*;
+pub use tick::*;
+
+// 실시간 패킷 앞쪽의 패킷유형(2) + 압축구분(1)
+const SISE_HEADER_LEN: usize = 3;
+
+/**
+ * 실시간 서비스 코드 (BC)
+ * wmcaAttach/wmcaDetach의 szBCType이며, 수신 패킷의 패킷유형과 같음
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SiseKind {
+    #[serde(rename = "h1")]
+    H1, // 거래소 호가잔량
+    #[serde(rename = "k3")]
+    K3, // 코스닥 호가잔량
+    #[serde(rename = "h2")]
+    H2, // 거래소 시간외 호가잔량
+    #[serde(rename = "k4")]
+    K4, // 코스닥 시간외 호가잔량
+    #[serde(rename = "h3")]
+    H3, // 거래소 예상호가잔량
+    #[serde(rename = "k5")]
+    K5, // 코스닥 예상호가잔량
+    #[serde(rename = "j8")]
+    J8, // 거래소 체결
+    #[serde(rename = "k8")]
+    K8, // 코스닥 체결
+    #[serde(rename = "f1")]
+    F1, // 선물 호가
+    #[serde(rename = "f3")]
+    F3, // 선물 이론가
+    #[serde(rename = "f4")]
+    F4, // 선물 미결제약정
+    #[serde(rename = "f8")]
+    F8, // 선물 체결
+    #[serde(rename = "q1")]
+    Q1, // 선물스프레드 호가
+    #[serde(rename = "q2")]
+    Q2, // 선물스프레드 체결
+    #[serde(rename = "o1")]
+    O1, // 옵션 호가
+    #[serde(rename = "o2")]
+    O2, // 옵션 체결
+    #[serde(rename = "o3")]
+    O3, // 옵션 이론가
+    #[serde(rename = "o4")]
+    O4, // 옵션 미결제약정
+    #[serde(rename = "vH")]
+    VH, // 주식선물 호가
+    #[serde(rename = "vC")]
+    VC, // 주식선물 체결
+    #[serde(rename = "vV")]
+    VV, // 주식선물 이론가
+    #[serde(rename = "vM")]
+    VM, // 주식선물 미결제약정
+    #[serde(rename = "v7")]
+    V7, // 주식선물 스프레드 호가
+    #[serde(rename = "v8")]
+    V8, // 주식선물 스프레드 체결
+    #[serde(rename = "eC")]
+    EC, // ELW 체결
+    #[serde(rename = "eH")]
+    EH, // ELW 호가
+    #[serde(rename = "eV")]
+    EV, // ELW 이론가
+    #[serde(rename = "eL")]
+    EL, // ELW 투자지표
+    #[serde(rename = "eT")]
+    ET, // ELW 실시간거래원
+    #[serde(rename = "fE")]
+    FE, // 선물 예상체결
+    #[serde(rename = "oE")]
+    OE, // 옵션 예상체결
+    #[serde(rename = "vE")]
+    VE, // 주식선물 예상체결
+    #[serde(rename = "j0")]
+    J0, // 거래소 ETF NAV
+    #[serde(rename = "j1")]
+    J1, // 코스닥 ETF NAV
+    #[serde(rename = "f7")]
+    F7, // 선물 단계별 상하한가
+    #[serde(rename = "o7")]
+    O7, // 옵션 단계별 상하한가
+    #[serde(rename = "vI")]
+    VI, // 주식선물 단계별 상하한가
+    #[serde(rename = "u1")]
+    U1, // 코스피 업종지수
+    #[serde(rename = "k1")]
+    K1, // 코스닥 업종지수
+}
+
+// (BC, 서비스 코드, 입력 종목코드 길이)
+const SISE_KINDS: [(SiseKind, &str, usize); 39] = [
+    (SiseKind::H1, "h1", 6),
+    (SiseKind::K3, "k3", 6),
+    (SiseKind::H2, "h2", 6),
+    (SiseKind::K4, "k4", 6),
+    (SiseKind::H3, "h3", 6),
+    (SiseKind::K5, "k5", 6),
+    (SiseKind::J8, "j8", 6),
+    (SiseKind::K8, "k8", 6),
+    (SiseKind::F1, "f1", 8),
+    (SiseKind::F3, "f3", 8),
+    (SiseKind::F4, "f4", 8),
+    (SiseKind::F8, "f8", 8),
+    (SiseKind::Q1, "q1", 8),
+    (SiseKind::Q2, "q2", 8),
+    (SiseKind::O1, "o1", 8),
+    (SiseKind::O2, "o2", 8),
+    (SiseKind::O3, "o3", 8),
+    (SiseKind::O4, "o4", 8),
+    (SiseKind::VH, "vH", 8),
+    (SiseKind::VC, "vC", 8),
+    (SiseKind::VV, "vV", 8),
+    (SiseKind::VM, "vM", 8),
+    (SiseKind::V7, "v7", 8),
+    (SiseKind::V8, "v8", 8),
+    (SiseKind::EC, "eC", 6),
+    (SiseKind::EH, "eH", 6),
+    (SiseKind::EV, "eV", 6),
+    (SiseKind::EL, "eL", 6),
+    (SiseKind::ET, "eT", 6),
+    (SiseKind::FE, "fE", 8),
+    (SiseKind::OE, "oE", 8),
+    (SiseKind::VE, "vE", 8),
+    (SiseKind::J0, "j0", 6),
+    (SiseKind::J1, "j1", 6),
+    (SiseKind::F7, "f7", 8),
+    (SiseKind::O7, "o7", 8),
+    (SiseKind::VI, "vI", 8),
+    (SiseKind::U1, "u1", 2),
+    (SiseKind::K1, "k1", 2),
+];
+
+impl SiseKind {
+    pub fn from_code(code: &str) -> Option<SiseKind> {
+        SISE_KINDS
+            .iter()
+            .find(|(_, bc_code, _)| *bc_code == code)
+            .map(|(kind, _, _)| *kind)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        self.entry().1
+    }
+
+    /**
+     * wmcaAttach의 nCodeLen (종목코드 6, 선물옵션 8, 업종코드 2)
+     */
+    pub fn code_len(&self) -> usize {
+        self.entry().2
+    }
+
+    /**
+     * wmcaAttach의 szInput (종목코드를 nCodeLen 길이로 공백 채워 이어 붙임)
+     */
+    pub fn pack_codes(&self, codes: &[String]) -> Result<String, QvOpenApiError> {
+        let code_len = self.code_len();
+        let mut packed = String::with_capacity(code_len * codes.len());
+        for code in codes {
+            let code = code.trim();
+            if code.is_empty() || code.len() > code_len || !code.is_ascii() {
+                return Err(QvOpenApiError::BadRequestError {
+                    message: format!("Invalid code [{}] for {}", code, self.as_str()),
+                });
+            }
+            packed.push_str(&format!("{:<width$}", code, width = code_len));
+        }
+        Ok(packed)
+    }
+
+    fn entry(&self) -> &'static (SiseKind, &'static str, usize) {
+        SISE_KINDS.iter().find(|(kind, _, _)| kind == self).unwrap()
+    }
+}
+
+/**
+ * 실시간 패킷 내용
+ * 패킷별 파서가 없는 경우 Raw (cp949 디코딩한 고정길이 문자열)
+ * register_sise_parser()로 등록한 파서는 Custom으로 원하는 내용을 담을 수 있음
+ */
+#[derive(Debug, Clone, Serialize)]
+pub enum SiseEvent {
+    Hoga(Hoga),                                          //h1, k3
+    HogaTotal(HogaTotal),                                //h2, k4
+    ExpectedHoga(ExpectedHoga),                          //h3, k5
+    Tick(Tick),                                          //j8, k8
+    EtfNav(EtfNav),                                      //j0, j1
+    DerivativesHoga(DerivativesHoga),                    //f1, o1, q1
+    FuturesTheory(FuturesTheory),                        //f3
+    OpenInterest(OpenInterest),                          //f4, o4, vM
+    FuturesTick(FuturesTick),                            //f8
+    OptionTick(Box<OptionTick>),                         //o2
+    OptionTheory(OptionTheory),                          //o3
+    SpreadTick(SpreadTick),                              //q2
+    StockFuturesHoga(Box<StockFuturesHoga>),             //vH, v7
+    StockFuturesTick(Box<StockFuturesTick>),             //vC
+    StockFuturesTheory(StockFuturesTheory),              //vV
+    StockFuturesSpreadTick(Box<StockFuturesSpreadTick>), //v8
+    ElwTick(ElwTick),                                    //eC
+    ElwHoga(Box<ElwHoga>),                               //eH
+    ElwTheory(ElwTheory),                                //eV
+    ElwIndicator(ElwIndicator),                          //eL
+    ElwTrader(Box<ElwTrader>),                           //eT
+    ExpectedPrice(ExpectedPrice),                        //fE, oE, vE
+    PriceLimit(PriceLimit),                              //f7, o7, vI
+    IndexTick(IndexTick),                                //u1, k1
+    Custom(Value),
+    Raw(String),
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SiseResponse {
+    pub tr_index: i32,
+    // 패킷유형 (BC 코드)
+    pub bc_code: String,
+    // SiseKind에 없는 BC 코드면 None
+    pub kind: Option<SiseKind>,
+    // kind가 None이면 종목코드 길이를 알 수 없으므로 빈 문자열
+    pub code: String,
+    pub block_name: String,
+    pub block_len: i32,
+    pub block_data: SiseEvent,
+}
+
+pub fn parse_sise(lparam: isize) -> std::result::Result<SiseResponse, QvOpenApiError> {
+    let (tr_index, block_name, block_len, packet) = unsafe { read_sise_packet(lparam) };
+
+    let bc_code = match packet_type(packet) {
+        Some(bc_code) => bc_code,
+        None => return Err(QvOpenApiError::UnimplementedBlockError { block_name }),
+    };
+    let kind = SiseKind::from_code(&bc_code);
+    //앞쪽 3바이트는 패킷유형과 압축구분이므로 skip
+    let body = &packet[SISE_HEADER_LEN..];
+    let block_data = match parse_sise_event(&bc_code, kind, body) {
+        Some(block_data) => block_data?,
+        None => return Err(QvOpenApiError::UnimplementedBlockError { block_name }),
+    };
+    // 모든 실시간 패킷은 종목코드(업종코드)로 시작
+    let code = match kind {
+        Some(kind) => from_cp949(&body[..kind.code_len().min(body.len())])
+            .trim()
+            .to_string(),
+        None => String::new(),
+    };
+
+    Ok(SiseResponse {
+        tr_index,
+        bc_code,
+        kind,
+        code,
+        block_name,
+        block_len,
+        block_data,
+    })
+}
+
+/**
+ * (tr_index, block_name, block_len, 헤더를 포함한 패킷)
+ */
+unsafe fn read_sise_packet<'a>(lparam: isize) -> (i32, String, i32, &'a [c_char]) {
+    let data_block = lparam as *const OutDataBlock<c_char>;
+    let tr_index = (*data_block).tr_index;
+    let data = (*data_block).p_data;
+    let block_name = from_cp949_ptr((*data).block_name);
+    let block_len = (*data).len;
+    let packet = core::slice::from_raw_parts((*data).sz_data, block_len.max(0) as usize);
+    (tr_index, block_name, block_len, packet)
+}
+
+fn packet_type(packet: &[c_char]) -> Option<String> {
+    if packet.len() < SISE_HEADER_LEN {
+        return None;
+    }
+    Some(from_cp949(&packet[..2]))
+}
+
+/**
+ * 패킷 본문 -> SiseEvent 변환 함수
+ */
+pub type SiseParser = fn(&[c_char]) -> Result<SiseEvent, QvOpenApiError>;
+
+lazy_static! {
+    // 기본 파서는 바뀌지 않으므로 lock 없이 읽음
+    static ref DEFAULT_SISE_PARSERS: HashMap<String, SiseParser> = default_sise_parsers();
+    // register_sise_parser()로 등록한 파서. 기본 파서보다 먼저 찾음
+    static ref CUSTOM_SISE_PARSERS: RwLock<HashMap<String, SiseParser>> =
+        RwLock::new(HashMap::new());
+}
+
+// 등록된 파서가 없으면 패킷마다 lock을 잡지 않도록 함
+static HAS_CUSTOM_SISE_PARSERS: AtomicBool = AtomicBool::new(false);
+
+/**
+ * 실시간 패킷 파서 등록 (같은 패킷유형이 있으면 교체)
+ * SiseKind에 없는 BC 코드도 등록할 수 있음
+ */
+pub fn register_sise_parser(bc_code: &str, parser: SiseParser) {
+    CUSTOM_SISE_PARSERS
+        .write()
+        .unwrap()
+        .insert(bc_code.into(), parser);
+    HAS_CUSTOM_SISE_PARSERS.store(true, Ordering::Release);
+}
+
+/**
+ * 파서를 찾을 수 없는 알 수 없는 BC 코드면 None
+ * SiseKind에 있지만 파서가 없는 패킷은 SiseEvent::Raw
+ */
+fn parse_sise_event(
+    bc_code: &str,
+    kind: Option<SiseKind>,
+    body: &[c_char],
+) -> Option<Result<SiseEvent, QvOpenApiError>> {
+    let custom_parser = if HAS_CUSTOM_SISE_PARSERS.load(Ordering::Acquire) {
+        CUSTOM_SISE_PARSERS.read().unwrap().get(bc_code).copied()
+    } else {
+        None
+    };
+    match custom_parser.or_else(|| DEFAULT_SISE_PARSERS.get(bc_code).copied()) {
+        Some(parser) => Some(parser(body)),
+        None => kind.map(|_| Ok(SiseEvent::Raw(from_cp949(body)))),
+    }
+}
+
+fn default_sise_parsers() -> HashMap<String, SiseParser> {
+    let parsers: [(SiseKind, SiseParser); 39] = [
+        (SiseKind::H1, |body| Ok(SiseEvent::Hoga(parse_h1(body)?))),
+        (SiseKind::K3, |body| Ok(SiseEvent::Hoga(parse_k3(body)?))),
+        (SiseKind::H2, |body| {
+            Ok(SiseEvent::HogaTotal(parse_h2(body)?))
+        }),
+        (SiseKind::K4, |body| {
+            Ok(SiseEvent::HogaTotal(parse_k4(body)?))
+        }),
+        (SiseKind::H3, |body| {
+            Ok(SiseEvent::ExpectedHoga(parse_h3(body)?))
+        }),
+        (SiseKind::K5, |body| {
+            Ok(SiseEvent::ExpectedHoga(parse_k5(body)?))
+        }),
+        (SiseKind::J8, |body| Ok(SiseEvent::Tick(parse_j8(body)?))),
+        (SiseKind::K8, |body| Ok(SiseEvent::Tick(parse_k8(body)?))),
+        (SiseKind::F1, |body| {
+            Ok(SiseEvent::DerivativesHoga(parse_f1(body)?))
+        }),
+        (SiseKind::F3, |body| {
+            Ok(SiseEvent::FuturesTheory(parse_f3(body)?))
+        }),
+        (SiseKind::F4, |body| {
+            Ok(SiseEvent::OpenInterest(parse_f4(body)?))
+        }),
+        (SiseKind::F8, |body| {
+            Ok(SiseEvent::FuturesTick(parse_f8(body)?))
+        }),
+        (SiseKind::Q1, |body| {
+            Ok(SiseEvent::DerivativesHoga(parse_q1(body)?))
+        }),
+        (SiseKind::Q2, |body| {
+            Ok(SiseEvent::SpreadTick(parse_q2(body)?))
+        }),
+        (SiseKind::O1, |body| {
+            Ok(SiseEvent::DerivativesHoga(parse_o1(body)?))
+        }),
+        (SiseKind::O2, |body| {
+            Ok(SiseEvent::OptionTick(Box::new(parse_o2(body)?)))
+        }),
+        (SiseKind::O3, |body| {
+            Ok(SiseEvent::OptionTheory(parse_o3(body)?))
+        }),
+        (SiseKind::O4, |body| {
+            Ok(SiseEvent::OpenInterest(parse_o4(body)?))
+        }),
+        (SiseKind::VH, |body| {
+            Ok(SiseEvent::StockFuturesHoga(Box::new(parse_vh(body)?)))
+        }),
+        (SiseKind::VC, |body| {
+            Ok(SiseEvent::StockFuturesTick(Box::new(parse_vc(body)?)))
+        }),
+        (SiseKind::VV, |body| {
+            Ok(SiseEvent::StockFuturesTheory(parse_vv(body)?))
+        }),
+        (SiseKind::VM, |body| {
+            Ok(SiseEvent::OpenInterest(parse_vm(body)?))
+        }),
+        (SiseKind::V7, |body| {
+            Ok(SiseEvent::StockFuturesHoga(Box::new(parse_v7(body)?)))
+        }),
+        (SiseKind::V8, |body| {
+            Ok(SiseEvent::StockFuturesSpreadTick(Box::new(parse_v8(body)?)))
+        }),
+        (SiseKind::EC, |body| Ok(SiseEvent::ElwTick(parse_ec(body)?))),
+        (SiseKind::EH, |body| {
+            Ok(SiseEvent::ElwHoga(Box::new(parse_eh(body)?)))
+        }),
+        (SiseKind::EV, |body| {
+            Ok(SiseEvent::ElwTheory(parse_ev(body)?))
+        }),
+        (SiseKind::EL, |body| {
+            Ok(SiseEvent::ElwIndicator(parse_el(body)?))
+        }),
+        (SiseKind::ET, |body| {
+            Ok(SiseEvent::ElwTrader(Box::new(parse_et(body)?)))
+        }),
+        (SiseKind::FE, |body| {
+            Ok(SiseEvent::ExpectedPrice(parse_fe(body)?))
+        }),
+        (SiseKind::OE, |body| {
+            Ok(SiseEvent::ExpectedPrice(parse_oe(body)?))
+        }),
+        (SiseKind::VE, |body| {
+            Ok(SiseEvent::ExpectedPrice(parse_ve(body)?))
+        }),
+        (SiseKind::J0, |body| Ok(SiseEvent::EtfNav(parse_j0(body)?))),
+        (SiseKind::J1, |body| Ok(SiseEvent::EtfNav(parse_j1(body)?))),
+        (SiseKind::F7, |body| {
+            Ok(SiseEvent::PriceLimit(parse_f7(body)?))
+        }),
+        (SiseKind::O7, |body| {
+            Ok(SiseEvent::PriceLimit(parse_o7(body)?))
+        }),
+        (SiseKind::VI, |body| {
+            Ok(SiseEvent::PriceLimit(parse_vi(body)?))
+        }),
+        (SiseKind::U1, |body| {
+            Ok(SiseEvent::IndexTick(parse_u1(body)?))
+        }),
+        (SiseKind::K1, |body| {
+            Ok(SiseEvent::IndexTick(parse_k1(body)?))
+        }),
+    ];
+    parsers
+        .into_iter()
+        .map(|(kind, parser)| (kind.as_str().to_string(), parser))
+        .collect()
+}
+
+/**
+ * 패킷 본문을 고정길이 구조체로 해석
+ * 구조체 필드는 모두 c_char 배열이므로 정렬 문제는 없고, 길이만 확인
+ */
+fn cast_packet<T>(kind: SiseKind, body: &[c_char]) -> Result<&T, QvOpenApiError> {
+    cast_packet_as(kind.as_str(), body)
+}
+
+fn cast_packet_as<'a, T>(bc_code: &str, body: &'a [c_char]) -> Result<&'a T, QvOpenApiError> {
+    if body.len() < size_of::<T>() {
+        return Err(QvOpenApiError::SisePacketLengthError {
+            bc_code: bc_code.into(),
+            len: body.len(),
+        });
+    }
+    unsafe { Ok(&*(body.as_ptr() as *const T)) }
+}
+
+/**
+ * 실시간 등록 (wmcaAttach)
+ */
+pub struct AttachRequest {
+    pub subscription_id: SubscriptionId,
+    pub kind: SiseKind,
+    pub codes: Vec<String>,
+}
+
+impl QvOpenApiRequest for AttachRequest {
+    fn before_post(&self) -> Result<(), QvOpenApiError> {
+        self.kind.pack_codes(&self.codes)?;
+        wmca_lib::assert_connected()
+    }
+
+    fn call_lib(&self, _tr_index: i32, hwnd: isize) -> Result<(), QvOpenApiError> {
+        // 보내기 전에 접속이 끊긴 경우. 재접속 때 다시 등록되지 않도록 on_call_failed에서 구독을 되돌림
+        wmca_lib::assert_connected()?;
+        wmca_lib::subscribe(hwnd, self.kind, &self.codes)
+    }
+
+    fn get_tr_code(&self) -> &str {
+        self.kind.as_str()
+    }
+
+    fn on_call_failed(
+        &self,
+        handler: &QvOpenApiClientMessageHandler,
+        _tr_index: i32,
+        err: &QvOpenApiError,
+    ) {
+        let (detach_codes, reattach) =
+            handler
+                .subscriptions
+                .fail(self.subscription_id, &self.codes, err);
+        if !detach_codes.is_empty() {
+            handler.post_request(Arc::new(DetachRequest {
+                kind: self.kind,
+                codes: detach_codes,
+            }));
+        }
+        for (subscription_id, codes) in reattach {
+            handler.post_request(Arc::new(AttachRequest {
+                subscription_id,
+                kind: self.kind,
+                codes,
+            }));
+        }
+    }
+}
+
+/**
+ * 실시간 취소 (wmcaDetach)
+ */
+pub struct DetachRequest {
+    pub kind: SiseKind,
+    pub codes: Vec<String>,
+}
+
+impl QvOpenApiRequest for DetachRequest {
+    fn before_post(&self) -> Result<(), QvOpenApiError> {
+        Ok(())
+    }
+
+    fn call_lib(&self, _tr_index: i32, hwnd: isize) -> Result<(), QvOpenApiError> {
+        if !wmca_lib::is_connected()? {
+            return Ok(());
+        }
+        wmca_lib::unsubscribe(hwnd, self.kind, &self.codes)
+    }
+
+    fn get_tr_code(&self) -> &str {
+        self.kind.as_str()
+    }
+}
@@ -0,0 +1,160 @@
+use std::ffi::c_char;
+
+use serde::Serialize;
+
+use crate::error::*;
+use crate::utils::{parse_decimal, parse_number, parse_string};
+use qvopenapi_bindings::{Td2OutBlock, Td3OutBlock};
+
+use super::{cast_packet_as, packet_type, read_sise_packet, SISE_HEADER_LEN};
+
+pub const BC_CODE_EXECUTION: &str = "d2";
+pub const BC_CODE_ORDER_ACK: &str = "d3";
+
+/**
+ * 주문 체결/접수 통보
+ * 로그인한 사용자의 주문에 대해 등록 없이 CA_RECEIVESISE로 수신
+ */
+#[derive(Debug, Clone, Serialize)]
+pub enum OrderNotice {
+    Execution(ExecutionReport), //d2
+    Ack(OrderAck),              //d3
+}
+
+impl OrderNotice {
+    pub fn get_order_no(&self) -> Option<i64> {
+        match self {
+            OrderNotice::Execution(report) => report.orderno,
+            OrderNotice::Ack(ack) => ack.orderno,
+        }
+    }
+
+    pub fn set_tr_index(&mut self, tr_index: Option<i32>) {
+        match self {
+            OrderNotice::Execution(report) => report.tr_index = tr_index,
+            OrderNotice::Ack(ack) => ack.tr_index = tr_index,
+        }
+    }
+}
+
+/**
+ * 체결/접수 통보가 아닌 실시간 패킷이면 None
+ */
+pub fn parse_order_notice(lparam: isize) -> Result<Option<OrderNotice>, QvOpenApiError> {
+    let (_, _, _, packet) = unsafe { read_sise_packet(lparam) };
+    let body = match packet.get(SISE_HEADER_LEN..) {
+        Some(body) => body,
+        None => return Ok(None),
+    };
+
+    match packet_type(packet).as_deref() {
+        Some(BC_CODE_EXECUTION) => Ok(Some(OrderNotice::Execution(parse_d2(body)?))),
+        Some(BC_CODE_ORDER_ACK) => Ok(Some(OrderNotice::Ack(parse_d3(body)?))),
+        _ => Ok(None),
+    }
+}
+
+/**
+ * 체결 통보 (d2)
+ */
+pub fn parse_d2(body: &[c_char]) -> Result<ExecutionReport, QvOpenApiError> {
+    let res: &Td2OutBlock = cast_packet_as(BC_CODE_EXECUTION, body)?;
+    Ok(ExecutionReport {
+        tr_index: None,
+        userid: parse_string(&res.userid)?,
+        itemgb: parse_string(&res.itemgb)?,
+        accountno: parse_string(&res.accountno)?,
+        orderno: parse_number(&res.orderno)?,
+        issuecd: parse_string(&res.issuecd)?,
+        slbygb: parse_string(&res.slbygb)?,
+        concgty: parse_number(&res.concgty)?,
+        concprc: parse_decimal(&res.concprc)?,
+        conctime: parse_string(&res.conctime)?,
+        ucgb: parse_string(&res.ucgb)?,
+        rejgb: parse_string(&res.rejgb)?,
+        fundcode: parse_string(&res.fundcode)?,
+        sin_gb: parse_string(&res.sin_gb)?,
+        loan_date: parse_string(&res.loan_date)?,
+        ato_ord_tpe_chg: parse_string(&res.ato_ord_tpe_chg)?,
+    })
+}
+
+/**
+ * 주문 접수 통보 (d3)
+ */
+pub fn parse_d3(body: &[c_char]) -> Result<OrderAck, QvOpenApiError> {
+    let res: &Td3OutBlock = cast_packet_as(BC_CODE_ORDER_ACK, body)?;
+    Ok(OrderAck {
+        tr_index: None,
+        userid: parse_string(&res.userid)?,
+        itemgb: parse_string(&res.itemgb)?,
+        accountno: parse_string(&res.accountno)?,
+        orderno: parse_number(&res.orderno)?,
+        orgordno: parse_number(&res.orgordno)?,
+        ordercd: parse_string(&res.ordercd)?,
+        issuecd: parse_string(&res.issuecd)?,
+        issuename: parse_string(&res.issuename)?,
+        slbygb: parse_string(&res.slbygb)?,
+        order_type: parse_string(&res.order_type)?,
+        ordergty: parse_number(&res.ordergty)?,
+        orderprc: parse_decimal(&res.orderprc)?,
+        procnm: parse_string(&res.procnm)?,
+        commcd: parse_string(&res.commcd)?,
+        order_cond: parse_string(&res.order_cond)?,
+        fundcode: parse_string(&res.fundcode)?,
+        sin_gb: parse_string(&res.sin_gb)?,
+        order_time: parse_string(&res.order_time)?,
+        loan_date: parse_string(&res.loan_date)?,
+    })
+}
+
+/**
+ * 체결 통보
+ * tr_index는 같은 주문번호를 돌려준 주문 TR의 index (이 클라이언트가 낸 주문이 아니면 None)
+ */
+#[derive(Debug, Clone, Serialize)]
+pub struct ExecutionReport {
+    pub tr_index: Option<i32>,
+    pub userid: String,          //사용자ID
+    pub itemgb: String,          //ITEM구분
+    pub accountno: String,       //계좌번호
+    pub orderno: Option<i64>,    //주문번호
+    pub issuecd: String,         //종목코드
+    pub slbygb: String,          //매도수구분
+    pub concgty: Option<i64>,    //체결수량
+    pub concprc: Option<f64>,    //체결가격
+    pub conctime: String,        //체결시간
+    pub ucgb: String,            //정정취소구분
+    pub rejgb: String,           //거부구분
+    pub fundcode: String,        //펀드코드
+    pub sin_gb: String,          //신용구분
+    pub loan_date: String,       //대출일자
+    pub ato_ord_tpe_chg: String, //선물옵션주문유형변경여부
+}
+
+/**
+ * 주문 접수 통보
+ */
+#[derive(Debug, Clone, Serialize)]
+pub struct OrderAck {
+    pub tr_index: Option<i32>,
+    pub userid: String,        //USERID
+    pub itemgb: String,        //ITEM구분
+    pub accountno: String,     //계좌번호
+    pub orderno: Option<i64>,  //주문번호
+    pub orgordno: Option<i64>, //원주문번호
+    pub ordercd: String,       //주문구분
+    pub issuecd: String,       //종목코드
+    pub issuename: String,     //종목명
+    pub slbygb: String,        //매매구분
+    pub order_type: String,    //주문유형
+    pub ordergty: Option<i64>, //주문수량
+    pub orderprc: Option<f64>, //주문단가
+    pub procnm: String,        //처리구분
+    pub commcd: String,        //매체구분
+    pub order_cond: String,    //주문조건1
+    pub fundcode: String,      //펀드코드
+    pub sin_gb: String,        //신용구분
+    pub order_time: String,    //주문시간
+    pub loan_date: String,     //대출일자
+}
@@ -0,0 +1,611 @@
+use std::ffi::c_char;
+
+use serde::Serialize;
+
+use crate::utils::{parse_number, parse_scaled, parse_sign, parse_string};
+use crate::{error::*, models::*};
+use qvopenapi_bindings::{
+    Tv7OutBlock, Tv8OutBlock, TvCOutBlock, TvEOutBlock, TvHOutBlock, TvIOutBlock, TvMOutBlock,
+    TvVOutBlock,
+};
+
+use super::cast_packet;
+
+/**
+ * 주식선물 호가 (vH)
+ */
+pub fn parse_vh(body: &[c_char]) -> Result<StockFuturesHoga, QvOpenApiError> {
+    let res: &TvHOutBlock = cast_packet(SiseKind::VH, body)?;
+    Ok(StockFuturesHoga {
+        code: parse_string(&res.fuitem)?,
+        hotime: parse_string(&res.futime)?,
+        levels: vec![
+            parse_stock_futures_hoga_level(
+                &res.offer,
+                &res.bid,
+                &res.offerjan,
+                &res.bidjan,
+                &res.offersu,
+                &res.bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S2offer,
+                &res.S2bid,
+                &res.S2offerjan,
+                &res.S2bidjan,
+                &res.S2offersu,
+                &res.S2bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S3offer,
+                &res.S3bid,
+                &res.S3offerjan,
+                &res.S3bidjan,
+                &res.S3offersu,
+                &res.S3bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S4offer,
+                &res.S4bid,
+                &res.S4offerjan,
+                &res.S4bidjan,
+                &res.S4offersu,
+                &res.S4bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S5offer,
+                &res.S5bid,
+                &res.S5offerjan,
+                &res.S5bidjan,
+                &res.S5offersu,
+                &res.S5bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S6offer,
+                &res.S6bid,
+                &res.S6offerjan,
+                &res.S6bidjan,
+                &res.S6offersu,
+                &res.S6bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S7offer,
+                &res.S7bid,
+                &res.S7offerjan,
+                &res.S7bidjan,
+                &res.S7offersu,
+                &res.S7bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S8offer,
+                &res.S8bid,
+                &res.S8offerjan,
+                &res.S8bidjan,
+                &res.S8offersu,
+                &res.S8bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S9offer,
+                &res.S9bid,
+                &res.S9offerjan,
+                &res.S9bidjan,
+                &res.S9offersu,
+                &res.S9bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S0offer,
+                &res.S0bid,
+                &res.S0offerjan,
+                &res.S0bidjan,
+                &res.S0offersu,
+                &res.S0bidsu,
+            )?,
+        ],
+        tofferjan: parse_number(&res.tofferjan)?,
+        tobidjan: parse_number(&res.tobidjan)?,
+        toffersu: parse_number(&res.toffersu)?,
+        tbidsu: parse_number(&res.tbidsu)?,
+    })
+}
+
+/**
+ * 주식선물 체결 (vC)
+ */
+pub fn parse_vc(body: &[c_char]) -> Result<StockFuturesTick, QvOpenApiError> {
+    let res: &TvCOutBlock = cast_packet(SiseKind::VC, body)?;
+    Ok(StockFuturesTick {
+        fuitem: parse_string(&res.fuitem)?,
+        futime: parse_string(&res.futime)?,
+        jgubun: parse_string(&res.jgubun)?,
+        fusign: parse_sign(&res.fusign)?,
+        fuchange: parse_number(&res.fuchange)?,
+        fucurr: parse_number(&res.fucurr)?,
+        fuopen: parse_number(&res.fuopen)?,
+        fuhigh: parse_number(&res.fuhigh)?,
+        fulow: parse_number(&res.fulow)?,
+        fuvol: parse_number(&res.fuvol)?,
+        fuvolall: parse_number(&res.fuvolall)?,
+        fuvalall: parse_number(&res.fuvalall)?,
+        openyak: parse_number(&res.openyak)?,
+        jandatecnt: parse_number(&res.jandatecnt)?,
+        levels: vec![
+            parse_stock_futures_hoga_level(
+                &res.offer,
+                &res.bid,
+                &res.offerjan,
+                &res.bidjan,
+                &res.offersu,
+                &res.bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S2offer,
+                &res.S2bid,
+                &res.S2offerjan,
+                &res.S2bidjan,
+                &res.S2offersu,
+                &res.S2bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S3offer,
+                &res.S3bid,
+                &res.S3offerjan,
+                &res.S3bidjan,
+                &res.S3offersu,
+                &res.S3bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S4offer,
+                &res.S4bid,
+                &res.S4offerjan,
+                &res.S4bidjan,
+                &res.S4offersu,
+                &res.S4bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S5offer,
+                &res.S5bid,
+                &res.S5offerjan,
+                &res.S5bidjan,
+                &res.S5offersu,
+                &res.S5bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S6offer,
+                &res.S6bid,
+                &res.S6offerjan,
+                &res.S6bidjan,
+                &res.S6offersu,
+                &res.S6bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S7offer,
+                &res.S7bid,
+                &res.S7offerjan,
+                &res.S7bidjan,
+                &res.S7offersu,
+                &res.S7bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S8offer,
+                &res.S8bid,
+                &res.S8offerjan,
+                &res.S8bidjan,
+                &res.S8offersu,
+                &res.S8bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S9offer,
+                &res.S9bid,
+                &res.S9offerjan,
+                &res.S9bidjan,
+                &res.S9offersu,
+                &res.S9bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S0offer,
+                &res.S0bid,
+                &res.S0offerjan,
+                &res.S0bidjan,
+                &res.S0offersu,
+                &res.S0bidsu,
+            )?,
+        ],
+        tofferjan: parse_number(&res.tofferjan)?,
+        tobidjan: parse_number(&res.tobidjan)?,
+        toffersu: parse_number(&res.toffersu)?,
+        tbidsu: parse_number(&res.tbidsu)?,
+        chrate: parse_scaled(&res.chrate, 2)?,
+        basis: parse_number(&res.basis)?,
+        grate: parse_number(&res.grate)?,
+        gratio: parse_scaled(&res.gratio, 2)?,
+        preopenyak: parse_number(&res.preopenyak)?,
+        bp_jgubun: parse_string(&res.bp_jgubun)?,
+        fspvolall: parse_number(&res.fspvolall)?,
+    })
+}
+
+/**
+ * 주식선물 이론가 (vV)
+ */
+pub fn parse_vv(body: &[c_char]) -> Result<StockFuturesTheory, QvOpenApiError> {
+    let res: &TvVOutBlock = cast_packet(SiseKind::VV, body)?;
+    Ok(StockFuturesTheory {
+        fuitem: parse_string(&res.fuitem)?,
+        theoryprice: parse_number(&res.theoryprice)?,
+        theorytime: parse_string(&res.theorytime)?,
+        grate: parse_number(&res.grate)?,
+        gratio: parse_scaled(&res.gratio, 2)?,
+    })
+}
+
+/**
+ * 주식선물 미결제약정 (vM)
+ */
+pub fn parse_vm(body: &[c_char]) -> Result<OpenInterest, QvOpenApiError> {
+    let res: &TvMOutBlock = cast_packet(SiseKind::VM, body)?;
+    Ok(OpenInterest {
+        code: parse_string(&res.fuitem)?,
+        chetime: parse_string(&res.chetime)?,
+        openyak: parse_number(&res.openyak)?,
+        preopenyak: parse_number(&res.preopenyak)?,
+    })
+}
+
+/**
+ * 주식선물 스프레드 호가 (v7)
+ */
+pub fn parse_v7(body: &[c_char]) -> Result<StockFuturesHoga, QvOpenApiError> {
+    let res: &Tv7OutBlock = cast_packet(SiseKind::V7, body)?;
+    Ok(StockFuturesHoga {
+        code: parse_string(&res.fspitem)?,
+        hotime: parse_string(&res.fsptime)?,
+        levels: vec![
+            parse_stock_futures_hoga_level(
+                &res.offer,
+                &res.bid,
+                &res.offerjan,
+                &res.bidjan,
+                &res.offersu,
+                &res.bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S2offer,
+                &res.S2bid,
+                &res.S2offerjan,
+                &res.S2bidjan,
+                &res.S2offersu,
+                &res.S2bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S3offer,
+                &res.S3bid,
+                &res.S3offerjan,
+                &res.S3bidjan,
+                &res.S3offersu,
+                &res.S3bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S4offer,
+                &res.S4bid,
+                &res.S4offerjan,
+                &res.S4bidjan,
+                &res.S4offersu,
+                &res.S4bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S5offer,
+                &res.S5bid,
+                &res.S5offerjan,
+                &res.S5bidjan,
+                &res.S5offersu,
+                &res.S5bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S6offer,
+                &res.S6bid,
+                &res.S6offerjan,
+                &res.S6bidjan,
+                &res.S6offersu,
+                &res.S6bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S7offer,
+                &res.S7bid,
+                &res.S7offerjan,
+                &res.S7bidjan,
+                &res.S7offersu,
+                &res.S7bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S8offer,
+                &res.S8bid,
+                &res.S8offerjan,
+                &res.S8bidjan,
+                &res.S8offersu,
+                &res.S8bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S9offer,
+                &res.S9bid,
+                &res.S9offerjan,
+                &res.S9bidjan,
+                &res.S9offersu,
+                &res.S9bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S0offer,
+                &res.S0bid,
+                &res.S0offerjan,
+                &res.S0bidjan,
+                &res.S0offersu,
+                &res.S0bidsu,
+            )?,
+        ],
+        tofferjan: parse_number(&res.tofferjan)?,
+        tobidjan: parse_number(&res.tobidjan)?,
+        toffersu: parse_number(&res.toffersu)?,
+        tbidsu: parse_number(&res.tbidsu)?,
+    })
+}
+
+/**
+ * 주식선물 스프레드 체결 (v8)
+ */
+pub fn parse_v8(body: &[c_char]) -> Result<StockFuturesSpreadTick, QvOpenApiError> {
+    let res: &Tv8OutBlock = cast_packet(SiseKind::V8, body)?;
+    Ok(StockFuturesSpreadTick {
+        fspitem: parse_string(&res.fspitem)?,
+        fsptime: parse_string(&res.fsptime)?,
+        jgubun: parse_string(&res.jgubun)?,
+        fspsign: parse_sign(&res.fspsign)?,
+        fspchange: parse_number(&res.fspchange)?,
+        fspcurr: parse_number(&res.fspcurr)?,
+        fspcurr1: parse_number(&res.fspcurr1)?,
+        fspcurr2: parse_number(&res.fspcurr2)?,
+        fspopen: parse_number(&res.fspopen)?,
+        fsphigh: parse_number(&res.fsphigh)?,
+        fsplow: parse_number(&res.fsplow)?,
+        fspvol: parse_number(&res.fspvol)?,
+        fspvolall: parse_number(&res.fspvolall)?,
+        fspvalall: parse_number(&res.fspvalall)?,
+        levels: vec![
+            parse_stock_futures_hoga_level(
+                &res.offer,
+                &res.bid,
+                &res.offerjan,
+                &res.bidjan,
+                &res.offersu,
+                &res.bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S2offer,
+                &res.S2bid,
+                &res.S2offerjan,
+                &res.S2bidjan,
+                &res.S2offersu,
+                &res.S2bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S3offer,
+                &res.S3bid,
+                &res.S3offerjan,
+                &res.S3bidjan,
+                &res.S3offersu,
+                &res.S3bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S4offer,
+                &res.S4bid,
+                &res.S4offerjan,
+                &res.S4bidjan,
+                &res.S4offersu,
+                &res.S4bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S5offer,
+                &res.S5bid,
+                &res.S5offerjan,
+                &res.S5bidjan,
+                &res.S5offersu,
+                &res.S5bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S6offer,
+                &res.S6bid,
+                &res.S6offerjan,
+                &res.S6bidjan,
+                &res.S6offersu,
+                &res.S6bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S7offer,
+                &res.S7bid,
+                &res.S7offerjan,
+                &res.S7bidjan,
+                &res.S7offersu,
+                &res.S7bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S8offer,
+                &res.S8bid,
+                &res.S8offerjan,
+                &res.S8bidjan,
+                &res.S8offersu,
+                &res.S8bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S9offer,
+                &res.S9bid,
+                &res.S9offerjan,
+                &res.S9bidjan,
+                &res.S9offersu,
+                &res.S9bidsu,
+            )?,
+            parse_stock_futures_hoga_level(
+                &res.S0offer,
+                &res.S0bid,
+                &res.S0offerjan,
+                &res.S0bidjan,
+                &res.S0offersu,
+                &res.S0bidsu,
+            )?,
+        ],
+        tofferjan: parse_number(&res.tofferjan)?,
+        tobidjan: parse_number(&res.tobidjan)?,
+        toffersu: parse_number(&res.toffersu)?,
+        tbidsu: parse_number(&res.tbidsu)?,
+        chrate: parse_scaled(&res.chrate, 2)?,
+        bp_jgubun: parse_string(&res.bp_jgubun)?,
+    })
+}
+
+/**
+ * 주식선물 예상체결 (vE)
+ */
+pub fn parse_ve(body: &[c_char]) -> Result<ExpectedPrice, QvOpenApiError> {
+    let res: &TvEOutBlock = cast_packet(SiseKind::VE, body)?;
+    Ok(ExpectedPrice {
+        code: parse_string(&res.expcode)?,
+        time: parse_string(&res.time)?,
+        dongsi: parse_string(&res.dongsi)?,
+        eqsign: parse_sign(&res.eqsign)?,
+        eqprice: parse_scaled(&res.eqprice, 0)?,
+        eqchange: parse_scaled(&res.eqchange, 0)?,
+        eqchrate: parse_scaled(&res.eqchrate, 2)?,
+    })
+}
+
+/**
+ * 주식선물 단계별 상하한가 (vI)
+ */
+pub fn parse_vi(body: &[c_char]) -> Result<PriceLimit, QvOpenApiError> {
+    let res: &TvIOutBlock = cast_packet(SiseKind::VI, body)?;
+    Ok(PriceLimit {
+        code: parse_string(&res.fuitem)?,
+        time: parse_string(&res.futime)?,
+        exlmtstep: parse_string(&res.exlmtstep)?,
+        exlmtgb: parse_string(&res.exlmtgb)?,
+        uplmtgb: parse_string(&res.uplmtgb)?,
+        dnlmtgb: parse_string(&res.dnlmtgb)?,
+        uplmtprice: parse_scaled(&res.uplmtprice, 0)?,
+        dnlmtprice: parse_scaled(&res.dnlmtprice, 0)?,
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+fn parse_stock_futures_hoga_level(
+    offer: &[c_char],
+    bid: &[c_char],
+    offerjan: &[c_char],
+    bidjan: &[c_char],
+    offersu: &[c_char],
+    bidsu: &[c_char],
+) -> Result<StockFuturesHogaLevel, QvOpenApiError> {
+    Ok(StockFuturesHogaLevel {
+        offer: parse_number(offer)?,
+        bid: parse_number(bid)?,
+        offerjan: parse_number(offerjan)?,
+        bidjan: parse_number(bidjan)?,
+        offersu: parse_number(offersu)?,
+        bidsu: parse_number(bidsu)?,
+    })
+}
+
+/**
+ * 주식선물/주식선물 스프레드 10단계 호가 (vH, v7)
+ * levels[0]이 우선호가, levels[9]가 10차호가
+ */
+#[derive(Debug, Clone, Serialize)]
+pub struct StockFuturesHoga {
+    pub code: String,                       //종목코드
+    pub hotime: String,                     //시간
+    pub levels: Vec<StockFuturesHogaLevel>, //1~10차 호가
+    pub tofferjan: Option<i64>,             //총매도호가잔량
+    pub tobidjan: Option<i64>,              //총매수호가잔량
+    pub toffersu: Option<i64>,              //총매도건수
+    pub tbidsu: Option<i64>,                //총매수건수
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StockFuturesHogaLevel {
+    pub offer: Option<i64>,    //매도호가
+    pub bid: Option<i64>,      //매수호가
+    pub offerjan: Option<i64>, //매도잔량
+    pub bidjan: Option<i64>,   //매수잔량
+    pub offersu: Option<i64>,  //매도건수
+    pub bidsu: Option<i64>,    //매수건수
+}
+
+/**
+ * 주식선물 체결 (10단계 호가 포함)
+ * 가격은 원 단위 정수
+ */
+#[derive(Debug, Clone, Serialize)]
+pub struct StockFuturesTick {
+    pub fuitem: String,                     //종목코드
+    pub futime: String,                     //시간
+    pub jgubun: String,                     //장운용
+    pub fusign: Option<PriceSign>,          //전일대비 부호
+    pub fuchange: Option<i64>,              //전일대비
+    pub fucurr: Option<i64>,                //현재가
+    pub fuopen: Option<i64>,                //시가
+    pub fuhigh: Option<i64>,                //고가
+    pub fulow: Option<i64>,                 //저가
+    pub fuvol: Option<i64>,                 //체결수량
+    pub fuvolall: Option<i64>,              //누적 체결수량
+    pub fuvalall: Option<i64>,              //누적거래대금
+    pub openyak: Option<i64>,               //미결제약정수량
+    pub jandatecnt: Option<i64>,            //잔존일수
+    pub levels: Vec<StockFuturesHogaLevel>, //1~10차 호가
+    pub tofferjan: Option<i64>,             //총매도호가잔량
+    pub tobidjan: Option<i64>,              //총매수호가잔량
+    pub toffersu: Option<i64>,              //총매도건수
+    pub tbidsu: Option<i64>,                //총매수건수
+    pub chrate: Option<f64>,                //등락률
+    pub basis: Option<i64>,                 //베이시스
+    pub grate: Option<i64>,                 //괴리도
+    pub gratio: Option<f64>,                //괴리율
+    pub preopenyak: Option<i64>,            //미결제약정전일비
+    pub bp_jgubun: String,                  //BP용 장구분
+    pub fspvolall: Option<i64>,             //스프레드 체결수량
+}
+
+/**
+ * 주식선물 이론가와 괴리
+ */
+#[derive(Debug, Clone, Serialize)]
+pub struct StockFuturesTheory {
+    pub fuitem: String,           //종목코드
+    pub theoryprice: Option<i64>, //선물 이론가
+    pub theorytime: String,       //이론가 시간
+    pub grate: Option<i64>,       //괴리도
+    pub gratio: Option<f64>,      //괴리율
+}
+
+/**
+ * 주식선물 스프레드 체결 (10단계 호가 포함)
+ */
+#[derive(Debug, Clone, Serialize)]
+pub struct StockFuturesSpreadTick {
+    pub fspitem: String,                    //스프레드종목코드
+    pub fsptime: String,                    //시간
+    pub jgubun: String,                     //장운용
+    pub fspsign: Option<PriceSign>,         //전일대비 부호
+    pub fspchange: Option<i64>,             //전일대비
+    pub fspcurr: Option<i64>,               //현재가
+    pub fspcurr1: Option<i64>,              //의제약정가-근월물
+    pub fspcurr2: Option<i64>,              //의제약정가-원월물
+    pub fspopen: Option<i64>,               //시가
+    pub fsphigh: Option<i64>,               //고가
+    pub fsplow: Option<i64>,                //저가
+    pub fspvol: Option<i64>,                //체결수량
+    pub fspvolall: Option<i64>,             //누적 체결수량
+    pub fspvalall: Option<i64>,             //누적거래대금
+    pub levels: Vec<StockFuturesHogaLevel>, //1~10차 호가
+    pub tofferjan: Option<i64>,             //총매도호가잔량
+    pub tobidjan: Option<i64>,              //총매수호가잔량
+    pub toffersu: Option<i64>,              //총매도건수
+    pub tbidsu: Option<i64>,                //총매수건수
+    pub chrate: Option<f64>,                //등락률
+    pub bp_jgubun: String,                  //BP용 장구분
+}
@@ -0,0 +1,148 @@
+use std::ffi::c_char;
+
+use serde::Serialize;
+
+use crate::utils::{parse_decimal, parse_number, parse_sign, parse_string};
+use crate::{error::*, models::*};
+use qvopenapi_bindings::{Tj0OutBlock, Tj1OutBlock, Tj8OutBlock, Tk8OutBlock};
+
+use super::cast_packet;
+
+/**
+ * 거래소 체결 (j8)
+ */
+pub fn parse_j8(body: &[c_char]) -> Result<Tick, QvOpenApiError> {
+    let res: &Tj8OutBlock = cast_packet(SiseKind::J8, body)?;
+    Ok(Tick {
+        code: parse_string(&res.code)?,
+        time: parse_string(&res.time)?,
+        price: parse_number(&res.price)?,
+        sign: parse_sign(&res.sign)?,
+        change: parse_number(&res.change)?,
+        chrate: parse_decimal(&res.chrate)?,
+        volume: parse_number(&res.movolume)?,
+        cum_volume: parse_number(&res.volume)?,
+        value: parse_number(&res.value)?,
+        open: parse_number(&res.open)?,
+        high: parse_number(&res.high)?,
+        low: parse_number(&res.low)?,
+        offer: parse_number(&res.offer)?,
+        bid: parse_number(&res.bid)?,
+        volrate: parse_decimal(&res.volrate)?,
+        avgprice: parse_number(&res.avgprice)?,
+        janggubun: parse_string(&res.janggubun)?,
+    })
+}
+
+/**
+ * 코스닥 체결 (k8)
+ */
+pub fn parse_k8(body: &[c_char]) -> Result<Tick, QvOpenApiError> {
+    let res: &Tk8OutBlock = cast_packet(SiseKind::K8, body)?;
+    Ok(Tick {
+        code: parse_string(&res.code)?,
+        time: parse_string(&res.time)?,
+        price: parse_number(&res.price)?,
+        sign: parse_sign(&res.sign)?,
+        change: parse_number(&res.change)?,
+        chrate: parse_decimal(&res.chrate)?,
+        volume: parse_number(&res.movolume)?,
+        cum_volume: parse_number(&res.volume)?,
+        value: parse_number(&res.value)?,
+        open: parse_number(&res.open)?,
+        high: parse_number(&res.high)?,
+        low: parse_number(&res.low)?,
+        offer: parse_number(&res.offer)?,
+        bid: parse_number(&res.bid)?,
+        volrate: parse_decimal(&res.volrate)?,
+        avgprice: parse_number(&res.avgprice)?,
+        janggubun: parse_string(&res.janggubun)?,
+    })
+}
+
+/**
+ * 거래소 ETF NAV (j0)
+ */
+pub fn parse_j0(body: &[c_char]) -> Result<EtfNav, QvOpenApiError> {
+    let res: &Tj0OutBlock = cast_packet(SiseKind::J0, body)?;
+    Ok(EtfNav {
+        code: parse_string(&res.code)?,
+        time: parse_string(&res.time)?,
+        nsign: parse_sign(&res.nsign)?,
+        nchange: parse_decimal(&res.nchange)?,
+        nav: parse_decimal(&res.nav)?,
+        nopen: parse_decimal(&res.nopen)?,
+        nhigh: parse_decimal(&res.nhigh)?,
+        nlow: parse_decimal(&res.nlow)?,
+        tsign: parse_string(&res.tsign)?,
+        terror: parse_decimal(&res.terror)?,
+        gsign: parse_string(&res.gsign)?,
+        grate: parse_decimal(&res.grate)?,
+    })
+}
+
+/**
+ * 코스닥 ETF NAV (j1)
+ */
+pub fn parse_j1(body: &[c_char]) -> Result<EtfNav, QvOpenApiError> {
+    let res: &Tj1OutBlock = cast_packet(SiseKind::J1, body)?;
+    Ok(EtfNav {
+        code: parse_string(&res.code)?,
+        time: parse_string(&res.time)?,
+        nsign: parse_sign(&res.nsign)?,
+        nchange: parse_decimal(&res.nchange)?,
+        nav: parse_decimal(&res.nav)?,
+        nopen: parse_decimal(&res.nopen)?,
+        nhigh: parse_decimal(&res.nhigh)?,
+        nlow: parse_decimal(&res.nlow)?,
+        tsign: parse_string(&res.tsign)?,
+        terror: parse_decimal(&res.terror)?,
+        gsign: parse_string(&res.gsign)?,
+        grate: parse_decimal(&res.grate)?,
+    })
+}
+
+/**
+ * 주식 체결
+ * volume은 이번 체결의 변동거래량, cum_volume은 당일 누적거래량
+ */
+#[derive(Debug, Clone, Serialize)]
+pub struct Tick {
+    pub code: String,            //종목코드
+    pub time: String,            //시간
+    pub price: Option<i64>,      //현재가
+    pub sign: Option<PriceSign>, //등락부호
+    pub change: Option<i64>,     //등락폭
+    pub chrate: Option<f64>,     //등락률
+    pub volume: Option<i64>,     //변동거래량
+    pub cum_volume: Option<i64>, //거래량
+    pub value: Option<i64>,      //거래대금
+    pub open: Option<i64>,       //시가
+    pub high: Option<i64>,       //고가
+    pub low: Option<i64>,        //저가
+    pub offer: Option<i64>,      //매도호가
+    pub bid: Option<i64>,        //매수호가
+    pub volrate: Option<f64>,    //거래량전일비
+    pub avgprice: Option<i64>,   //가중평균가
+    pub janggubun: String,       //장구분
+}
+
+/**
+ * ETF 실시간 NAV
+ * 체결 정보가 없으므로 Tick이 아닌 별도 구조체
+ */
+#[derive(Debug, Clone, Serialize)]
+pub struct EtfNav {
+    pub code: String,             //종목코드
+    pub time: String,             //시간
+    pub nsign: Option<PriceSign>, //등락부호
+    pub nchange: Option<f64>,     //등락폭
+    pub nav: Option<f64>,         //NAV현재가
+    pub nopen: Option<f64>,       //NAV시가
+    pub nhigh: Option<f64>,       //NAV고가
+    pub nlow: Option<f64>,        //NAV저가
+    pub tsign: String,            //추적부호
+    pub terror: Option<f64>,      //추적오차율
+    pub gsign: String,            //괴리부호
+    pub grate: Option<f64>,       //괴리율
+}
@@ -0,0 +1,162 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::RwLock,
+};
+
+use serde::Serialize;
+
+use crate::models::*;
+
+// 주문번호(order_noz10)를 돌려주는 주문 TR 출력 블록
+const ORDER_BLOCK_NAMES: [&str; 9] = [
+    BLOCK_NAME_C8101_OUT,
+    BLOCK_NAME_C8102_OUT,
+    BLOCK_NAME_C8103_OUT,
+    BLOCK_NAME_C8104_OUT,
+    BLOCK_NAME_C8141_OUT,
+    BLOCK_NAME_C8142_OUT,
+    BLOCK_NAME_C8143_OUT,
+    BLOCK_NAME_S8301_OUT,
+    BLOCK_NAME_S8302_OUT,
+];
+
+// 주문 TR 응답보다 먼저 온 통보를 보관할 주문번호 수. 다른 매체의 주문 통보는 계속 쌓이므로 제한
+const MAX_PENDING_ORDERS: usize = 1024;
+
+/**
+ * 주문 TR 응답의 주문번호와 d2/d3 통보를 연결
+ * 주문번호는 당일 기준으로만 유일하므로 날짜가 바뀌거나 접속이 끊기면 clear() 필요
+ */
+#[derive(Default)]
+pub struct OrderTracker {
+    state: RwLock<OrderTrackerState>,
+}
+
+#[derive(Default)]
+struct OrderTrackerState {
+    orders: HashMap<i64, TrackedOrder>,
+    // 주문 TR 응답보다 먼저 온 통보. 응답이 오면 record_response()에서 반영
+    pending_notices: HashMap<i64, Vec<OrderNotice>>,
+    // 오래된 주문번호부터 버리기 위한 순서
+    pending_order_nos: VecDeque<i64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TrackedOrder {
+    pub order_no: i64,
+    pub tr_index: i32,
+    // 주문 TR 출력 블록 이름 (c8101OutBlock 등)
+    pub block_name: String,
+    // d3 접수 통보 수신 여부
+    pub accepted: bool,
+    // d2 체결 통보의 체결수량 합계 (정정/취소 확인과 거부는 제외)
+    pub filled_qty: i64,
+}
+
+impl OrderTracker {
+    pub fn new() -> OrderTracker {
+        Self::default()
+    }
+
+    /**
+     * 주문 TR 응답이면 주문번호를 등록
+     */
+    pub fn record_response(&self, res: &DataResponse) -> Option<TrackedOrder> {
+        if !ORDER_BLOCK_NAMES.contains(&res.block_name.as_str()) {
+            return None;
+        }
+        let order_no = res.block_data.get("order_noz10")?.as_i64()?;
+        let mut order = TrackedOrder {
+            order_no,
+            tr_index: res.tr_index,
+            block_name: res.block_name.clone(),
+            accepted: false,
+            filled_qty: 0,
+        };
+
+        let mut state = self.state.write().unwrap();
+        if let Some(notices) = state.pending_notices.remove(&order_no) {
+            state.pending_order_nos.retain(|no| *no != order_no);
+            for notice in notices.iter() {
+                order.apply(notice);
+            }
+        }
+        state.orders.insert(order_no, order.clone());
+        Some(order)
+    }
+
+    /**
+     * 통보의 주문번호로 주문 TR을 찾아 tr_index를 채우고 상태를 갱신
+     * 아직 주문 TR 응답이 오지 않았으면 통보를 보관해 두고 None
+     */
+    pub fn on_notice(&self, notice: &mut OrderNotice) -> Option<TrackedOrder> {
+        let order_no = notice.get_order_no()?;
+        let mut state = self.state.write().unwrap();
+        match state.orders.get_mut(&order_no) {
+            Some(order) => {
+                order.apply(notice);
+                notice.set_tr_index(Some(order.tr_index));
+                Some(order.clone())
+            }
+            None => {
+                state.push_pending(order_no, notice.clone());
+                None
+            }
+        }
+    }
+
+    pub fn get_order(&self, order_no: i64) -> Option<TrackedOrder> {
+        self.state.read().unwrap().orders.get(&order_no).cloned()
+    }
+
+    pub fn get_orders(&self) -> Vec<TrackedOrder> {
+        self.state
+            .read()
+            .unwrap()
+            .orders
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    pub fn clear(&self) {
+        let mut state = self.state.write().unwrap();
+        state.orders.clear();
+        state.pending_notices.clear();
+        state.pending_order_nos.clear();
+    }
+}
+
+impl OrderTrackerState {
+    fn push_pending(&mut self, order_no: i64, notice: OrderNotice) {
+        if !self.pending_notices.contains_key(&order_no) {
+            if self.pending_order_nos.len() >= MAX_PENDING_ORDERS {
+                if let Some(oldest) = self.pending_order_nos.pop_front() {
+                    self.pending_notices.remove(&oldest);
+                }
+            }
+            self.pending_order_nos.push_back(order_no);
+        }
+        self.pending_notices
+            .entry(order_no)
+            .or_default()
+            .push(notice);
+    }
+}
+
+impl TrackedOrder {
+    fn apply(&mut self, notice: &OrderNotice) {
+        match notice {
+            OrderNotice::Ack(_) => self.accepted = true,
+            OrderNotice::Execution(report) => {
+                if is_blank_or_zero(&report.ucgb) && is_blank_or_zero(&report.rejgb) {
+                    self.filled_qty += report.concgty.unwrap_or(0);
+                }
+            }
+        }
+    }
+}
+
+fn is_blank_or_zero(code: &str) -> bool {
+    code.is_empty() || code == "0"
+}
@@ -0,0 +1,208 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, Mutex},
+};
+
+use crate::{
+    error::QvOpenApiError,
+    models::{SiseKind, SiseResponse},
+};
+
+pub type SubscriptionId = u64;
+
+// Err는 등록에 실패해 구독이 취소된 경우에 한 번만 옴
+pub type SiseCallback = Box<dyn FnMut(Result<&SiseResponse, &QvOpenApiError>) + Send>;
+
+/**
+ * 실시간 구독 목록
+ * 같은 (BC, 종목코드)를 여러 구독자가 등록해도 wmcaAttach/wmcaDetach는 한 번만 호출되도록 참조 수를 관리
+ * 재접속 후에는 get_active()로 남아 있는 구독을 다시 등록
+ */
+#[derive(Default)]
+pub struct SubscriptionRegistry {
+    state: Mutex<SubscriptionState>,
+}
+
+#[derive(Default)]
+struct SubscriptionState {
+    next_id: SubscriptionId,
+    subscriptions: HashMap<SubscriptionId, Subscription>,
+    ref_counts: HashMap<(SiseKind, String), usize>,
+}
+
+struct Subscription {
+    kind: SiseKind,
+    codes: Vec<String>,
+    callback: Arc<Mutex<SiseCallback>>,
+}
+
+impl SubscriptionRegistry {
+    pub fn new() -> SubscriptionRegistry {
+        Self::default()
+    }
+
+    /**
+     * 구독 추가 후 새로 등록해야 하는 종목코드 반환
+     */
+    pub fn add(
+        &self,
+        kind: SiseKind,
+        codes: &[String],
+        callback: SiseCallback,
+    ) -> (SubscriptionId, Vec<String>) {
+        let mut state = self.state.lock().unwrap();
+        state.next_id += 1;
+        let id = state.next_id;
+
+        let mut codes: Vec<String> = codes.iter().map(|code| code.trim().to_string()).collect();
+        codes.sort();
+        codes.dedup();
+        let mut attach_codes = Vec::new();
+        for code in codes.iter() {
+            let count = state.ref_counts.entry((kind, code.clone())).or_insert(0);
+            *count += 1;
+            if *count == 1 {
+                attach_codes.push(code.clone());
+            }
+        }
+
+        state.subscriptions.insert(
+            id,
+            Subscription {
+                kind,
+                codes,
+                callback: Arc::new(Mutex::new(callback)),
+            },
+        );
+        (id, attach_codes)
+    }
+
+    /**
+     * 구독 제거 후 더 이상 구독자가 없어 취소해야 하는 종목코드 반환
+     */
+    pub fn remove(&self, id: SubscriptionId) -> Option<(SiseKind, Vec<String>)> {
+        let mut state = self.state.lock().unwrap();
+        state
+            .take(id)
+            .map(|(subscription, detach_codes)| (subscription.kind, detach_codes))
+    }
+
+    /**
+     * wmcaAttach가 실패한 구독의 참조만 되돌리고 구독자 콜백에 오류를 전달
+     * attach_codes: 실패한 wmcaAttach로 등록하려던 종목코드
+     * 반환: 이미 등록돼 있었는데 구독자가 없어져 취소해야 하는 종목코드,
+     *      실패한 등록을 함께 기다리던 구독자가 남아 있어 다시 등록해야 하는 (구독 id, 종목코드)
+     */
+    pub fn fail(
+        &self,
+        id: SubscriptionId,
+        attach_codes: &[String],
+        err: &QvOpenApiError,
+    ) -> (Vec<String>, Vec<(SubscriptionId, Vec<String>)>) {
+        let (subscription, detach_codes, reattach) = {
+            let mut state = self.state.lock().unwrap();
+            let (subscription, released_codes) = match state.take(id) {
+                Some(taken) => taken,
+                None => return (Vec::new(), Vec::new()),
+            };
+            // 등록되지 않은 종목은 취소하지 않음
+            let detach_codes: Vec<String> = released_codes
+                .into_iter()
+                .filter(|code| !attach_codes.contains(code))
+                .collect();
+            // 남은 구독자 중 id가 가장 작은 쪽 이름으로 다시 등록
+            let mut reattach: BTreeMap<SubscriptionId, Vec<String>> = BTreeMap::new();
+            for code in attach_codes {
+                let owner = state
+                    .subscriptions
+                    .iter()
+                    .filter(|(_, other)| {
+                        other.kind == subscription.kind && other.codes.contains(code)
+                    })
+                    .map(|(other_id, _)| *other_id)
+                    .min();
+                if let Some(owner) = owner {
+                    reattach.entry(owner).or_default().push(code.clone());
+                }
+            }
+            (subscription, detach_codes, reattach)
+        };
+        (subscription.callback.lock().unwrap())(Err(err));
+        (detach_codes, reattach.into_iter().collect())
+    }
+
+    /**
+     * 모든 구독을 취소하고 구독자 콜백에 오류를 전달 (재접속하지 않고 세션이 끝난 경우)
+     */
+    pub fn fail_all(&self, err: &QvOpenApiError) {
+        let subscriptions: Vec<Subscription> = {
+            let mut state = self.state.lock().unwrap();
+            state.ref_counts.clear();
+            state
+                .subscriptions
+                .drain()
+                .map(|(_, subscription)| subscription)
+                .collect()
+        };
+        for subscription in subscriptions {
+            (subscription.callback.lock().unwrap())(Err(err));
+        }
+    }
+
+    pub fn clear(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.subscriptions.clear();
+        state.ref_counts.clear();
+    }
+
+    /**
+     * 현재 등록되어 있어야 하는 (BC, 종목코드 목록)
+     */
+    pub fn get_active(&self) -> Vec<(SiseKind, Vec<String>)> {
+        let state = self.state.lock().unwrap();
+        let mut active: HashMap<SiseKind, Vec<String>> = HashMap::new();
+        for (kind, code) in state.ref_counts.keys() {
+            active.entry(*kind).or_default().push(code.clone());
+        }
+        active.into_iter().collect()
+    }
+
+    /**
+     * 콜백은 목록 lock을 푼 뒤 호출하므로 콜백 안에서 구독/취소해도 됨
+     */
+    pub fn dispatch(&self, res: &SiseResponse) {
+        let callbacks: Vec<Arc<Mutex<SiseCallback>>> = {
+            let state = self.state.lock().unwrap();
+            state
+                .subscriptions
+                .values()
+                .filter(|subscription| {
+                    res.kind == Some(subscription.kind) && subscription.codes.contains(&res.code)
+                })
+                .map(|subscription| subscription.callback.clone())
+                .collect()
+        };
+        for callback in callbacks {
+            (callback.lock().unwrap())(Ok(res));
+        }
+    }
+}
+
+impl SubscriptionState {
+    fn take(&mut self, id: SubscriptionId) -> Option<(Subscription, Vec<String>)> {
+        let subscription = self.subscriptions.remove(&id)?;
+
+        let mut detach_codes = Vec::new();
+        for code in subscription.codes.iter() {
+            let key = (subscription.kind, code.clone());
+            if let Some(count) = self.ref_counts.get_mut(&key) {
+                *count -= 1;
+                if *count == 0 {
+                    self.ref_counts.remove(&key);
+                    detach_codes.push(key.1);
+                }
+            }
+        }
+        Some((subscription, detach_codes))
+    }
+}
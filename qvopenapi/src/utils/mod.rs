@@ -1,9 +1,14 @@
-use std::ffi::{c_char, CStr};
+use std::{
+    ffi::{c_char, CStr},
+    fmt::Display,
+};
 
 use chrono::FixedOffset;
-use encoding::{all::WINDOWS_949, DecoderTrap, Encoding};
+use encoding::{all::WINDOWS_949, DecoderTrap, EncoderTrap, Encoding};
+use log::*;
 
 use crate::error::*;
+use crate::models::PriceSign;
 
 lazy_static! {
     pub static ref SEOUL_TZ: FixedOffset = FixedOffset::east_opt(9 * 3600).unwrap();
@@ -84,6 +89,59 @@ pub fn parse_number(src: &[c_char]) -> Result<Option<i64>, QvOpenApiError> {
     Ok(Some(parsed?))
 }
 
+/**
+ * "  350.25" -> 350.25
+ * "00001234" -> 1234.0
+ */
+pub fn parse_decimal(src: &[c_char]) -> Result<Option<f64>, QvOpenApiError> {
+    let input = parse_string(src)?;
+
+    if input.is_empty() {
+        return Ok(None);
+    }
+
+    let parsed: f64 = input
+        .parse()
+        .map_err(|_| QvOpenApiError::ParseNumberError { input })?;
+    Ok(Some(parsed))
+}
+
+/**
+ * 선물옵션 가격처럼 소수점 없이 내려오는 값
+ * "35025" (scale 2) -> 350.25
+ * "350.25" -> 350.25 (소수점이 있으면 그대로 사용)
+ */
+pub fn parse_scaled(src: &[c_char], scale: i32) -> Result<Option<f64>, QvOpenApiError> {
+    let input = parse_string(src)?;
+
+    if input.contains('.') {
+        return parse_decimal(src);
+    }
+
+    Ok(parse_number(src)?.map(|value| value as f64 / 10f64.powi(scale)))
+}
+
+/**
+ * 등락부호 (시장과 관계없이 같은 코드체계)
+ * 0x18:상한 0x1E:상승 0x20:보합 0x19:하한 0x1F:하락
+ * 보합이 공백이므로 parse_string()으로 trim하면 안 됨
+ */
+pub fn parse_sign(src: &[c_char]) -> Result<Option<PriceSign>, QvOpenApiError> {
+    match src.first().map(|ch| *ch as u8) {
+        None | Some(0) => Ok(None),
+        Some(0x18) => Ok(Some(PriceSign::UpperLimit)),
+        Some(0x1E) => Ok(Some(PriceSign::Up)),
+        Some(0x20) => Ok(Some(PriceSign::Unchanged)),
+        Some(0x19) => Ok(Some(PriceSign::LowerLimit)),
+        Some(0x1F) => Ok(Some(PriceSign::Down)),
+        // 알 수 없는 부호 하나 때문에 나머지 필드를 버리지 않도록 부호 없음으로 처리
+        Some(ch) => {
+            warn!("Unknown price sign 0x{:02X}", ch);
+            Ok(None)
+        }
+    }
+}
+
 pub fn parse_string(src: &[c_char]) -> Result<String, QvOpenApiError> {
     Ok(from_cp949(&src).trim().to_string())
 }
@@ -116,3 +174,143 @@ pub fn from_cp949_ptr(src: *const c_char) -> String {
             .unwrap()
     }
 }
+
+/**
+ * "005930" -> "005930      " (왼쪽 정렬, 나머지는 공백)
+ * 길이를 넘는 입력은 잘라내지 않고 에러
+ */
+pub fn to_fixed_string<const N: usize>(src: &str) -> Result<[c_char; N], QvOpenApiError> {
+    let encoded = WINDOWS_949.encode(src, EncoderTrap::Strict).map_err(|_| {
+        QvOpenApiError::BadRequestError {
+            message: format!("Failed to encode [{}] to cp949", src),
+        }
+    })?;
+
+    if encoded.len() > N {
+        return Err(QvOpenApiError::BadRequestError {
+            message: format!("[{}] exceeds {} bytes", src, N),
+        });
+    }
+
+    let mut ret = [' ' as c_char; N];
+    for (dst, byte) in ret.iter_mut().zip(encoded.iter()) {
+        *dst = *byte as c_char;
+    }
+    Ok(ret)
+}
+
+/**
+ * 1234 -> "000000001234" (오른쪽 정렬, 나머지는 0)
+ * -1234 -> "-00000001234"
+ */
+pub fn to_fixed_number<const N: usize>(src: i64) -> Result<[c_char; N], QvOpenApiError> {
+    let formatted = if src < 0 {
+        format!(
+            "-{:0>width$}",
+            src.unsigned_abs(),
+            width = N.saturating_sub(1)
+        )
+    } else {
+        format!("{:0>width$}", src, width = N)
+    };
+    to_fixed_string(&formatted)
+}
+
+/**
+ * 350.25 -> "000000350.25"
+ * NaN, inf -> BadRequestError
+ */
+pub fn to_fixed_decimal<const N: usize>(
+    src: f64,
+    precision: usize,
+) -> Result<[c_char; N], QvOpenApiError> {
+    if !src.is_finite() {
+        return Err(QvOpenApiError::BadRequestError {
+            message: format!("[{}] is not a finite number", src),
+        });
+    }
+
+    let formatted = if src < 0. {
+        format!(
+            "-{:0>width$.precision$}",
+            -src,
+            width = N.saturating_sub(1),
+            precision = precision
+        )
+    } else {
+        format!(
+            "{:0>width$.precision$}",
+            src,
+            width = N,
+            precision = precision
+        )
+    };
+    to_fixed_string(&formatted)
+}
+
+/**
+ * 주문수량, 주문단가처럼 0보다 커야 하는 값 확인
+ * assert_positive("order_qty", 0) -> Err
+ */
+pub fn assert_positive<T: PartialOrd + Default + Display>(
+    field: &str,
+    value: T,
+) -> Result<(), QvOpenApiError> {
+    if value > T::default() {
+        Ok(())
+    } else {
+        Err(QvOpenApiError::BadRequestError {
+            message: format!("{} must be greater than 0, but [{}]", field, value),
+        })
+    }
+}
+
+/**
+ * 원주문번호처럼 음수가 될 수 없는 값 확인
+ */
+pub fn assert_non_negative<T: PartialOrd + Default + Display>(
+    field: &str,
+    value: T,
+) -> Result<(), QvOpenApiError> {
+    if value >= T::default() {
+        Ok(())
+    } else {
+        Err(QvOpenApiError::BadRequestError {
+            message: format!("{} must not be negative, but [{}]", field, value),
+        })
+    }
+}
+
+/**
+ * 코드값 필드가 허용된 값인지 확인
+ * assert_code("slbuy_type", '1', "12") -> Ok
+ */
+pub fn assert_code(field: &str, value: char, allowed: &str) -> Result<(), QvOpenApiError> {
+    if allowed.contains(value) {
+        Ok(())
+    } else {
+        Err(QvOpenApiError::BadRequestError {
+            message: format!(
+                "Invalid {} [{}], expected one of [{}]",
+                field, value, allowed
+            ),
+        })
+    }
+}
+
+/**
+ * 두 자리 이상인 코드값 필드가 허용된 값인지 확인
+ * assert_code_in("trade_type", "03", &["00", "03"]) -> Ok
+ */
+pub fn assert_code_in(field: &str, value: &str, allowed: &[&str]) -> Result<(), QvOpenApiError> {
+    if allowed.contains(&value) {
+        Ok(())
+    } else {
+        Err(QvOpenApiError::BadRequestError {
+            message: format!(
+                "Invalid {} [{}], expected one of {:?}",
+                field, value, allowed
+            ),
+        })
+    }
+}
@@ -105,6 +105,57 @@ pub fn disconnect() -> Result<(), QvOpenApiError> {
     c_bool_to_result((get_lib()?.disconnect)())
 }
 
+/**
+ * 실시간 등록
+ * 종목코드는 BC별 고정길이(nCodeLen)로 공백 채워 이어 붙임
+ */
+pub fn subscribe(hwnd: isize, kind: SiseKind, codes: &[String]) -> Result<(), QvOpenApiError> {
+    let bc_type_cstr = make_c_string(kind.as_str());
+    let input_cstr = make_c_string(&kind.pack_codes(codes)?);
+
+    debug!("subscribe ({}, {:?})", kind.as_str(), codes);
+
+    c_bool_to_result((get_lib()?.attach)(
+        hwnd,
+        bc_type_cstr.as_ptr(),
+        input_cstr.as_ptr(),
+        kind.code_len() as c_int,
+        input_cstr.as_bytes().len() as c_int,
+    ))
+}
+
+/**
+ * 실시간 취소
+ */
+pub fn unsubscribe(hwnd: isize, kind: SiseKind, codes: &[String]) -> Result<(), QvOpenApiError> {
+    let bc_type_cstr = make_c_string(kind.as_str());
+    let input_cstr = make_c_string(&kind.pack_codes(codes)?);
+
+    debug!("unsubscribe ({}, {:?})", kind.as_str(), codes);
+
+    c_bool_to_result((get_lib()?.detach)(
+        hwnd,
+        bc_type_cstr.as_ptr(),
+        input_cstr.as_ptr(),
+        kind.code_len() as c_int,
+        input_cstr.as_bytes().len() as c_int,
+    ))
+}
+
+#[allow(dead_code)]
+pub fn detach_window(hwnd: isize) -> Result<(), QvOpenApiError> {
+    debug!("detach_window ({})", hwnd);
+
+    c_bool_to_result((get_lib()?.detach_window)(hwnd))
+}
+
+#[allow(dead_code)]
+pub fn detach_all() -> Result<(), QvOpenApiError> {
+    debug!("detach_all");
+
+    c_bool_to_result((get_lib()?.detach_all)())
+}
+
 #[allow(dead_code)]
 pub fn set_account_index_pwd<T>(
     input: &mut T,